@@ -13,9 +13,10 @@ use hdk::prelude::*;
 use hdk_uuid_types::{
     DnaAddressable, DnaIdentifiable,
 };
+use hdk_semantic_indexes_zome_rpc::RevisionHistoryParams;
 
 use crate::{
-    RecordAPIResult, DataIntegrityError, SemanticIndexError,
+    RecordAPIResult, DataIntegrityError, SemanticIndexError, RevisionHistory,
     record_interface::{
         Identified, Identifiable, UniquelyIdentifiable,
         Updateable, UpdateableIdentifier,
@@ -24,11 +25,13 @@ use crate::{
     records::{
         create_record,
         read_record_entry_by_identity,
+        read_revision_history_by_identity,
+        ensure_revision_heads,
+        delete_record,
     },
     entries::{
         get_entry_by_action,
         update_entry,
-        delete_entry,
     },
 };
 
@@ -120,6 +123,23 @@ pub fn read_anchored_record_entry<LT, T, R, B, A, I>(
     Ok((meta, A::new(dna_info()?.hash, id_string.as_ref().to_string()), entry_data))
 }
 
+/// Reads a page of the revision history of a record via its `anchor index`.
+///
+/// @see hdk_records::metadata::read_revision_history
+///
+pub fn read_anchored_record_revision_history<LT, A>(
+    link_type: LT,
+    page: &RevisionHistoryParams<A>,
+) -> RecordAPIResult<RevisionHistory>
+    where LT: LinkTypeFilterExt,
+        A: DnaIdentifiable<String>,
+{
+    let id_string: &String = page.address.as_ref();
+    let anchor_address = calculate_anchor_address(id_string)?;
+    let identity_address = read_anchor_identity(link_type, &anchor_address)?;
+    read_revision_history_by_identity(&identity_address, page)
+}
+
 /// Creates a new record in the DHT and assigns it a manually specified `anchor index`
 /// that can be used like a primary key. The `create_payload` must also implement
 /// `UniquelyIdentifiable` in order to derive the unique `anchor index` value.
@@ -172,11 +192,16 @@ pub fn create_anchored_record<LT, I, B, A, C, R, T, E, S, F, G>(
 /// The `update_payload` must contain all data necessary to determine both the existing
 /// `anchor index` ID of the record, and the new `anchor index` that it has been moved to (if any).
 ///
+/// As with `update_record`, only the latest revision of a record may be updated, unless
+/// `conflicting_revisions` are given in order to resolve them as for `resolve_conflict`.
+///
 /// @see hdk_records::record_interface::UpdateableIdentifier
+/// @see hdk_records::records::update_or_resolve_record
 ///
 pub fn update_anchored_record<LT, I, R, A, B, U, E>(
     link_type: LT,
     revision_id: &ActionHash,
+    conflicting_revisions: Option<&[ActionHash]>,
     update_payload: U,
 ) -> RecordAPIResult<(SignedActionHashed, B, I, I)>
     where A: DnaAddressable<EntryHash>,
@@ -196,6 +221,9 @@ pub fn update_anchored_record<LT, I, R, A, B, U, E>(
     let prev_entry = previous.entry();
     let identity = previous.identity()?;
 
+    // prevent forking the revision history or discarding any concurrent edits
+    let merges = ensure_revision_heads(identity.as_ref(), revision_id, conflicting_revisions)?;
+
     let identity_hash = calculate_identity_address(&identity)?;
     let maybe_current_id = read_entry_anchor_id([link_type.to_owned()], &identity_hash);
 
@@ -207,7 +235,7 @@ pub fn update_anchored_record<LT, I, R, A, B, U, E>(
 
             // apply update payload
             let new_entry = prev_entry.update_with(update_payload)?;
-            let storage: R = new_entry.with_identity(Some(identity_hash.clone()));
+            let storage: R = new_entry.with_merged_identity(Some(identity_hash.clone()), merges);
 
             // perform regular entry update using internal address
             let (meta, _new_entry_addr) = update_entry(revision_id, storage)?;
@@ -254,11 +282,21 @@ pub fn update_anchored_record<LT, I, R, A, B, U, E>(
 /// :TODO: This is a stub- include any logic necessary to handle cleanup of associated links.
 ///        Not clearing old anchors may cause issues upon subsequent reinsert, which is not yet tested.
 ///
-pub fn delete_anchored_record<T>(address: &ActionHash) -> RecordAPIResult<bool>
-    where SerializedBytes: TryInto<T, Error = SerializedBytesError>,
+pub fn delete_anchored_record<T, I, B, S, F, C>(
+    indexing_zome_name_from_config: F,
+    entry_def_id: S,
+    address: &ActionHash,
+) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+        B: DnaAddressable<EntryHash>,
+        T: Identified<I, B>,
+        Entry: TryFrom<T>,
+        SerializedBytes: TryInto<T, Error = SerializedBytesError>,
+        F: FnOnce(C) -> Option<String>,
+        C: std::fmt::Debug,
+        SerializedBytes: TryInto<C, Error = SerializedBytesError>,
 {
-    delete_entry::<T>(address)?;
-    Ok(true)
+    delete_record::<T, I, B, S, F, C>(indexing_zome_name_from_config, entry_def_id, address)
 }
 
 //--------------------------[ UTILITIES  / INTERNALS ]---------------------
@@ -27,7 +27,7 @@ use crate::{
     rpc_helpers::call_local_zome_method,
 };
 use hdk_semantic_indexes_zome_rpc::{
-    AppendAddress,
+    AppendAddress, ByAddress,
 };
 
 //--------------------------------[ READ ]--------------------------------------
@@ -91,3 +91,31 @@ pub fn create_entry_identity<A, S, F, C>(
         },
     ).map_err(|e| { DataIntegrityError::LocalIndexNotConfigured(entry_def_id.to_string(), e.to_string()) })?)
 }
+
+//-------------------------------[ DELETE ]-------------------------------------
+
+/// Removes the entry identified by `address` from the global index for all entries of
+/// the given `entry_type`, so that it is no longer returned from record listings.
+///
+pub fn delete_entry_identity<A, S, F, C>(
+    zome_name_from_config: F,
+    entry_def_id: S,
+    address: &A,
+) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+        A: DnaAddressable<EntryHash>,
+        F: FnOnce(C) -> Option<String>,
+        C: std::fmt::Debug,
+        SerializedBytes: TryInto<C, Error = SerializedBytesError>,
+{
+    // @see hdk_semantic_indexes_zome_derive::index_zome
+    let remove_fn_name = format!("record_deleted_{}", entry_def_id);
+
+    // request removal from index in companion zome
+    Ok(call_local_zome_method(
+        zome_name_from_config, remove_fn_name,
+        ByAddress {
+            address: address.to_owned(),
+        },
+    ).map_err(|e| { DataIntegrityError::LocalIndexNotConfigured(entry_def_id.to_string(), e.to_string()) })?)
+}
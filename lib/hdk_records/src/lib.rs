@@ -5,7 +5,7 @@ use thiserror::Error;
 use std::convert::Infallible;
 use hdk::prelude::*;
 
-pub use hdk::prelude::{CellId, EntryHash, hash_entry};
+pub use hdk::prelude::{CellId, EntryHash, ActionHash, hash_entry};
 pub use holo_hash::{DnaHash};
 pub use hdk::{
     info::{agent_info, dna_info},
@@ -42,7 +42,7 @@ pub mod metadata { pub use crate::metadata_helpers::*; }
 
 // externally-facing structs
 
-pub use metadata_helpers::{ RevisionMeta, RecordMeta };
+pub use metadata_helpers::{ RevisionMeta, RevisionType, RecordMeta, RevisionHistory, RevisionDiff, FieldChange };
 
 // :TODO: these error types may just be duplicating enums from the HDK,
 // revisit this once result handling & serialisation have stabilised.
@@ -72,6 +72,8 @@ pub enum DataIntegrityError {
     EntryWrongType,
     #[error("Conflicting revisions found: {0:?}")]
     UpdateConflict(Vec<ActionHash>),
+    #[error("Revisions {0} and {1} are not revisions of the same record")]
+    MismatchingRevisions(ActionHash, ActionHash),
 
     #[error("Error in remote call {0}")]
     RemoteRequestError(String),
@@ -1,6 +1,21 @@
 use chrono::{ DateTime, Utc, NaiveDateTime };
 use hdk::prelude::*;
-use crate::{ RecordAPIResult, DataIntegrityError };
+use holochain_serialized_bytes::encode;
+use rmpv::{ Value, decode::read_value };
+use hdk_semantic_indexes_zome_rpc::RevisionHistoryParams;
+use crate::{ RecordAPIResult, DataIntegrityError, SemanticIndexError };
+
+/// Default number of revisions returned by `read_revision_history` if no page size is given
+const REVISION_HISTORY_PAGE_SIZE: usize = 30;
+
+/// The kind of change which a revision made to a record
+///
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum RevisionType {
+    Create,
+    Update,
+    Delete,
+}
 
 /// Metadata for a specific revision of a record, serializable for external transmission
 ///
@@ -10,44 +25,107 @@ pub struct RevisionMeta {
     pub id: ActionHash,
     pub time: DateTime<Utc>,
     pub agent_pub_key: AgentPubKey,
+    pub revision_type: RevisionType,
 }
 
 /// Record metadata structure to enable iterating revisions of a record over time
 ///
+/// Fields summarising the rest of the record's history are only populated by
+/// `read_revision_metadata_full`, since they require traversing every revision.
+/// Record zomes use it for single-record reads, but not for lists of records.
+///
+/// `conflicting_revisions` lists any other live branch heads if the record has been edited
+/// concurrently and the conflict has not yet been resolved (@see `records::resolve_conflict`).
+/// `latest_revision` is then the most recent head. It is populated by both full and abbreviated reads.
+///
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RecordMeta {
-    // pub original_revision: RevisionMeta,
+    pub original_revision: Option<RevisionMeta>,
     pub previous_revision: Option<RevisionMeta>,
-    // pub previous_revisions_count: u32,
-    // pub latest_revision: RevisionMeta,
-    // pub future_revisions_count: u32,
+    pub previous_revisions_count: Option<u32>,
+    pub latest_revision: Option<RevisionMeta>,
+    pub future_revisions_count: Option<u32>,
     pub retrieved_revision: RevisionMeta,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicting_revisions: Vec<RevisionMeta>,
+}
+
+/// A page of revisions from the history of a record, ordered from most recent to oldest
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionHistory {
+    pub revisions: Vec<RevisionMeta>,
+    // true if there are more recent revisions than those in this page
+    pub has_previous_page: bool,
+    // true if there are older revisions than those in this page
+    pub has_next_page: bool,
+    pub total_count: usize,
+}
+
+/// A change to a single field of a record between two revisions.
+///
+/// Values retain the same serialized representation as the field has in the record's entry data.
+///
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Value,
+    pub new_value: Value,
+}
+
+/// Field-level differences between two revisions of a record
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionDiff {
+    pub from_revision: RevisionMeta,
+    pub to_revision: RevisionMeta,
+    pub changes: Vec<FieldChange>,
 }
 
 /// Retrieve minimal revision metadata for a record needed by UIs to retrieve version history
 ///
+/// Any conflicting revisions are always reported, so that conflicts are visible wherever a record is read.
+///
 pub fn read_revision_metadata_abbreviated(header: &SignedActionHashed) -> RecordAPIResult<RecordMeta>
 {
     let maybe_previous_element = get_previous_revision(header)?;
 
+    let first = match maybe_previous_element.clone() {
+        Some(previous_element) => find_earliest_revision(previous_element.signed_action(), 1)?.0,
+        None => header.to_owned(),
+    };
+
     Ok(RecordMeta {
-        // original_revision: (&first).into(),
+        original_revision: None,
         previous_revision: maybe_previous_element.map(|e| e.into()),
-        // previous_revisions_count,
-        // future_revisions_count: 0,
-        // latest_revision: e.clone().into(),
+        previous_revisions_count: None,
+        latest_revision: None,
+        future_revisions_count: None,
         retrieved_revision: header.into(),
+        conflicting_revisions: find_conflicting_revisions(&first)?,
     })
 }
 
+/// List every live branch head of the record originally written by `first` other than the most
+/// recent one, if its revision history has diverged.
+///
+fn find_conflicting_revisions(first: &SignedActionHashed) -> RecordAPIResult<Vec<RevisionMeta>>
+{
+    let mut heads = find_revision_heads(first)?;
+    heads.pop();
+    Ok(heads.iter().map(|(head, _)| head.into()).collect())
+}
+
 /**
  * Derive metadata for a record's full revision history by querying the DHT
  *
- * :TODO: handle conflicts @see https://github.com/h-REA/hREA/issues/196
- *
- * :TODO: think of some sensible way to differentiate a delete revision from
- * others if it is the one being requested
+ * If the record's revision history has diverged, the most recent branch head is reported as the
+ * latest revision and all other heads as conflicting revisions.
+ * For deleted records, the latest revision is the `Delete` action which removed the record.
  */
 pub fn read_revision_metadata_full(header: &SignedActionHashed) -> RecordAPIResult<RecordMeta>
 {
@@ -59,36 +137,27 @@ pub fn read_revision_metadata_full(header: &SignedActionHashed) -> RecordAPIResu
 
                 // recurse backwards from previous to determine original,
                 // or indicate current as original if no previous Element exists
-                let (_first, _previous_revisions_count) = match maybe_previous_element.clone() {
+                let (first, previous_revisions_count) = match maybe_previous_element.clone() {
                     Some(previous_element) => find_earliest_revision(previous_element.signed_action(), 1)?,
                     None => (header.to_owned(), 0),
                 };
 
-                match details.updates.len() {
-                    // no updates referencing this Element; therefore there are no future revisions and we are the latest
-                    0 => {
-                        Ok(RecordMeta {
-                            // original_revision: (&first).into(),
-                            previous_revision: maybe_previous_element.map(|e| e.into()),
-                            // previous_revisions_count,
-                            // future_revisions_count: 0,
-                            // latest_revision: e.clone().into(),
-                            retrieved_revision: header.into(),
-                        })
-                    },
-                    // updates found, recurse to determine latest
-                    _ => {
-                        let (_latest, _future_revisions_count) = find_latest_revision(details.updates.as_slice(), 0)?;
-                        Ok(RecordMeta {
-                            // original_revision: (&first).into(),
-                            previous_revision: maybe_previous_element.map(|e| e.into()),
-                            // previous_revisions_count,
-                            // future_revisions_count,
-                            // latest_revision: (&latest).into(),
-                            retrieved_revision: header.into(),
-                        })
-                    },
-                }
+                // traverse the revision tree forwards to determine latest
+                let mut heads = find_revision_heads(header)?;
+                let (latest, future_revisions_count) = match heads.pop() {
+                    Some(head) => head,
+                    None => find_latest_deletion(header)?,
+                };
+
+                Ok(RecordMeta {
+                    original_revision: Some((&first).into()),
+                    previous_revision: maybe_previous_element.map(|e| e.into()),
+                    previous_revisions_count: Some(previous_revisions_count),
+                    latest_revision: Some((&latest).into()),
+                    future_revisions_count: Some(future_revisions_count),
+                    retrieved_revision: header.into(),
+                    conflicting_revisions: heads.iter().map(|(head, _)| head.into()).collect(),
+                })
             },
             _ => Err(DataIntegrityError::EntryNotFound),
         },
@@ -96,6 +165,94 @@ pub fn read_revision_metadata_full(header: &SignedActionHashed) -> RecordAPIResu
     }
 }
 
+/// Read a page of the revision history of the record originally written by the given action.
+///
+/// Revisions from every branch of the record's history are included, as is any `Delete`
+/// action removing the record. Paging forwards
+/// (`first` / `after`) moves back in time from the most recent revision; paging backwards
+/// (`last` / `before`) moves towards the present from the original revision.
+///
+pub fn read_revision_history<T>(original: &SignedActionHashed, page: &RevisionHistoryParams<T>) -> RecordAPIResult<RevisionHistory>
+{
+    let revisions: Vec<RevisionMeta> = collect_revisions(original)?
+        .iter().rev()
+        .map(|(revision, _)| revision.into())
+        .collect();
+    let total_count = revisions.len();
+
+    let cursor_position = |cursor: &ActionHash| {
+        revisions.iter().position(|r| r.id == *cursor)
+            .ok_or(DataIntegrityError::from(SemanticIndexError::InvalidCursor(cursor.to_string())))
+    };
+
+    let (start, end) = if page.last.is_some() || page.before.is_some() {
+        let end = match &page.before {
+            Some(cursor) => cursor_position(cursor)?,
+            None => total_count,
+        };
+        (end.saturating_sub(page.last.unwrap_or(REVISION_HISTORY_PAGE_SIZE)), end)
+    } else {
+        let start = match &page.after {
+            Some(cursor) => cursor_position(cursor)? + 1,
+            None => 0,
+        };
+        (start, std::cmp::min(start + page.first.unwrap_or(REVISION_HISTORY_PAGE_SIZE), total_count))
+    };
+
+    Ok(RevisionHistory {
+        has_previous_page: start > 0,
+        has_next_page: end < total_count,
+        revisions: revisions[start..end].to_vec(),
+        total_count,
+    })
+}
+
+/// Compare the serialized representations of two versions of some entry data, returning
+/// every top-level field whose value differs between them in order of declaration.
+///
+/// Fields present in only one version are compared against a `nil` value.
+///
+pub fn diff_entries<T>(from: &T, to: &T) -> RecordAPIResult<Vec<FieldChange>>
+    where T: Serialize + std::fmt::Debug,
+{
+    let from_fields = read_entry_fields(from)?;
+    let mut to_fields = read_entry_fields(to)?;
+
+    let mut changes = vec![];
+    for (field, old_value) in from_fields {
+        let new_value = match to_fields.iter().position(|(f, _)| *f == field) {
+            Some(idx) => to_fields.remove(idx).1,
+            None => Value::Nil,
+        };
+        if old_value != new_value {
+            changes.push(FieldChange { field, old_value, new_value });
+        }
+    }
+    // any fields remaining did not exist in the original
+    for (field, new_value) in to_fields {
+        if new_value != Value::Nil {
+            changes.push(FieldChange { field, old_value: Value::Nil, new_value });
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Decode the serialized representation of some entry data into its named fields
+///
+fn read_entry_fields<T>(entry: &T) -> RecordAPIResult<Vec<(String, Value)>>
+    where T: Serialize + std::fmt::Debug,
+{
+    let bytes = encode(entry)?;
+    match read_value(&mut bytes.as_slice()) {
+        Ok(Value::Map(fields)) => Ok(fields.into_iter()
+            .filter_map(|(key, value)| key.as_str().map(|k| (k.to_string(), value)))
+            .collect()),
+        Ok(_) => Err(DataIntegrityError::EntryWrongType),
+        Err(e) => Err(SerializedBytesError::Deserialize(e.to_string()).into()),
+    }
+}
+
 impl TryFrom<Record> for RecordMeta {
     type Error = DataIntegrityError;
 
@@ -124,6 +281,11 @@ impl From<&SignedActionHashed> for RevisionMeta {
             id: get_action_hash(e),
             time: DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp_opt(secs, nsecs).unwrap(), Utc),
             agent_pub_key: e.action().author().to_owned(),
+            revision_type: match e.action() {
+                Action::Update(_) => RevisionType::Update,
+                Action::Delete(_) => RevisionType::Delete,
+                _ => RevisionType::Create,
+            },
         }
     }
 }
@@ -168,36 +330,122 @@ fn find_earliest_revision(signed_action: &SignedActionHashed, revisions_before:
     }
 }
 
-/**
- * Recursive helper for determining latest revision in chain, and count of subsequent revisions.
- *
- * :TODO: currently we assume multiple updates to the same entry were non-conflicting
- * and perceive the most recent as pointing to the next revision. We should not.
- * Instead every update path needs to be checked, all diverging leaf nodes resolved;
- * and if any remain then a DataIntegrityError::UpdateConflict should be thrown
- * with all the conflicting branch heads.
- *
- * :TODO: decide whether to return a delete as the latest revision for deleted entries
- */
-fn find_latest_revision(updates: &[SignedActionHashed], revisions_until: u32) -> RecordAPIResult<(SignedActionHashed, u32)> {
-    let mut sortlist = updates.to_vec();
-    sortlist.sort_by_key(by_action_time);
-    let most_recent = sortlist.last().unwrap().to_owned();
+/// Traverse the tree of revisions forwards from the given action, returning every live branch head
+/// alongside the count of revisions separating it from the starting action. Heads are sorted
+/// from oldest to newest, with ties broken by `ActionHash` so that the order is deterministic.
+///
+/// Branch heads which have been deleted are omitted, as are any heads superseded by a merge
+/// revision elsewhere in the tree (@see `hdk_records::records::resolve_conflict`).
+///
+pub fn find_revision_heads(signed_action: &SignedActionHashed) -> RecordAPIResult<Vec<(SignedActionHashed, u32)>> {
+    let mut heads = vec![];
+    let mut merged = vec![];
+    let mut pending = vec![(get_action_hash(signed_action), 0)];
 
-    match get_details(get_action_hash(&most_recent), GetOptions { strategy: GetStrategy::Latest }) {
-        Ok(Some(Details::Record(details))) => match details.validation_status {
-            ValidationStatus::Valid => match details.updates.len() {
-                // found latest revision
-                0 => Ok((details.record.signed_action().to_owned(), revisions_until + 1)),
-                // still more updates to crawl, keep going
-                _ => find_latest_revision(details.updates.as_slice(), revisions_until + 1),
+    while let Some((action_hash, revisions_until)) = pending.pop() {
+        match get_details(action_hash, GetOptions { strategy: GetStrategy::Latest })? {
+            Some(Details::Record(details)) => match details.validation_status {
+                ValidationStatus::Valid => {
+                    // merge revisions may supersede heads in any other branch of the tree
+                    merged.extend(read_merged_revisions(&details.record)?);
+
+                    match details.updates.len() {
+                        // found a branch head, which is live unless deleted
+                        0 => if details.deletes.is_empty() {
+                            heads.push((details.record.signed_action().to_owned(), revisions_until));
+                        },
+                        // still more updates to crawl, queue every branch
+                        _ => pending.extend(details.updates.iter().map(|u| (get_action_hash(u), revisions_until + 1))),
+                    }
+                },
+                // rejected or abandoned revisions do not form part of the record's history
+                _ => (),
             },
-            // :TODO: how to handle abandoned validations?
-            _ => Err(DataIntegrityError::EntryNotFound),
-        },
-        // :TODO: should we account for `None` being returned from the DHT?
-        _ => Err(DataIntegrityError::EntryNotFound),
+            // :TODO: should we account for `None` being returned from the DHT?
+            _ => return Err(DataIntegrityError::EntryNotFound),
+        }
     }
+
+    heads.retain(|(h, _)| !merged.contains(h.as_hash()));
+    heads.sort_by(|(a, _), (b, _)| by_action_time(a).cmp(&by_action_time(b)).then_with(|| a.as_hash().cmp(b.as_hash())));
+    Ok(heads)
+}
+
+/// Determine the latest revision following the given action, and count of subsequent revisions.
+///
+/// If the revision history has diverged into multiple live branches, the most recent branch head
+/// is returned so that reads remain possible until the conflict is resolved. If no live branches
+/// remain (because the record was deleted) then `DataIntegrityError::EntryNotFound` is returned.
+///
+pub fn find_latest_revision(signed_action: &SignedActionHashed) -> RecordAPIResult<(SignedActionHashed, u32)> {
+    find_revision_heads(signed_action)?
+        .pop()
+        .ok_or(DataIntegrityError::EntryNotFound)
+}
+
+/// Determine the most recent `Delete` action following the given action, for records which
+/// have no live revisions remaining.
+///
+fn find_latest_deletion(signed_action: &SignedActionHashed) -> RecordAPIResult<(SignedActionHashed, u32)> {
+    collect_revisions(signed_action)?
+        .into_iter()
+        .filter(|(revision, _)| matches!(revision.action(), Action::Delete(_)))
+        .last()
+        .ok_or(DataIntegrityError::EntryNotFound)
+}
+
+/// Minimal view of a record's storage entry, used to read the revisions superseded by a merge
+/// revision without knowing the type of the record.
+///
+/// @see hdk_records::generate_record_entry
+///
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct RevisionMerges {
+    #[serde(default)]
+    merges: Vec<ActionHash>,
+}
+
+/// Read the `ActionHash` of every revision which the given revision was written to supersede
+///
+fn read_merged_revisions(record: &Record) -> RecordAPIResult<Vec<ActionHash>> {
+    match record.entry().as_option() {
+        Some(entry) => read_entry_merges(entry),
+        None => Ok(vec![]),
+    }
+}
+
+fn read_entry_merges(entry: &Entry) -> RecordAPIResult<Vec<ActionHash>> {
+    match entry {
+        Entry::App(AppEntryBytes(bytes)) => Ok(RevisionMerges::try_from(bytes.to_owned())?.merges),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Traverse the tree of revisions forwards from the given action, returning every revision (including
+/// deletions) alongside the count of revisions separating it from the starting action. Revisions are
+/// sorted from oldest to newest.
+///
+fn collect_revisions(signed_action: &SignedActionHashed) -> RecordAPIResult<Vec<(SignedActionHashed, u32)>> {
+    let mut revisions = vec![];
+    let mut pending = vec![(signed_action.to_owned(), 0)];
+
+    while let Some((revision, revisions_until)) = pending.pop() {
+        match get_details(get_action_hash(&revision), GetOptions { strategy: GetStrategy::Latest })? {
+            Some(Details::Record(details)) => match details.validation_status {
+                ValidationStatus::Valid => {
+                    revisions.extend(details.deletes.iter().map(|d| (d.to_owned(), revisions_until + 1)));
+                    pending.extend(details.updates.iter().map(|u| (u.to_owned(), revisions_until + 1)));
+                    revisions.push((revision, revisions_until));
+                },
+                // rejected or abandoned revisions do not form part of the record's history
+                _ => (),
+            },
+            _ => return Err(DataIntegrityError::EntryNotFound),
+        }
+    }
+
+    revisions.sort_by_key(|(r, _)| by_action_time(r));
+    Ok(revisions)
 }
 
 /// Helper to retrieve the ActionHash for an Record
@@ -209,3 +457,52 @@ pub (crate) fn get_action_hash(shh: &SignedActionHashed) -> ActionHash {
 fn by_action_time(h: &SignedActionHashed) -> i64 {
     h.action().timestamp().as_micros()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Serialize, Deserialize, Debug)]
+    pub struct TestEntry {
+        name: Option<String>,
+        note: Option<String>,
+        quantity: Option<u32>,
+    }
+
+    #[test]
+    fn test_diff_entries() {
+        let from = TestEntry { name: Some("test".into()), note: None, quantity: Some(1) };
+        let to = TestEntry { name: Some("test".into()), note: Some("changed".into()), quantity: None };
+
+        let changes = diff_entries(&from, &to).unwrap();
+
+        assert_eq!(changes, vec![
+            FieldChange { field: "note".into(), old_value: Value::Nil, new_value: Value::from("changed") },
+            FieldChange { field: "quantity".into(), old_value: Value::from(1), new_value: Value::Nil },
+        ], "only changed fields should be returned, in order of declaration");
+        assert_eq!(diff_entries(&from, &from).unwrap(), vec![], "identical entries should have no changes");
+    }
+
+    // mirrors the storage layout of `generate_record_entry!`
+    #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+    pub struct TestEntryWithIdentity {
+        entry: TestEntry,
+        id_hash: Option<EntryHash>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        merges: Vec<ActionHash>,
+    }
+
+    fn storage_entry(merges: Vec<ActionHash>) -> Entry {
+        let entry = TestEntry { name: Some("test".into()), note: None, quantity: None };
+        let storage = TestEntryWithIdentity { entry, id_hash: Some(EntryHash::from_raw_36(vec![1; 36])), merges };
+        Entry::App(AppEntryBytes(storage.try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_read_entry_merges() {
+        let superseded = vec![ActionHash::from_raw_36(vec![2; 36]), ActionHash::from_raw_36(vec![3; 36])];
+
+        assert_eq!(read_entry_merges(&storage_entry(superseded.clone())).unwrap(), superseded, "merged revisions should be read from any record entry");
+        assert_eq!(read_entry_merges(&storage_entry(vec![])).unwrap(), vec![], "regular revisions should not supersede any others");
+    }
+}
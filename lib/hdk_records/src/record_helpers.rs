@@ -10,10 +10,11 @@
  */
 use hdk::prelude::*;
 use hdk::info::dna_info;
+use hdk_semantic_indexes_zome_rpc::RevisionHistoryParams;
 
 use crate::{
     DnaAddressable,
-    RecordAPIResult, DataIntegrityError,
+    RecordAPIResult, DataIntegrityError, RevisionHistory, RevisionDiff,
     record_interface::{Identifiable, Identified, Updateable},
     entries::{
         get_entry_by_action,
@@ -23,11 +24,16 @@ use crate::{
     },
     identity_helpers::{
         create_entry_identity,
+        delete_entry_identity,
         infer_local_entry_identity,
         calculate_identity_address,
     },
     metadata_helpers::{
         get_action_hash,
+        find_revision_heads,
+        find_latest_revision,
+        read_revision_history,
+        diff_entries,
     },
 };
 
@@ -37,40 +43,68 @@ use crate::{
 ///
 /// Useful in coordinating updates between different entry types.
 ///
-/// :TODO: this is a very naive recursive algorithm that basically assumes full network
-/// connectivity between everyone at all times, and Updates form a Linked List, rather
-/// than a multi-branching tree. This should be updated during other 'conflict resolution' related
-/// changes outlined in issue https://github.com/h-REA/hREA/issues/196
+/// All branches of the record's revision history are traversed. If more than one live revision
+/// remains, the record is in conflict and the most recent of them is returned. The other heads are
+/// reported in the `conflicting_revisions` of both full and abbreviated `RecordMeta`, or may be
+/// read with `get_revision_heads`.
 ///
 pub fn get_latest_action_hash(entry_hash: EntryHash) -> RecordAPIResult<ActionHash> {
-    match get_details(entry_hash.clone(), GetOptions { strategy: GetStrategy::Latest })? {
-        Some(Details::Entry(details)) => match details.entry_dht_status {
-            metadata::EntryDhtStatus::Live => match details.updates.len() {
-                0 => {
-                    // https://docs.rs/hdk/latest/hdk/prelude/struct.EntryDetails.html#structfield.actions
-                    Ok(get_action_hash(details.actions.first().unwrap()))
-                },
-                _ => {
-                    // updates exist, find most recent action
-                    let mut sortlist = details.updates.to_vec();
-                    sortlist.sort_by_key(|update| update.action().timestamp().as_micros());
-                    let last = sortlist.last().unwrap();
-                    // (unwrap should be safe because these are Update actions)
-                    let last_entry_hash = last.action().entry_hash().unwrap().clone();
-                    if entry_hash == last_entry_hash {
-                      Ok(get_action_hash(last))
-                    } else {
-                      // recurse
-                      get_latest_action_hash(last_entry_hash)
-                    }
-                },
-            },
-            _ => Err(DataIntegrityError::EntryNotFound),
-        },
+    let (latest, _) = find_latest_revision(&get_original_action(entry_hash)?)?;
+    Ok(get_action_hash(&latest))
+}
+
+/// Retrieve the `ActionHash` of every live branch head in the revision history of the given EntryHash.
+///
+/// A record which has not been concurrently edited will have a single head.
+///
+pub fn get_revision_heads(entry_hash: EntryHash) -> RecordAPIResult<Vec<ActionHash>> {
+    Ok(find_revision_heads(&get_original_action(entry_hash)?)?
+        .iter()
+        .map(|(head, _)| get_action_hash(head))
+        .collect())
+}
+
+/// Retrieve the action which originally created the entry with the given EntryHash.
+///
+/// The action is returned even if the record has since been deleted, so that its history remains
+/// accessible. Deleted records have no live revisions, which `find_latest_revision` accounts for.
+///
+fn get_original_action(entry_hash: EntryHash) -> RecordAPIResult<SignedActionHashed> {
+    match get_details(entry_hash, GetOptions { strategy: GetStrategy::Latest })? {
+        // https://docs.rs/hdk/latest/hdk/prelude/struct.EntryDetails.html#structfield.actions
+        Some(Details::Entry(details)) => details.actions.first().cloned().ok_or(DataIntegrityError::EntryNotFound),
         _ => Err(DataIntegrityError::EntryNotFound),
     }
 }
 
+/// Ensure that the given revision of a record may be superseded without forking or discarding
+/// any part of the record's revision history, returning any other heads which it merges.
+///
+/// With no `conflicting_revisions`, `revision_id` must be the only branch head. Otherwise they must
+/// list every branch head, including `revision_id`. If this is not the case the update is rejected with
+/// `DataIntegrityError::UpdateConflict`, referencing the record's current branch heads.
+///
+pub (crate) fn ensure_revision_heads(
+    identity_hash: &EntryHash,
+    revision_id: &ActionHash,
+    conflicting_revisions: Option<&[ActionHash]>,
+) -> RecordAPIResult<Vec<ActionHash>> {
+    let current_heads = get_revision_heads(identity_hash.to_owned())?;
+    if current_heads.is_empty() {
+        return Err(DataIntegrityError::EntryNotFound);
+    }
+
+    let expected_heads = conflicting_revisions.map(|heads| heads.to_vec()).unwrap_or_else(|| vec![revision_id.to_owned()]);
+    if !current_heads.contains(revision_id)
+        || current_heads.len() != expected_heads.len()
+        || !expected_heads.iter().all(|head| current_heads.contains(head))
+    {
+        return Err(DataIntegrityError::UpdateConflict(current_heads));
+    }
+
+    Ok(current_heads.into_iter().filter(|head| head != revision_id).collect())
+}
+
 /// Retrive the specific version of an entry specified by the given `ActionHash`
 ///
 pub fn read_record_entry_by_action<T, R, B>(
@@ -88,11 +122,8 @@ pub fn read_record_entry_by_action<T, R, B>(
 
 /// Read a record's entry data by its identity index
 ///
-/// :TODO: Currently, the most recent version of the given entry will
-///        be provided instead of the exact entry specified.
-///        We should also check for multiple live actions, and throw a
-///        conflict error if necessary. But core may implement this for
-///        us eventually. (@see EntryDhtStatus)
+/// The most recent version of the given entry will be provided. If the record has conflicting
+/// revisions, this is the most recent of them (@see `get_latest_action_hash`).
 ///
 pub (crate) fn read_record_entry_by_identity<T, R, B>(
     identity_address: &EntryHash,
@@ -133,6 +164,99 @@ pub fn read_record_entry<T, R, B>(
     read_record_entry_by_identity::<T, R, B>(&identity_address)
 }
 
+/// Read the entry data of a record as it was when originally created, regardless of
+/// any revisions or deletion since.
+///
+/// Presumes that the record is stored in the current DNA.
+///
+pub fn read_record_entry_original<T, R, B>(
+    address: &EntryHash,
+) -> RecordAPIResult<(SignedActionHashed, B, T)>
+    where T: std::fmt::Debug,
+        B: DnaAddressable<EntryHash>,
+        SerializedBytes: TryInto<R, Error = SerializedBytesError> + TryInto<B, Error = SerializedBytesError>,
+        Entry: TryFrom<R>,
+        R: std::fmt::Debug + Identified<T, B>,
+{
+    let identity_address = calculate_identity_address(&B::new(dna_info()?.hash, address.clone()))?;
+    read_record_entry_by_action::<T, R, B>(get_original_action(identity_address)?.as_hash())
+}
+
+/// Read a page of the revision history of a record by its identity index
+///
+/// @see hdk_records::metadata::read_revision_history
+///
+pub (crate) fn read_revision_history_by_identity<T>(
+    identity_address: &EntryHash,
+    page: &RevisionHistoryParams<T>,
+) -> RecordAPIResult<RevisionHistory>
+{
+    read_revision_history(&get_original_action(identity_address.to_owned())?, page)
+}
+
+/// Read a page of the revision history of the record identified by `page.address`,
+/// including the deletion of the record if it has been deleted.
+///
+/// Presumes that the record is stored in the current DNA.
+///
+pub fn read_record_revision_history<B>(
+    page: &RevisionHistoryParams<B>,
+) -> RecordAPIResult<RevisionHistory>
+    where B: DnaAddressable<EntryHash>,
+{
+    let identity_address = calculate_identity_address(&page.address)?;
+    read_revision_history_by_identity(&identity_address, page)
+}
+
+/// Response fields which describe the revision being read rather than the record's data,
+/// and so differ between any two revisions.
+///
+const REVISION_RESPONSE_FIELDS: [&str; 2] = ["revisionId", "meta"];
+
+/// Compare two revisions of a record, returning the fields of its RPC response which changed
+/// between `from_revision_id` and `to_revision_id` along with their old and new values.
+///
+/// `construct_response` builds the zome's response representation for each revision, so that
+/// field names and values match those of a regular record read. Fields describing the revision
+/// itself (`revisionId` and `meta`) are not compared.
+///
+/// Link fields are read from indexes which only reflect the record's current state, and so would be
+/// identical for both revisions. `construct_response` should leave them empty rather than query them.
+///
+/// Both revisions must belong to the same record.
+///
+pub fn diff_revisions<T, R, B, S, F>(
+    from_revision_id: &ActionHash,
+    to_revision_id: &ActionHash,
+    construct_response: F,
+) -> RecordAPIResult<RevisionDiff>
+    where T: std::fmt::Debug,
+        B: DnaAddressable<EntryHash>,
+        S: std::fmt::Debug + Serialize,
+        F: Fn(&B, &SignedActionHashed, &T) -> RecordAPIResult<S>,
+        SerializedBytes: TryInto<R, Error = SerializedBytesError>,
+        Entry: TryFrom<R>,
+        R: std::fmt::Debug + Identified<T, B>,
+{
+    let (from_meta, from_identity, from_entry) = read_record_entry_by_action::<T, R, B>(from_revision_id)?;
+    let (to_meta, to_identity, to_entry) = read_record_entry_by_action::<T, R, B>(to_revision_id)?;
+
+    if from_identity != to_identity {
+        return Err(DataIntegrityError::MismatchingRevisions(from_revision_id.to_owned(), to_revision_id.to_owned()));
+    }
+
+    let from_response = construct_response(&from_identity, &from_meta, &from_entry)?;
+    let to_response = construct_response(&to_identity, &to_meta, &to_entry)?;
+
+    Ok(RevisionDiff {
+        from_revision: (&from_meta).into(),
+        to_revision: (&to_meta).into(),
+        changes: diff_entries(&from_response, &to_response)?.into_iter()
+            .filter(|change| !REVISION_RESPONSE_FIELDS.contains(&change.field.as_str()))
+            .collect(),
+    })
+}
+
 //-------------------------------[ CREATE ]-------------------------------------
 
 /// Creates a new record in the DHT, assigns it an identity index (@see identity_helpers.rs)
@@ -183,7 +307,8 @@ pub fn create_record<T, I, R: Clone, B, C, E, S, F, G>(
 /// The way in which the input update payload is applied to the existing
 /// entry data is up to the implementor of `Updateable<U>` for the entry type.
 ///
-/// :TODO: prevent multiple updates to the same ActionHash under standard operations
+/// Only the latest revision of a record may be updated. Updates to earlier revisions, or to
+/// records with conflicting revisions, fail with `DataIntegrityError::UpdateConflict`.
 ///
 /// @see hdk_records::record_interface::Updateable
 ///
@@ -197,35 +322,105 @@ pub fn update_record<I, R: Clone, B, U, E>(
         Entry: TryFrom<R, Error = E>,
         R: Clone + Identified<I, B>,
         SerializedBytes: TryInto<R, Error = SerializedBytesError>,
+{
+    update_or_resolve_record(address, None, update_payload)
+}
+
+/// Resolves conflicting revisions of a record by writing a merge revision.
+///
+/// `heads` must list every conflicting revision, as reported by `DataIntegrityError::UpdateConflict`
+/// or `RecordMeta.conflicting_revisions`. The `update_payload` is applied to the head identified by
+/// `revision_id` in order to form the merge revision, which records all other heads as superseded
+/// by it. They then no longer count as heads when traversing the revision history.
+///
+/// If `heads` does not match the record's current branch heads (for example, because further
+/// edits have arrived since the conflict was reported) a new `DataIntegrityError::UpdateConflict`
+/// is returned and nothing is written.
+///
+pub fn resolve_conflict<I, R: Clone, B, U, E>(
+    revision_id: &ActionHash,
+    heads: &[ActionHash],
+    update_payload: U,
+) -> RecordAPIResult<(SignedActionHashed, B, I, I)>
+    where B: DnaAddressable<EntryHash>,
+        I: Identifiable<R> + Updateable<U>,
+        WasmError: From<E>,
+        Entry: TryFrom<R, Error = E>,
+        R: Clone + Identified<I, B>,
+        SerializedBytes: TryInto<R, Error = SerializedBytesError>,
+{
+    update_or_resolve_record(revision_id, Some(heads), update_payload)
+}
+
+/// Updates a record as for `update_record` if no `conflicting_revisions` are given, or
+/// resolves them as for `resolve_conflict` otherwise.
+///
+/// Allows record zomes to share the same update logic between both operations.
+///
+pub fn update_or_resolve_record<I, R: Clone, B, U, E>(
+    revision_id: &ActionHash,
+    conflicting_revisions: Option<&[ActionHash]>,
+    update_payload: U,
+) -> RecordAPIResult<(SignedActionHashed, B, I, I)>
+    where B: DnaAddressable<EntryHash>,
+        I: Identifiable<R> + Updateable<U>,
+        WasmError: From<E>,
+        Entry: TryFrom<R, Error = E>,
+        R: Clone + Identified<I, B>,
+        SerializedBytes: TryInto<R, Error = SerializedBytesError>,
 {
     // get referenced entry for the given action
-    let (_meta, previous): (_, R) = get_entry_by_action(address)?;
+    let (_meta, previous): (_, R) = get_entry_by_action(revision_id)?;
     let prev_entry = previous.entry();
     let identity = previous.identity()?;
     let identity_hash: &EntryHash = identity.as_ref();
 
+    // prevent forking the revision history or discarding any concurrent edits
+    let merges = ensure_revision_heads(identity_hash, revision_id, conflicting_revisions)?;
+
     // apply update payload
     let new_entry = prev_entry.update_with(update_payload)?;
-    let storage: R = new_entry.with_identity(Some(identity_hash.clone()));
+    let storage: R = new_entry.with_merged_identity(Some(identity_hash.clone()), merges);
 
     // perform regular entry update using internal address
-    let (meta, _entry_addr) = update_entry(address, storage)?;
+    let (meta, _entry_addr) = update_entry(revision_id, storage)?;
 
     Ok((meta, identity, new_entry, prev_entry))
 }
 
 //-------------------------------[ DELETE ]-------------------------------------
 
-/// Removes a record of the given `ActionHash` from the DHT by marking it as deleted.
+/// Removes a record of the given `ActionHash` from the DHT by marking it as deleted,
+/// and removes it from the time-ordered indexes of its companion index zome.
 ///
 /// Links are not affected so as to retain a link to the referencing information, which may now need to be updated.
 ///
-pub fn delete_record<T>(address: &ActionHash) -> RecordAPIResult<bool>
-    where SerializedBytes: TryInto<T, Error = SerializedBytesError>,
+pub fn delete_record<T, I, B, S, F, C>(
+    indexing_zome_name_from_config: F,
+    entry_def_id: S,
+    address: &ActionHash,
+) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+        B: DnaAddressable<EntryHash>,
+        T: Identified<I, B>,
+        Entry: TryFrom<T>,
+        SerializedBytes: TryInto<T, Error = SerializedBytesError>,
+        F: FnOnce(C) -> Option<String>,
+        C: std::fmt::Debug,
+        SerializedBytes: TryInto<C, Error = SerializedBytesError>,
 {
     // :TODO: handle deletion of the identity `Path` for the referenced entry if this is the last action being deleted
+    let (_meta, entry): (_, T) = get_entry_by_action(address)?;
+    let identity: B = entry.identity()?;
 
     delete_entry::<T>(address)?;
+
+    // :TODO: move this to a postcommit hook in coordination zome; see #264
+    delete_entry_identity(
+        indexing_zome_name_from_config,
+        &entry_def_id, &identity,
+    )?;
+
     Ok(true)
 }
 
@@ -298,10 +493,51 @@ mod tests {
         assert_eq!(action_addr_3, updated_action_addr, "record revision should be same as latest update");
         assert_eq!(third_entry, Entry { field: Some("value".into()) }, "retrieved record should be changed after update");
 
+        // Verify superseded revisions cannot be updated
+        let conflict = update_record::<Entry, EntryWithIdentity, EntryId,_,_>(&action_addr, UpdateRequest { field: Some("stale".into()) }).err().unwrap();
+        assert!(matches!(conflict, DataIntegrityError::UpdateConflict(_)), "updating a superseded revision should be a conflict");
+
         // DELETE
-        let _ = delete_record::<Entry>(&updated_action_addr);
+        let _ = delete_record::<EntryWithIdentity,_,EntryId,_,_,_>(indexing_zome_name_from_config, &entry_type, &updated_action_addr);
 
         // Verify read failure
         let _failure = read_record_entry::<Entry, EntryWithIdentity, EntryId,_,_>(&identity_address).err().unwrap();
     }
+
+    #[test]
+    fn test_conflict_resolution() {
+        let entry_type: String = "testing".to_string();
+        let (action_addr, base_address, _initial_entry): (_, EntryId, Entry) = create_record(indexing_zome_name_from_config, &entry_type, CreateRequest { field: None }).unwrap();
+        let identity_hash: &EntryHash = base_address.as_ref();
+
+        // fork the revision history, as would happen with concurrent edits by disconnected agents
+        let (head_1, _) = update_entry(&action_addr, Entry { field: Some("one".into()) }.with_identity(Some(identity_hash.clone()))).unwrap();
+        let (head_2, _) = update_entry(&action_addr, Entry { field: Some("two".into()) }.with_identity(Some(identity_hash.clone()))).unwrap();
+        let mut heads = get_revision_heads(identity_hash.clone()).unwrap();
+        assert_eq!(heads.len(), 2, "both concurrent revisions should be heads");
+
+        // Verify reads pick the most recent head, rather than failing
+        let (read_action, _, read_entry) = read_record_entry::<Entry, EntryWithIdentity, EntryId,_,_>(identity_hash).unwrap();
+        assert_eq!(read_action.as_hash(), head_2.as_hash(), "reads should return the most recent head");
+        assert_eq!(read_entry, Entry { field: Some("two".into()) }, "reads should return data of the most recent head");
+
+        // Verify updates are rejected until the conflict is resolved
+        let conflict = update_record::<Entry, EntryWithIdentity, EntryId,_,_>(head_2.as_hash(), UpdateRequest { field: Some("stale".into()) }).err().unwrap();
+        assert!(matches!(conflict, DataIntegrityError::UpdateConflict(_)), "updating a conflicted record should be a conflict");
+        let conflict = resolve_conflict::<Entry, EntryWithIdentity, EntryId,_,_>(head_1.as_hash(), &heads[0..1], UpdateRequest { field: Some("partial".into()) }).err().unwrap();
+        assert!(matches!(conflict, DataIntegrityError::UpdateConflict(_)), "resolving only some conflicting heads should be a conflict");
+
+        // RESOLVE
+        let (merge_action, _, merged_entry, _): (_, EntryId, Entry, Entry) = resolve_conflict(head_1.as_hash(), &heads, UpdateRequest { field: Some("merged".into()) }).unwrap();
+        assert_eq!(merged_entry, Entry { field: Some("merged".into()) }, "merge revision should be written");
+        heads = get_revision_heads(identity_hash.clone()).unwrap();
+        assert_eq!(heads, vec![merge_action.as_hash().to_owned()], "merge revision should be the only head");
+        let (_, _, read_entry) = read_record_entry::<Entry, EntryWithIdentity, EntryId,_,_>(identity_hash).unwrap();
+        assert_eq!(read_entry, merged_entry, "reads should return the merge revision");
+
+        // Verify superseded heads are not recorded as deletions
+        let history = read_record_revision_history(&RevisionHistoryParams { address: base_address.clone(), first: None, after: None, last: None, before: None }).unwrap();
+        assert_eq!(history.total_count, 4, "history should contain the original, both conflicting revisions and the merge");
+        assert!(history.revisions.iter().all(|r| r.revision_type != crate::RevisionType::Delete), "superseded revisions should not be deleted");
+    }
 }
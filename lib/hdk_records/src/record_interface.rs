@@ -41,6 +41,13 @@ pub trait Identified<T, A>
 ///
 pub trait Identifiable<T> {
     fn with_identity(&self, id_hash: Option<EntryHash>) -> T;
+
+    /// As for `with_identity`, but also records the revisions of the record which this
+    /// one supersedes in addition to the revision it updates.
+    ///
+    /// @see hdk_records::records::resolve_conflict
+    ///
+    fn with_merged_identity(&self, id_hash: Option<EntryHash>, merges: Vec<ActionHash>) -> T;
 }
 
 /// Compose an `Identified` structure around the provided entry struct, in order to provide
@@ -60,6 +67,8 @@ macro_rules! generate_record_entry {
                 pub struct $to {
                     entry: $t,
                     id_hash: Option<$crate::EntryHash>, // :NOTE: None for first record
+                    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                    merges: Vec<$crate::ActionHash>, // :NOTE: only set on revisions resolving conflicts
                 }
 
                 impl $crate::record_interface::Identified<$t, $id> for $to
@@ -86,10 +95,16 @@ macro_rules! generate_record_entry {
                 impl $crate::record_interface::Identifiable<$to> for $t
                 {
                     fn with_identity(&self, id_hash: Option<$crate::EntryHash>) -> $to
+                    {
+                        self.with_merged_identity(id_hash, vec![])
+                    }
+
+                    fn with_merged_identity(&self, id_hash: Option<$crate::EntryHash>, merges: Vec<$crate::ActionHash>) -> $to
                     {
                         $to {
                             entry: self.to_owned(),
                             id_hash,
+                            merges,
                         }
                     }
                 }
@@ -152,6 +167,7 @@ mod tests {
             TestEntryWithIdentity {
                 entry: TestEntry { field: None },
                 id_hash: None,
+                merges: vec![],
             }
         );
         assert_eq!(
@@ -27,6 +27,7 @@
  * @since   2020-08-07
  */
 use std::collections::HashMap;
+use chrono::{DateTime, Utc};
 use hdk::prelude::*;
 use holo_hash::DnaHash;
 use hdk_records::{
@@ -38,7 +39,7 @@ use hdk_records::{
     },
 };
 use hdk_semantic_indexes_zome_rpc::{
-    ByAddress,
+    ByAddress, AppendAddress,
     RemoteEntryLinkRequest, RemoteEntryLinkResponse,
 };
 use hc_zome_dna_auth_resolver_lib::AvailableCapability;
@@ -116,7 +117,7 @@ macro_rules! update_string_index {
     (
         $record_type:ident($record_id:expr).$rel:ident.not($remove_string_ids:expr)<$addressable_type:ident>
     ) => { {
-        let string_hashes: Vec<$addressable_type> = string_index_hashes($dest_string_ids)?;
+        let string_hashes: Vec<$addressable_type> = string_index_hashes($remove_string_ids)?;
         paste! {
             manage_index::<EntryTypes, _, _, _, _, _, _, _, _, _>(
                 [<read_ $record_type:lower:snake _index_zome>],
@@ -125,8 +126,9 @@ macro_rules! update_string_index {
                 |_| { None }, // specify none for destination index
                 &"", // ignored, since no index zome name is returned
                 &"", // ignored, since no index zome name is returned
-                &vec![].as_slice(),
+                vec![].as_slice(),
                 string_hashes.as_slice(),
+                LinkTypes::AvailableCapability
             )
         }
     } };
@@ -146,6 +148,7 @@ macro_rules! update_string_index {
                 &"", // ignored, since no index zome name is returned
                 dest_string_hashes.as_slice(),
                 remove_string_hashes.as_slice(),
+                LinkTypes::AvailableCapability
             )
         }
     } };
@@ -288,8 +291,96 @@ macro_rules! update_index {
     };
 }
 
+/// Order a record by some timestamp field, in a time index declared on its index zome
+/// via `#[time_index(field)]`. Records already present in the index are moved to the new time.
+///
+#[macro_export]
+macro_rules! index_time {
+    (
+        $record_type:ident($record_id:expr).$field:ident($timestamp:expr)
+    ) => {
+        paste! {
+            append_time_index(
+                [<read_ $record_type:lower:snake _index_zome>],
+                &stringify!([<record_ $record_type:lower:snake _ $field:lower:snake>]),
+                $record_id,
+                $timestamp,
+            )
+        }
+    };
+}
+
+/// Remove a record from a time index declared via `#[time_index(field)]`, for when
+/// its timestamp field has been cleared.
+///
+#[macro_export]
+macro_rules! unindex_time {
+    (
+        $record_type:ident($record_id:expr).$field:ident
+    ) => {
+        paste! {
+            remove_time_index(
+                [<read_ $record_type:lower:snake _index_zome>],
+                &stringify!([<unrecord_ $record_type:lower:snake _ $field:lower:snake>]),
+                $record_id,
+            )
+        }
+    };
+}
+
 //-------------------------------[ CREATE ]-------------------------------------
 
+/// Add a record to a time index in its companion index zome, ordered by `timestamp`.
+///
+/// Returns `false` if the record was already present in the index.
+///
+/// @see index_time!
+///
+pub fn append_time_index<C, F, A, S, T>(
+    zome_name_from_config: F,
+    append_fn_name: &S,
+    address: &A,
+    timestamp: T,
+) -> RecordAPIResult<bool>
+    where S: AsRef<str>,
+        C: std::fmt::Debug,
+        SerializedBytes: TryInto<C, Error = SerializedBytesError>,
+        F: FnOnce(C) -> Option<String>,
+        A: DnaAddressable<EntryHash>,
+        T: Into<DateTime<Utc>>,
+{
+    Ok(call_local_zome_method(
+        zome_name_from_config, append_fn_name,
+        AppendAddress {
+            address: address.to_owned(),
+            timestamp: timestamp.into(),
+        },
+    )?)
+}
+
+/// Remove a record from a time index in its companion index zome.
+///
+/// Returns `false` if the record was not present in the index.
+///
+/// @see unindex_time!
+///
+pub fn remove_time_index<C, F, A, S>(
+    zome_name_from_config: F,
+    remove_fn_name: &S,
+    address: &A,
+) -> RecordAPIResult<bool>
+    where S: AsRef<str>,
+        C: std::fmt::Debug,
+        SerializedBytes: TryInto<C, Error = SerializedBytesError>,
+        F: FnOnce(C) -> Option<String>,
+        A: DnaAddressable<EntryHash>,
+{
+    Ok(call_local_zome_method(
+        zome_name_from_config, remove_fn_name,
+        ByAddress { address: address.to_owned() },
+    )?)
+}
+
 /// Outer method for creating indexes.
 ///
 /// :TODO: documentation
@@ -17,6 +17,8 @@ pub enum SemanticIndexError {
     BadStringIndexError(Vec<u8>),
     #[error("Time indexing error {0}")]
     BadTimeIndexError(String),
+    #[error("Invalid pagination cursor {0}")]
+    InvalidCursor(String),
 }
 
 impl From<FromUtf8Error> for SemanticIndexError {
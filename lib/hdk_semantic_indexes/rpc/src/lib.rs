@@ -23,6 +23,25 @@ pub struct ByAction {
 pub struct ByRevision {
     pub revision_id: ActionHash,
 }
+
+/// Compare two revisions of the same record
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ByRevisionPair {
+    pub from_revision_id: ActionHash,
+    pub to_revision_id: ActionHash,
+}
+
+/// Query the revision history of a record, paging by the `RevisionMeta.id` of previously returned revisions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevisionHistoryParams<T> {
+    pub address: T,
+    pub first: Option<usize>,
+    pub after: Option<ActionHash>,
+    pub last: Option<usize>,
+    pub before: Option<ActionHash>,
+}
+
 /// Shared parameter struct that all related record storage endpoints must implement
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ByAddress<T> {
@@ -36,6 +55,24 @@ pub struct AppendAddress<T> {
     pub timestamp: DateTime<Utc>,
 }
 
+/// Boolean combinators for index queries, where `P` is the `QueryParams` struct of the index zome.
+///
+/// Each set of params matches records satisfying *all* of its provided fields. Results must then
+/// match every set in `and`, at least one set in `or` (if any are given), and none of the sets in `not`.
+/// Sets which provide no fields are ignored.
+///
+/// A query consisting only of `not` filters must scan every record in the index, so should be
+/// combined with some positive filter wherever possible.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QueryFilter<P> {
+    #[serde(default)]
+    pub and: Vec<P>,
+    #[serde(default)]
+    pub or: Vec<P>,
+    #[serde(default)]
+    pub not: Vec<P>,
+}
+
 /// Common request format (zome trait) for linking remote entries in cooperating DNAs
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RemoteEntryLinkRequest<A, B>
@@ -5,21 +5,26 @@
  * @package hdk_semantic_indexes
  * @since   2021-09-30
  */
-use chrono::{DateTime, Utc};
+pub use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use hdk::prelude::*;
-use holo_hash::{DnaHash, HOLO_HASH_FULL_LEN};
+use holo_hash::{DnaHash, EntryHashB64, HOLO_HASH_FULL_LEN};
 use hdk_records::{
+    DataIntegrityError,
     identities::calculate_identity_address,
     rpc::call_local_zome_method,
 };
-use hdk_time_indexing::{ index_entry };
+use hdk_time_indexing::{ index_entry, unindex_entry };
 pub use hdk_semantic_indexes_error::*;
 pub use hdk_time_indexing::{
     TimeIndex,
     TimeIndexingError,
     read_all_entry_hashes,
-    // get_latest_entry_hashes,
-    // get_older_entry_hashes,
+    get_latest_entry_hashes,
+    get_earliest_entry_hashes,
+    get_older_entry_hashes,
+    get_newer_entry_hashes,
+    get_entry_hashes_between,
     sort_entries_by_time_index,
 };
 pub use hdk_records::{
@@ -103,9 +108,7 @@ pub fn query_index<'a, T, O, C, F, A, S, I, J>(
         SerializedBytes: TryInto<C, Error = SerializedBytesError> + TryInto<O, Error = SerializedBytesError>,
         F: Fn(C) -> Option<String>,
 {
-    let index_address = calculate_identity_address(base_address)?;
-    let mut addrs_result = get_linked_addresses(&index_address, LinkTag::new(link_tag.as_ref()))?;
-    addrs_result.sort_by(sort_entries_by_time_index(order_by_time_index));
+    let addrs_result = query_index_identities(base_address, link_tag, order_by_time_index)?;
 
     let entries = retrieve_foreign_records::<T, O, C, F, J>(
         foreign_zome_name_from_config,
@@ -115,48 +118,331 @@ pub fn query_index<'a, T, O, C, F, A, S, I, J>(
     Ok(entries)
 }
 
-/// Query foreign entries pointers from a time-ordered index, in order from most recent to oldest.
+/// Given a base address to query from, returns the identity addresses of all records
+/// referenced via the given link tag, sorted in reverse creation order.
 ///
-/// If `start_from` is provided, the given `EntryHash` is used to determine the starting location
-/// for reading results. Otherwise the newest entries (as determined by their ordering in the time
-/// index) are returned.
+/// Use this method to build up sets of query results prior to retrieving records
+/// with `retrieve_foreign_records`.
 ///
-/// Full entry data is returned by querying from the associated record storage zome determined by
-/// `zome_name_from_config` and `read_method_name`.
+pub fn query_index_identities<'a, A, S, I>(
+    base_address: &A,
+    link_tag: &S,
+    order_by_time_index: &I,
+) -> RecordAPIResult<Vec<EntryHash>>
+    where I: AsRef<str> + std::fmt::Debug,
+        S: 'a + AsRef<[u8]> + ?Sized + std::fmt::Debug,
+        A: DnaAddressable<EntryHash>,
+{
+    let index_address = calculate_identity_address(base_address)?;
+    let mut addrs_result = get_linked_addresses(&index_address, LinkTag::new(link_tag.as_ref()))?;
+    addrs_result.sort_by(sort_entries_by_time_index(order_by_time_index));
+    Ok(addrs_result)
+}
+
+/// Combine the results of a set of `QueryParams` and an optional `QueryFilter` into a single
+/// set of record identity addresses.
 ///
-pub fn query_time_index<'a, T, B, C, F, I>(
-    zome_name_from_config: &'a F,
-    read_method_name: &I,
+/// `match_params` must return the identities matching *all* provided fields of a set of params,
+/// or `None` if no fields were provided. Sets of params with no fields are ignored wherever they
+/// appear, and an `or` filter in which no set provides any fields does not restrict the results.
+///
+/// `all_identities` is only called when a query consists solely of exclusions, to determine the
+/// full set of records to exclude from. Note that this means a `not`-only query reads every record
+/// in the index (eg. via `read_all_entry_hashes`) before filtering, and so scales with the size of
+/// the index rather than the size of the result set. Prefer combining `not` with a positive filter.
+///
+/// If `within` is provided (eg. the records created during some period), results are
+/// further restricted to that set.
+///
+pub fn combine_query_results<P, F, G>(
+    params: &P,
+    filter: &Option<QueryFilter<P>>,
+    within: Option<Vec<EntryHash>>,
+    match_params: F,
+    all_identities: G,
+) -> RecordAPIResult<Vec<EntryHash>>
+    where F: Fn(&P) -> RecordAPIResult<Option<Vec<EntryHash>>>,
+        G: FnOnce() -> RecordAPIResult<Vec<EntryHash>>,
+{
+    let mut result = within;
+    if let Some(matches) = match_params(params)? {
+        result = Some(intersect_identities(result, &matches));
+    }
+
+    if let Some(QueryFilter { and, or, not }) = filter {
+        for and_params in and {
+            if let Some(matches) = match_params(and_params)? {
+                result = Some(intersect_identities(result, &matches));
+            }
+        }
+
+        let mut any_matches: Option<Vec<EntryHash>> = None;
+        for or_params in or {
+            if let Some(matches) = match_params(or_params)? {
+                any_matches = Some(union_identities(any_matches.unwrap_or_default(), matches));
+            }
+        }
+        if let Some(any_matches) = any_matches {
+            result = Some(intersect_identities(result, &any_matches));
+        }
+
+        if !not.is_empty() {
+            let mut excluded = vec![];
+            for not_params in not {
+                if let Some(matches) = match_params(not_params)? {
+                    excluded = union_identities(excluded, matches);
+                }
+            }
+            let candidates = match result {
+                Some(r) => r,
+                None => all_identities()?,
+            };
+            let excluded: HashSet<&EntryHash> = excluded.iter().collect();
+            result = Some(candidates.into_iter().filter(|addr| !excluded.contains(addr)).collect());
+        }
+    }
+
+    result.ok_or(SemanticIndexError::EmptyQuery.into())
+}
+
+/// Restrict a (possibly not yet started) set of query results to those also present in `matches`.
+/// Ordering of the existing results is preserved.
+///
+pub fn intersect_identities(results: Option<Vec<EntryHash>>, matches: &[EntryHash]) -> Vec<EntryHash>
+{
+    match results {
+        None => matches.to_vec(),
+        Some(r) => {
+            let matches: HashSet<&EntryHash> = matches.iter().collect();
+            r.into_iter().filter(|addr| matches.contains(addr)).collect()
+        },
+    }
+}
+
+/// Append any identities in `matches` not already present in `results`
+///
+pub fn union_identities(mut results: Vec<EntryHash>, matches: Vec<EntryHash>) -> Vec<EntryHash>
+{
+    let mut seen: HashSet<EntryHash> = results.iter().cloned().collect();
+    for addr in matches {
+        if seen.insert(addr.clone()) {
+            results.push(addr);
+        }
+    }
+    results
+}
+
+/// Read the identities of all records indexed in the `index_name` time index at or after `from`
+/// and before `to`, ordered from most recent to oldest. Returns `None` if neither bound is given.
+///
+pub fn read_time_index_range<I>(
     index_name: &I,
-    _start_from: Option<EntryHash>,
-    _limit: usize,
-) -> RecordAPIResult<Vec<RecordAPIResult<T>>>
-    where T: serde::de::DeserializeOwned + std::fmt::Debug,
-        B: DnaAddressable<EntryHash> + TryFrom<SerializedBytes, Error = SerializedBytesError>,
-        I: AsRef<str> + std::fmt::Display + std::fmt::Debug,
-        C: std::fmt::Debug,
-        SerializedBytes: TryInto<C, Error = SerializedBytesError> + TryInto<B, Error = SerializedBytesError>,
-        F: Fn(C) -> Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> RecordAPIResult<Option<Vec<EntryHash>>>
+    where I: AsRef<str>,
 {
-    // this algorithm is the 'make it work' current pass, pending the full implementation mentioned
-    // in the TODO below, regarding efficiency and completeness
-    let linked_records = read_all_entry_hashes(index_name)
-        .map_err(|e| { SemanticIndexError::BadTimeIndexError(e.to_string()) })?;
-
-    // :TODO: efficient paginated retrieval
-    // let linked_records = match start_from {
-    //     None => get_latest_entry_hashes(index_name, limit),
-    //     Some(cursor) => get_older_entry_hashes(index_name, cursor, limit),
-    // }.map_err(|e| { SemanticIndexError::BadTimeIndexError(e.to_string()) })?;
-
-    let read_single_record = retrieve_foreign_record::<T, B, _,_,_>(zome_name_from_config, read_method_name);
-
-    Ok(linked_records.iter()
-        .map(|addr| {
-            // query full record from the associated CRUD zome
-            read_single_record(addr)
+    if from.is_none() && to.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(get_entry_hashes_between(
+        index_name,
+        from.unwrap_or(DateTime::<Utc>::MIN_UTC),
+        to.unwrap_or(DateTime::<Utc>::MAX_UTC),
+        usize::MAX,
+    ).map_err(|e| { SemanticIndexError::BadTimeIndexError(e.to_string()) })?))
+}
+
+//--------------------------------[ PAGINATION ]--------------------------------------
+
+/// Relay-style pagination arguments for lists ordered from most recent to oldest.
+///
+/// Paging forwards (`first` / `after`) moves back in time from the `after` cursor, or from the
+/// most recent entry if no cursor is given. Paging backwards (`last` / `before`) moves towards
+/// the present from the `before` cursor, or from the oldest entry if no cursor is given.
+///
+#[derive(Debug, Clone, Default)]
+pub struct PageRequest {
+    pub first: Option<usize>,
+    pub after: Option<EntryHash>,
+    pub last: Option<usize>,
+    pub before: Option<EntryHash>,
+}
+
+impl PageRequest {
+    /// Construct a `PageRequest` from externally provided `Edge.cursor` values
+    pub fn new(first: Option<usize>, after: Option<String>, last: Option<usize>, before: Option<String>) -> RecordAPIResult<Self> {
+        Ok(Self {
+            first,
+            after: after.map(|c| parse_cursor(&c)).transpose()?,
+            last,
+            before: before.map(|c| parse_cursor(&c)).transpose()?,
         })
-        .collect())
+    }
+
+    fn is_backwards(&self) -> bool {
+        self.last.is_some() || self.before.is_some()
+    }
+}
+
+/// A page of record identities, ordered from most recent to oldest
+#[derive(Debug, Clone)]
+pub struct IdentityPage {
+    pub identities: Vec<EntryHash>,
+    // true if there are more recent results than those in this page
+    pub has_previous_page: bool,
+    // true if there are older results than those in this page
+    pub has_next_page: bool,
+    pub page_limit: usize,
+    pub total_count: Option<usize>,
+}
+
+/// Decode a cursor returned in `Edge.cursor` (the `Display` encoding of a record ID) into
+/// the identity `EntryHash` of the record.
+///
+pub fn parse_cursor(cursor: &str) -> RecordAPIResult<EntryHash>
+{
+    let hash_str = cursor.split(':').next().unwrap_or(cursor);
+    EntryHashB64::from_b64_str(hash_str)
+        .map(EntryHash::from)
+        .map_err(|_e| SemanticIndexError::InvalidCursor(cursor.to_string()).into())
+}
+
+/// Read a page of entries from a time-ordered index without loading the rest of the index.
+///
+/// One more entry than requested is read in the direction of travel in order to determine whether
+/// further pages exist. In the opposite direction, the index is checked for a single entry beyond
+/// the nearest result of the page.
+///
+pub fn read_time_index_page<I>(
+    index_name: &I,
+    page: &PageRequest,
+    default_limit: usize,
+) -> RecordAPIResult<IdentityPage>
+    where I: AsRef<str>,
+{
+    let to_index_error = |e: TimeIndexingError| -> DataIntegrityError { SemanticIndexError::BadTimeIndexError(e.to_string()).into() };
+
+    if page.is_backwards() {
+        page_time_index(
+            page, default_limit,
+            |cursor, limit| match cursor {
+                Some(cursor) => get_newer_entry_hashes(index_name, cursor, limit),
+                None => get_earliest_entry_hashes(index_name, limit),
+            }.map_err(to_index_error),
+            |nearest| get_older_entry_hashes(index_name, nearest, 1).map_err(to_index_error),
+        )
+    } else {
+        page_time_index(
+            page, default_limit,
+            |cursor, limit| match cursor {
+                Some(cursor) => get_older_entry_hashes(index_name, cursor, limit),
+                None => get_latest_entry_hashes(index_name, limit),
+            }.map_err(to_index_error),
+            |nearest| get_newer_entry_hashes(index_name, nearest, 1).map_err(to_index_error),
+        )
+    }
+}
+
+/// Assemble an `IdentityPage` for `page` from a time-ordered index.
+///
+/// `read_onwards` reads up to `limit` entries in the direction of travel from the given cursor (or from the
+/// end of the index), nearest first. `read_behind` reads an entry (if any) before the given one in the
+/// opposite direction.
+///
+fn page_time_index<R, B>(
+    page: &PageRequest,
+    default_limit: usize,
+    read_onwards: R,
+    read_behind: B,
+) -> RecordAPIResult<IdentityPage>
+    where R: Fn(Option<EntryHash>, usize) -> RecordAPIResult<Vec<EntryHash>>,
+        B: Fn(EntryHash) -> RecordAPIResult<Vec<EntryHash>>,
+{
+    let (limit, cursor) = match page.is_backwards() {
+        true => (page.last.unwrap_or(default_limit), page.before.to_owned()),
+        false => (page.first.unwrap_or(default_limit), page.after.to_owned()),
+    };
+
+    let mut identities = read_onwards(cursor.to_owned(), limit + 1)?;
+    let has_more_onwards = identities.len() > limit;
+    identities.truncate(limit);
+
+    let has_more_behind = match identities.first() {
+        Some(nearest) => read_behind(nearest.to_owned())?.len() > 0,
+        // an empty page read from a cursor still has the cursor itself on the far side
+        None => cursor.is_some(),
+    };
+
+    if page.is_backwards() {
+        identities.reverse();
+        Ok(IdentityPage {
+            identities,
+            has_previous_page: has_more_onwards,
+            has_next_page: has_more_behind,
+            page_limit: limit,
+            total_count: None,
+        })
+    } else {
+        Ok(IdentityPage {
+            identities,
+            has_previous_page: has_more_behind,
+            has_next_page: has_more_onwards,
+            page_limit: limit,
+            total_count: None,
+        })
+    }
+}
+
+/// Select a page from a complete set of query results, ordered from most recent to oldest.
+///
+/// Use this method to paginate the results of `query_index_identities` / `combine_query_results`,
+/// prior to retrieving the records for the page with `retrieve_foreign_records`.
+///
+pub fn paginate_identities(
+    identities: Vec<EntryHash>,
+    page: &PageRequest,
+    default_limit: usize,
+) -> RecordAPIResult<IdentityPage>
+{
+    let cursor_position = |cursor: &EntryHash| {
+        identities.iter().position(|addr| addr == cursor)
+            .ok_or(SemanticIndexError::InvalidCursor(cursor.to_string()))
+    };
+    let total_count = Some(identities.len());
+
+    if page.is_backwards() {
+        let limit = page.last.unwrap_or(default_limit);
+        let end = match &page.before {
+            Some(cursor) => cursor_position(cursor)?,
+            None => identities.len(),
+        };
+        let start = end.saturating_sub(limit);
+
+        Ok(IdentityPage {
+            has_previous_page: start > 0,
+            has_next_page: end < identities.len(),
+            identities: identities[start..end].to_vec(),
+            page_limit: limit,
+            total_count,
+        })
+    } else {
+        let limit = page.first.unwrap_or(default_limit);
+        let start = match &page.after {
+            Some(cursor) => cursor_position(cursor)? + 1,
+            None => 0,
+        };
+        let end = std::cmp::min(start + limit, identities.len());
+
+        Ok(IdentityPage {
+            has_previous_page: start > 0,
+            has_next_page: end < identities.len(),
+            identities: identities[start..end].to_vec(),
+            page_limit: limit,
+            total_count,
+        })
+    }
 }
 
 /// Fetches all referenced record entries found corresponding to the input
@@ -164,7 +450,7 @@ pub fn query_time_index<'a, T, B, C, F, I>(
 ///
 /// Useful in loading the results of indexed data, where indexes link identity `Path`s for different records.
 ///
-fn retrieve_foreign_records<'a, T, B, C, F, S>(
+pub fn retrieve_foreign_records<'a, T, B, C, F, S>(
     zome_name_from_config: &'a F,
     method_name: &S,
     addresses: &'a Vec<EntryHash>,
@@ -284,6 +570,42 @@ pub fn append_to_time_index<'a, A, I>(
     }
 }
 
+/// Removes an entry pointer from the time-ordered index `index_name`, such that it is
+/// no longer returned when reading from the index.
+///
+/// Returns `true` if the index was removed, `false` if the entry was not indexed and thus skipped.
+///
+pub fn remove_from_time_index<'a, A, I>(
+    index_name: &I,
+    entry_address: &A,
+) -> RecordAPIResult<bool>
+    where A: DnaAddressable<EntryHash>,
+        I: AsRef<str> + std::fmt::Display,
+{
+    let entry_hash: &EntryHash = entry_address.as_ref();
+
+    match unindex_entry(index_name, entry_hash.to_owned()) {
+        Ok(_) => Ok(true),
+        Err(TimeIndexingError::NotIndexed(_, _)) => Ok(false),
+        Err(e) => Err(SemanticIndexError::BadTimeIndexError(e.to_string()).into())
+    }
+}
+
+/// Moves an entry pointer to a new position in the time-ordered index `index_name`, for
+/// orderings based on timestamps which may change over the lifetime of a record.
+///
+pub fn move_in_time_index<'a, A, I>(
+    index_name: &I,
+    entry_address: &A,
+    timestamp: DateTime<Utc>,
+) -> RecordAPIResult<bool>
+    where A: DnaAddressable<EntryHash>,
+        I: AsRef<str> + std::fmt::Display,
+{
+    remove_from_time_index(index_name, entry_address)?;
+    append_to_time_index(index_name, entry_address, timestamp)
+}
+
 /// Creates a 'destination' query index used for following a link from some external record
 /// into records contained within the current DNA / zome.
 ///
@@ -591,3 +913,207 @@ fn convert_errors<E: Clone, F>(r: &Result<ActionHash, E>) -> Result<ActionHash,
         Err(e) => Err(F::from((*e).clone())),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    /// Query params matching records indexed under a single tag
+    #[derive(Debug, Default)]
+    struct Params {
+        tag: Option<char>,
+    }
+
+    fn hash(n: u8) -> EntryHash {
+        EntryHash::from_raw_36(vec![n; 36])
+    }
+
+    fn hashes(ns: &[u8]) -> Vec<EntryHash> {
+        ns.iter().map(|n| hash(*n)).collect()
+    }
+
+    fn tagged(tag: char) -> Params {
+        Params { tag: Some(tag) }
+    }
+
+    fn filter(and: Vec<Params>, or: Vec<Params>, not: Vec<Params>) -> Option<QueryFilter<Params>> {
+        Some(QueryFilter { and, or, not })
+    }
+
+    fn match_params(params: &Params) -> RecordAPIResult<Option<Vec<EntryHash>>> {
+        Ok(params.tag.map(|tag| match tag {
+            'a' => hashes(&[1, 2, 3]),
+            'b' => hashes(&[3, 4]),
+            'c' => hashes(&[5]),
+            _ => vec![],
+        }))
+    }
+
+    fn query(params: Params, filter: Option<QueryFilter<Params>>, within: Option<Vec<EntryHash>>) -> RecordAPIResult<Vec<EntryHash>> {
+        combine_query_results(&params, &filter, within, match_params, || Ok(hashes(&[1, 2, 3, 4, 5])))
+    }
+
+    #[test]
+    fn test_params_only() {
+        assert_eq!(query(tagged('a'), None, None).unwrap(), hashes(&[1, 2, 3]));
+        assert!(query(Params::default(), None, None).is_err(), "queries without any params are rejected");
+    }
+
+    #[test]
+    fn test_and_intersects() {
+        assert_eq!(query(tagged('a'), filter(vec![tagged('b')], vec![], vec![]), None).unwrap(), hashes(&[3]));
+        assert_eq!(query(tagged('a'), filter(vec![tagged('c')], vec![], vec![]), None).unwrap(), hashes(&[]));
+    }
+
+    #[test]
+    fn test_or_unions_then_intersects() {
+        assert_eq!(query(Params::default(), filter(vec![], vec![tagged('b'), tagged('c')], vec![]), None).unwrap(), hashes(&[3, 4, 5]));
+        assert_eq!(query(tagged('a'), filter(vec![], vec![tagged('b'), tagged('c')], vec![]), None).unwrap(), hashes(&[3]));
+        assert_eq!(
+            query(tagged('a'), filter(vec![], vec![Params::default()], vec![]), None).unwrap(), hashes(&[1, 2, 3]),
+            "or sets without any params do not restrict results",
+        );
+    }
+
+    #[test]
+    fn test_not_excludes() {
+        assert_eq!(query(tagged('a'), filter(vec![], vec![], vec![tagged('b')]), None).unwrap(), hashes(&[1, 2]));
+        assert_eq!(
+            query(tagged('a'), filter(vec![], vec![tagged('a'), tagged('b')], vec![tagged('c'), tagged('b')]), None).unwrap(),
+            hashes(&[1, 2]),
+        );
+    }
+
+    #[test]
+    fn test_not_only_scans_all_records() {
+        let scanned = Cell::new(false);
+        let results = combine_query_results(
+            &Params::default(), &filter(vec![], vec![], vec![tagged('a')]), None, match_params,
+            || { scanned.set(true); Ok(hashes(&[1, 2, 3, 4, 5])) },
+        ).unwrap();
+        assert!(scanned.get(), "not-only queries exclude from all records");
+        assert_eq!(results, hashes(&[4, 5]));
+
+        let scanned = Cell::new(false);
+        combine_query_results(
+            &tagged('b'), &filter(vec![], vec![], vec![tagged('a')]), None, match_params,
+            || { scanned.set(true); Ok(vec![]) },
+        ).unwrap();
+        assert!(!scanned.get(), "not combined with positive params does not scan all records");
+    }
+
+    #[test]
+    fn test_within_restricts_and_orders() {
+        assert_eq!(query(tagged('a'), None, Some(hashes(&[4, 3, 2]))).unwrap(), hashes(&[3, 2]));
+        assert_eq!(query(Params::default(), None, Some(hashes(&[4, 3]))).unwrap(), hashes(&[4, 3]));
+    }
+
+    /// Page through a time index holding hashes `1..=count`, ordered from oldest to newest,
+    /// recording the number of entries requested from each read
+    fn page_index(count: u8, page: PageRequest, reads: &RefCell<Vec<usize>>) -> IdentityPage {
+        let index: Vec<EntryHash> = (1..=count).map(hash).collect();
+        let position = |cursor: &EntryHash| index.iter().position(|h| h == cursor).unwrap();
+        let older = |cursor: Option<EntryHash>, limit: usize| {
+            let end = cursor.map_or(index.len(), |c| position(&c));
+            index[..end].iter().rev().take(limit).cloned().collect::<Vec<EntryHash>>()
+        };
+        let newer = |cursor: Option<EntryHash>, limit: usize| {
+            let start = cursor.map_or(0, |c| position(&c) + 1);
+            index[start..].iter().take(limit).cloned().collect::<Vec<EntryHash>>()
+        };
+        let backwards = page.is_backwards();
+
+        page_time_index(
+            &page, 2,
+            |cursor, limit| {
+                reads.borrow_mut().push(limit);
+                Ok(if backwards { newer(cursor, limit) } else { older(cursor, limit) })
+            },
+            |nearest| Ok(if backwards { older(Some(nearest), 1) } else { newer(Some(nearest), 1) }),
+        ).unwrap()
+    }
+
+    fn forwards(first: Option<usize>, after: Option<u8>) -> PageRequest {
+        PageRequest { first, after: after.map(hash), last: None, before: None }
+    }
+
+    fn backwards(last: Option<usize>, before: Option<u8>) -> PageRequest {
+        PageRequest { first: None, after: None, last, before: before.map(hash) }
+    }
+
+    #[test]
+    fn test_time_index_page_reads_one_extra() {
+        let reads = RefCell::new(vec![]);
+        let result = page_index(5, forwards(Some(3), None), &reads);
+        assert_eq!(*reads.borrow(), vec![4], "one more entry than the page size is read");
+        assert_eq!(result.identities, hashes(&[5, 4, 3]), "extra entry is dropped from the page");
+        assert!(result.has_next_page);
+
+        let result = page_index(3, forwards(Some(3), None), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[3, 2, 1]));
+        assert!(!result.has_next_page, "no next page when exactly the page size remains");
+
+        let result = page_index(5, forwards(None, None), &reads);
+        assert_eq!(result.page_limit, 2, "default limit used when no page size given");
+        assert_eq!(result.identities, hashes(&[5, 4]));
+    }
+
+    #[test]
+    fn test_time_index_page_forwards() {
+        let result = page_index(5, forwards(Some(2), None), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[5, 4]));
+        assert!(!result.has_previous_page, "first page has no previous page");
+        assert!(result.has_next_page);
+
+        let result = page_index(5, forwards(Some(2), Some(4)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[3, 2]));
+        assert!(result.has_previous_page);
+        assert!(result.has_next_page);
+
+        let result = page_index(5, forwards(Some(2), Some(2)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[1]));
+        assert!(result.has_previous_page);
+        assert!(!result.has_next_page, "last page has no next page");
+
+        let result = page_index(5, forwards(Some(2), Some(1)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[]));
+        assert!(result.has_previous_page, "cursor itself precedes an empty page");
+        assert!(!result.has_next_page);
+    }
+
+    #[test]
+    fn test_time_index_page_backwards() {
+        let result = page_index(5, backwards(Some(2), None), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[2, 1]), "backward pages are still ordered from newest to oldest");
+        assert!(result.has_previous_page);
+        assert!(!result.has_next_page, "oldest page has no next page");
+
+        let result = page_index(5, backwards(Some(2), Some(2)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[4, 3]));
+        assert!(result.has_previous_page);
+        assert!(result.has_next_page);
+
+        let result = page_index(5, backwards(Some(2), Some(4)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[5]));
+        assert!(!result.has_previous_page, "newest page has no previous page");
+        assert!(result.has_next_page);
+
+        let result = page_index(5, backwards(Some(2), Some(5)), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[]));
+        assert!(!result.has_previous_page);
+        assert!(result.has_next_page, "cursor itself follows an empty page");
+    }
+
+    #[test]
+    fn test_time_index_page_empty_index() {
+        let result = page_index(0, forwards(Some(2), None), &RefCell::new(vec![]));
+        assert_eq!(result.identities, hashes(&[]));
+        assert!(!result.has_previous_page);
+        assert!(!result.has_next_page);
+
+        let result = page_index(0, backwards(Some(2), None), &RefCell::new(vec![]));
+        assert!(!result.has_previous_page);
+        assert!(!result.has_next_page);
+    }
+}
@@ -16,6 +16,7 @@ use syn::{
     AttributeArgs,
     Data, DataStruct, DeriveInput,
     Fields, Type, TypePath, PathSegment,
+    Meta, MetaList, NestedMeta,
     PathArguments::AngleBracketed,
     AngleBracketedGenericArguments, GenericArgument,
     punctuated::Punctuated, token::Comma,
@@ -71,9 +72,56 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
         Some(read_fn) => format_ident!("{}", read_fn),
     };
     let exposed_append_api_name = format_ident!("record_new_{}", record_type_str_attribute);
+    let exposed_remove_api_name = format_ident!("record_deleted_{}", record_type_str_attribute);
     let creation_time_index_name = [record_type_str_attribute.clone(), ".created".to_string()].concat();
     let record_index_field_type = format_ident!("{}Address", record_type.to_string().to_case(Case::UpperCamel));
 
+    // parse additional time indexes ordering records by some timestamp field, declared as `#[time_index(field, ...)]`
+    let time_index_fields: Vec<String> = input.attrs.iter()
+        .filter(|attr| attr.path.is_ident("time_index"))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.into_iter()
+                .map(|item| match item {
+                    NestedMeta::Meta(Meta::Path(path)) => path.get_ident()
+                        .expect("expected field names in #[time_index(...)]")
+                        .to_string().to_case(Case::Snake),
+                    _ => panic!("expected field names in #[time_index(...)]"),
+                })
+                .collect::<Vec<String>>(),
+            _ => panic!("expected list of field names in #[time_index(...)]"),
+        })
+        .collect();
+
+    let time_index_ids: Vec<String> = time_index_fields.iter()
+        .map(|field| format!("{}.{}", record_type_str_attribute, field))
+        .collect();
+
+    // generate APIs for appending to and reading from each additional time index
+    let time_index_apis = time_index_fields.iter()
+        .map(|field| {
+            let time_index_id = format!("{}.{}", record_type_str_attribute, field);
+            let append_api_name = format_ident!("record_{}_{}", record_type_str_attribute, field);
+            let remove_api_name = format_ident!("unrecord_{}_{}", record_type_str_attribute, field);
+            let read_api_name = format_ident!("{}_by_{}", exposed_read_api_method_name, field);
+
+            quote! {
+                #[hdk_extern]
+                fn #append_api_name(AppendAddress { address, timestamp }: AppendAddress<#record_index_field_type>) -> ExternResult<bool> {
+                    Ok(move_in_time_index(&#time_index_id, &address, timestamp)?)
+                }
+
+                #[hdk_extern]
+                fn #remove_api_name(ByAddress { address }: ByAddress<#record_index_field_type>) -> ExternResult<bool> {
+                    Ok(remove_from_time_index(&#time_index_id, &address)?)
+                }
+
+                #[hdk_extern]
+                fn #read_api_name(paging: PagingParams) -> ExternResult<QueryResults> {
+                    read_time_index_records(&#time_index_id, paging)
+                }
+            }
+        });
+
     // build iterators for generating index update methods and query conditions
     let all_indexes = fields.iter()
         .map(|field| {
@@ -199,13 +247,12 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
                                 let index_anchor_path = Path::from(#query_field_ident);
                                 let index_anchor_id: #related_index_field_type = DnaAddressable::new(dna_info()?.hash, index_anchor_path.path_entry_hash()?);
 
-                                entries_result = query_index::<ResponseData, #record_index_field_type, _,_,_,_,_,_>(
+                                let matches = query_index_identities(
                                     &index_anchor_id,
                                     &stringify!(#reciprocal_index_name),
                                     &LOCAL_TIME_INDEX_ID,
-                                    &read_index_target_zome,
-                                    &QUERY_FN_NAME,
-                                );
+                                )?;
+                                matched = Some(intersect_identities(matched, &matches));
                             },
                             _ => (),
                         };
@@ -216,13 +263,12 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
                 None => quote! {
                     match &params.#query_field_ident {
                         Some(#query_field_ident) => {
-                            entries_result = query_index::<ResponseData, #record_index_field_type, _,_,_,_,_,_>(
+                            let matches = query_index_identities(
                                 #query_field_ident,
                                 &stringify!(#reciprocal_index_name),
                                 &LOCAL_TIME_INDEX_ID,
-                                &read_index_target_zome,
-                                &QUERY_FN_NAME,
-                            );
+                            )?;
+                            matched = Some(intersect_identities(matched, &matches));
                         },
                         _ => (),
                     };
@@ -248,10 +294,26 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
             Some(conf.#record_type_index_attribute.record_storage_zome)
         }
 
-        // define struct to wrap query parameter inputs, so that other meta-args (eg. pagination) can be added later
+        // define struct to wrap query parameter inputs along with filtering & pagination meta-args
         #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
         struct SearchInputs {
             pub params: QueryParams,
+            #[serde(default)]
+            pub filter: Option<QueryFilter<QueryParams>>,
+            // restrict results to records created at or after `created_after` and before `created_before`
+            #[serde(default)]
+            pub created_after: Option<DateTime<Utc>>,
+            #[serde(default)]
+            pub created_before: Option<DateTime<Utc>>,
+            #[serde(default)]
+            pub first: Option<usize>,
+            #[serde(default)]
+            pub after: Option<String>,
+            #[serde(default)]
+            pub last: Option<usize>,
+            #[serde(default)]
+            pub before: Option<String>,
         }
 
         // define zome API function name to read indexed records
@@ -273,15 +335,14 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
 
         // query input parameters mimicing Relay's pagination spec
         // @see https://relay.dev/graphql/connections.htm
-        // :TODO: extend to allow for filtering with `QueryParams`
+        // Cursors are the `Edge.cursor` values of previously returned results.
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         struct PagingParams {
-            // :TODO: forwards pagination
-            // first: Option<usize>,
-            // after: Option<EntryHash>,
+            first: Option<usize>,
+            after: Option<String>,
             last: Option<usize>,
-            before: Option<EntryHash>,
+            before: Option<String>,
         }
 
         // query results structure mimicing Relay's pagination format
@@ -305,18 +366,30 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
 
         // declare public list API
         #[hdk_extern]
-        fn #exposed_read_api_method_name(PagingParams { /*first, after,*/ last, before }: PagingParams) -> ExternResult<QueryResults> {
-            let mut entries_result: RecordAPIResult<Vec<RecordAPIResult<ResponseData>>> = Err(SemanticIndexError::EmptyQuery.into());
+        fn #exposed_read_api_method_name(paging: PagingParams) -> ExternResult<QueryResults> {
+            read_time_index_records(&LOCAL_TIME_INDEX_ID, paging)
+        }
+
+        // declare APIs for any additional time indexes
+        #(
+            #time_index_apis
+        )*
+
+        // read a page of records from the given time index
+        fn read_time_index_records(time_index_id: &str, PagingParams { first, after, last, before }: PagingParams) -> ExternResult<QueryResults> {
+            let page = read_time_index_page(
+                &time_index_id,
+                &PageRequest::new(first, after, last, before)?,
+                PAGE_SIZE,
+            )?;
 
-            entries_result = query_time_index::<ResponseData, #record_index_field_type,_,_,_>(
+            let entries_result = retrieve_foreign_records::<ResponseData, #record_index_field_type, _,_,_>(
                 &read_index_target_zome,
                 &QUERY_FN_NAME,
-                &LOCAL_TIME_INDEX_ID,
-                before,
-                last.unwrap_or(PAGE_SIZE),
+                &page.identities,
             );
 
-            Ok(handle_list_output(entries_result?.as_slice())?)
+            Ok(handle_list_output(entries_result.as_slice(), &page)?)
         }
 
         // declare API for global list API management
@@ -325,21 +398,57 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
             Ok(append_to_time_index(&LOCAL_TIME_INDEX_ID, &address, timestamp)?)
         }
 
+        // declare API for removing deleted records from all time indexes
+        #[hdk_extern]
+        fn #exposed_remove_api_name(ByAddress { address }: ByAddress<#record_index_field_type>) -> ExternResult<bool> {
+            #(
+                remove_from_time_index(&#time_index_ids, &address)?;
+            )*
+            Ok(remove_from_time_index(&LOCAL_TIME_INDEX_ID, &address)?)
+        }
+
         // declare public query method with injected handler logic
         #[hdk_extern]
-        fn #exposed_query_api_method_name(SearchInputs { params }: SearchInputs) -> ExternResult<QueryResults>
+        fn #exposed_query_api_method_name(SearchInputs { params, filter, created_after, created_before, first, after, last, before }: SearchInputs) -> ExternResult<QueryResults>
+        {
+            let identities = combine_query_results(
+                &params,
+                &filter,
+                read_time_index_range(&LOCAL_TIME_INDEX_ID, created_after, created_before)?,
+                match_query_params,
+                || Ok(read_all_entry_hashes(&LOCAL_TIME_INDEX_ID)
+                    .map_err(|e| { SemanticIndexError::BadTimeIndexError(e.to_string()) })?),
+            )?;
+
+            let page = paginate_identities(
+                identities,
+                &PageRequest::new(first, after, last, before)?,
+                PAGE_SIZE,
+            )?;
+
+            let entries_result = retrieve_foreign_records::<ResponseData, #record_index_field_type, _,_,_>(
+                &read_index_target_zome,
+                &QUERY_FN_NAME,
+                &page.identities,
+            );
+
+            Ok(handle_list_output(entries_result.as_slice(), &page)?)
+        }
+
+        // determine the records matching all provided fields of a set of query params,
+        // or `None` if no fields were provided
+        fn match_query_params(params: &QueryParams) -> RecordAPIResult<Option<Vec<EntryHash>>>
         {
-            let mut entries_result: RecordAPIResult<Vec<RecordAPIResult<ResponseData>>> = Err(SemanticIndexError::EmptyQuery.into());
+            let mut matched: Option<Vec<EntryHash>> = None;
 
-            // :TODO: proper search combinator logic, this just does exclusive boolean ops
             #(
                 #query_handlers
             )*
 
-            Ok(handle_list_output(entries_result?.as_slice())?)
+            Ok(matched)
         }
 
-        fn handle_list_output(entries: &[RecordAPIResult<ResponseData>]) -> RecordAPIResult<QueryResults>
+        fn handle_list_output(entries: &[RecordAPIResult<ResponseData>], page: &IdentityPage) -> RecordAPIResult<QueryResults>
         {
             let valid_edges = entries.iter()
                 .cloned()
@@ -367,11 +476,10 @@ pub fn index_zome(attribs: TokenStream, input: TokenStream) -> TokenStream {
                 page_info: PageInfo {
                     end_cursor: edge_cursors.last().unwrap_or(first_cursor.clone()),
                     start_cursor: first_cursor,
-                    // :TODO:
-                    has_next_page: true,
-                    has_previous_page: true,
-                    page_limit: None,
-                    total_count: None,
+                    has_next_page: page.has_next_page,
+                    has_previous_page: page.has_previous_page,
+                    page_limit: Some(page.page_limit),
+                    total_count: page.total_count,
                 },
                 errors: entries.iter()
                     .cloned()
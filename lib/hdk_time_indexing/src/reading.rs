@@ -1,4 +1,5 @@
-use chrono::{DateTime, Utc, Duration};
+use std::cmp::Ordering;
+use chrono::{DateTime, Utc};
 use hdk::prelude::*;
 use crate::{
     INDEX_DEPTH, HAS_CHUNK_LEAVES,
@@ -63,38 +64,28 @@ fn collect_leaf_index_hashes<I>(index_name: &I, context_hash: EntryHash, context
 
 /**
  * Retrieve the most recent entry hashes stored in the `index_name` time-ordered index,
- * up to a maximum of `limit`.
+ * up to a maximum of `limit`. Results are ordered from newest to oldest.
  */
 pub fn get_latest_entry_hashes<I>(index_name: &I, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
     where I: AsRef<str>,
 {
-    // find the most recently indexed EntryHash as a starting point
-    let most_recent = get_latest_indexed_entry_hash(index_name)?;
-
-    match most_recent {
-        None => Ok(vec![]),
-        Some(latest) => {
-            // load a page of links to entries immediately before the latest
-            let mut earlier_page = get_older_entry_hashes(index_name, latest.to_owned(), limit)?;
-
-            // remove the earliest linked entry
-            let num_earlier = earlier_page.len();
-            if num_earlier > 0 {
-                earlier_page.truncate(num_earlier - 1);
-            }
+    read_entry_hashes(index_name, None, &TimeRange::default(), ReadDirection::Older, limit)
+}
 
-            // prepend the most recent one
-            Ok([
-                vec![latest.to_owned()],
-                earlier_page,
-            ].concat())
-        },
-    }
+/**
+ * Retrieve the earliest entry hashes stored in the `index_name` time-ordered index,
+ * up to a maximum of `limit`. Results are ordered from oldest to newest.
+ */
+pub fn get_earliest_entry_hashes<I>(index_name: &I, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
+    where I: AsRef<str>,
+{
+    read_entry_hashes(index_name, None, &TimeRange::default(), ReadDirection::Newer, limit)
 }
 
 /**
  * Retrieve entry hashes indexed in the `index_name` time-ordered index immediately
- * before `before_entry` (not inclusive), up to a maximum of `limit`.
+ * before `before_entry` (not inclusive), up to a maximum of `limit`. Results are
+ * ordered from newest to oldest.
  *
  * This method is best used with cursor-based pagination, where the previously oldest
  * returned `EntryHash` is used as a cursor to return the next most recent page of entries.
@@ -102,194 +93,247 @@ pub fn get_latest_entry_hashes<I>(index_name: &I, limit: usize) -> TimeIndexResu
 pub fn get_older_entry_hashes<I>(index_name: &I, before_entry: EntryHash, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
     where I: AsRef<str>,
 {
-    Ok(get_ordered_links_before(index_name, before_entry, limit)?
-        .iter()
-        .filter_map(|link| { link.target.to_owned().into_entry_hash() })
-        .collect())
+    let cursor = get_index_position(index_name, before_entry)?;
+    read_entry_hashes(index_name, Some(cursor), &TimeRange::default(), ReadDirection::Older, limit)
 }
 
-/// Return a maximum of `limit` `Link`s, in order from most recent to oldest in `index_name`
-/// starting at the given (already indexed) `entry_hash`.
-///
-/// :TODO: account for the possibility that an entry might be validly linked multiple times in the same index
-/// :TODO: de-duplicate links to account for network partitions erroneously causing multiple writes of the same index
-///
-fn get_ordered_links_before<I>(index_name: &I, entry_hash: EntryHash, limit: usize) -> TimeIndexResult<Vec<Link>>
+/**
+ * Retrieve entry hashes indexed in the `index_name` time-ordered index immediately
+ * after `after_entry` (not inclusive), up to a maximum of `limit`. Results are
+ * ordered from oldest to newest, i.e. nearest to `after_entry` first.
+ *
+ * This is the reverse of `get_older_entry_hashes`, where the previously newest returned
+ * `EntryHash` is used as a cursor to page back towards the most recent entries.
+ */
+pub fn get_newer_entry_hashes<I>(index_name: &I, after_entry: EntryHash, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
     where I: AsRef<str>,
 {
-    // inspect link from entry to index in order to determine indexed time & parent node in index tree
-    let parents = get_links(
-        entry_hash.to_owned(),
-        LinkTypes::TimeIndex,
-        Some(link_prefix_for_index(index_name)),
-    )?;
-    let leaf_link = parents.first().ok_or(
-        TimeIndexingError::NotIndexed(index_name.as_ref().to_string(), entry_hash)
-    )?;
-    let this_index: IndexSegment = leaf_link.tag.to_owned().try_into()?;
-    let this_timestamp = this_index.into();
-
-    let leaf_hash = leaf_link.target.to_owned().into_entry_hash().unwrap();
-
-    // find all our older siblings
-    let mut older_siblings = get_ordered_child_links_of_node_older_than(index_name, leaf_hash, this_timestamp)?;
-
-    // truncate to requested limit
-    older_siblings.truncate(limit);
-
-    // continue fetching links from previous leaf nodes in the index until we have reached the limit
-    let mut context_timestamp = this_timestamp;
-    while older_siblings.len() < limit {
-        let previous_leaf_link = get_previous_leaf(
-            index_name,
-            &context_timestamp,
-            // :NOTE: if there are chunk nodes in the tree, immediate parent will be at
-            //        first INDEX_DEPTH, otherwise it will be second tier.
-            if *HAS_CHUNK_LEAVES { 0 } else { 1 },
-            if *HAS_CHUNK_LEAVES { 0 } else { 1 },
-        )?;
-
-        match previous_leaf_link {
-            // stop looking if there are no more leaves to query
-            None => { break; },
-            Some(prev) => {
-                // append any found link targets from the previous leaf
-                let more_older_siblings = get_ordered_child_links_of_node_older_than(index_name, prev.hash()?, this_timestamp)?;
-                older_siblings = [older_siblings, more_older_siblings].concat();
-                older_siblings.truncate(limit);
-
-                // look further backward from previous leaf if we still need to find more
-                let prev_time: DateTime<Utc> = prev.into();
-                context_timestamp = prev_time + Duration::milliseconds(-1);
-            },
-        }
-    }
-
-    Ok(older_siblings)
+    let cursor = get_index_position(index_name, after_entry)?;
+    read_entry_hashes(index_name, Some(cursor), &TimeRange::default(), ReadDirection::Newer, limit)
 }
 
-/// Locate all the child links of the node with hash `leaf_hash`, ordered from newest to oldest.
-///
-fn get_ordered_child_links_of_node<I>(index_name: &I, leaf_hash: EntryHash) -> TimeIndexResult<Vec<Link>>
+/**
+ * Retrieve entry hashes indexed in the `index_name` time-ordered index at or after `from`
+ * and before `to`, up to a maximum of `limit`. Results are ordered from newest to oldest.
+ *
+ * Only the branches of the index tree overlapping the requested range are loaded, so
+ * this method is suitable for reading a bounded period (eg. a calendar month) from
+ * indexes of any size.
+ */
+pub fn get_entry_hashes_between<I>(index_name: &I, from: DateTime<Utc>, to: DateTime<Utc>, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
     where I: AsRef<str>,
 {
-    // query children of parent node
-    let mut siblings = get_links(
-        leaf_hash,
-        LinkTypes::TimeIndex,
-        Some(link_prefix_for_index(index_name)),
-    )?;
+    let range = TimeRange { from: Some(from), to: Some(to) };
+    read_entry_hashes(index_name, None, &range, ReadDirection::Older, limit)
+}
 
-    // order them from newest to oldest
-    siblings.sort_unstable_by(|a, b| b.tag.cmp(&a.tag));
+/// Direction in which to read entries from a time index
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadDirection {
+    Older,
+    Newer,
+}
 
-    Ok(siblings)
+/// Position of an entry in a time index. Entries indexed at the same time are ordered by hash,
+/// so that cursors remain stable.
+pub type IndexPosition = (DateTime<Utc>, EntryHash);
+
+pub fn compare_positions(a: &IndexPosition, b: &IndexPosition) -> Ordering {
+    a.0.cmp(&b.0).then_with(|| a.1.get_raw_39().cmp(b.1.get_raw_39()))
+}
+
+/// Period of time to restrict reads to. `from` is inclusive and `to` is exclusive;
+/// either may be omitted to leave that end of the range open.
+#[derive(Debug, Clone, Default)]
+pub struct TimeRange {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl TimeRange {
+    pub fn contains(&self, time: &DateTime<Utc>) -> bool {
+        self.from.map_or(true, |from| *time >= from)
+            && self.to.map_or(true, |to| *time < to)
+    }
+
+    /// Whether an index segment beginning at `start` and ending before `end` (if known)
+    /// may hold entries within the range
+    pub fn overlaps(&self, start: &DateTime<Utc>, end: &Option<DateTime<Utc>>) -> bool {
+        self.to.map_or(true, |to| *start < to)
+            && match (self.from, end) {
+                (Some(from), Some(end)) => *end > from,
+                _ => true,
+            }
+    }
 }
 
-/// Locate all the child links of the node with hash `leaf_hash` which are older than `this_timestamp`,
-/// ordered from newest to oldest.
+/// Read up to `limit` entries within `range` from the `index_name` index, starting from `cursor`
+/// (not inclusive) or from the newest / oldest end of the index if no cursor is given.
 ///
-fn get_ordered_child_links_of_node_older_than<I>(index_name: &I, leaf_hash: EntryHash, this_timestamp: DateTime<Utc>) -> TimeIndexResult<Vec<Link>>
+fn read_entry_hashes<I>(index_name: &I, cursor: Option<IndexPosition>, range: &TimeRange, direction: ReadDirection, limit: usize) -> TimeIndexResult<Vec<EntryHash>>
     where I: AsRef<str>,
 {
-    // query children of parent node
-    let siblings = get_ordered_child_links_of_node(
-        index_name,
-        leaf_hash,
-    )?;
+    match get_root_hash(index_name)? {
+        None => Ok(vec![]),
+        Some(hash) => read_entry_hashes_from(
+            hash, cursor, range, direction, limit,
+            &|node_hash| get_child_positions_of_node(index_name, node_hash),
+        ),
+    }
+}
 
-    // filter out those newer
-    Ok(siblings.iter().cloned().filter(|sib| {
-        let this_index: Result<IndexSegment, _> = sib.tag.to_owned().try_into();
-        match this_index {
-            Ok(time) => {
-                let dt: DateTime<Utc> = time.into();
-                dt < this_timestamp
-            },
-            Err(_e) => false,
-        }
-    }).collect())
+/// Read up to `limit` entries within `range` from the index tree rooted at `root_hash`, starting
+/// from `cursor` (not inclusive). Children of each node are loaded with `load_children`, which
+/// must return them ordered from oldest to newest.
+///
+pub fn read_entry_hashes_from<L>(
+    root_hash: EntryHash,
+    cursor: Option<IndexPosition>,
+    range: &TimeRange,
+    direction: ReadDirection,
+    limit: usize,
+    load_children: &L,
+) -> TimeIndexResult<Vec<EntryHash>>
+    where L: Fn(EntryHash) -> TimeIndexResult<Vec<IndexPosition>>,
+{
+    let mut results = vec![];
+    collect_entry_hashes(load_children, root_hash, None, (*INDEX_DEPTH).len() as isize, &cursor, range, direction, limit, &mut results)?;
+    Ok(results)
 }
 
-/// Find the previous leafmost node in the time index tree
-/// :TODO: make this work
+/// Performs a depth-first traversal of the specified time index tree in the given `direction`, appending
+/// the `EntryHash`es of leafmost nodes within `range` and beyond `cursor` to `results` until `limit` is reached.
 ///
-fn get_previous_leaf<I>(index_name: &I, from_timestamp: &DateTime<Utc>, try_depth: usize, starting_depth: usize) -> TimeIndexResult<Option<IndexSegment>>
-    where I: AsRef<str>,
+/// Branches of the tree which cannot contain such entries are skipped without being loaded.
+/// `context_end` is the time at which the next sibling of the current node begins, if any.
+///
+fn collect_entry_hashes<L>(
+    load_children: &L,
+    context_hash: EntryHash,
+    context_end: Option<DateTime<Utc>>,
+    context_depth: isize,
+    cursor: &Option<IndexPosition>,
+    range: &TimeRange,
+    direction: ReadDirection,
+    limit: usize,
+    results: &mut Vec<EntryHash>,
+) -> TimeIndexResult<()>
+    where L: Fn(EntryHash) -> TimeIndexResult<Vec<IndexPosition>>,
 {
-    let mut this_depth = try_depth;
-    while this_depth >= starting_depth {
-        let try_parent_hash = if this_depth >= INDEX_DEPTH.len() {
-            // can't go upwards anymore, so go to the root
-            Path::from(index_name.as_ref()).typed(LinkTypes::TimeIndex)?.path_entry_hash()?
-        } else {
-            // determine next parent upwards
-            IndexSegment::new(from_timestamp, INDEX_DEPTH.get(this_depth).unwrap()).hash()?
-        };
+    let children = load_children(context_hash)?;
+
+    // last hop outside the index tree links to the targeted nodes, so return those beyond the cursor
+    if (*HAS_CHUNK_LEAVES && context_depth == -1) || (!(*HAS_CHUNK_LEAVES) && context_depth == 0) {
+        let mut children = children;
+        if direction == ReadDirection::Older {
+            children.reverse();
+        }
+        for child in children {
+            if results.len() >= limit {
+                break;
+            }
+            let is_beyond_cursor = match (cursor, direction) {
+                (None, _) => true,
+                (Some(c), ReadDirection::Older) => compare_positions(&child, c) == Ordering::Less,
+                (Some(c), ReadDirection::Newer) => compare_positions(&child, c) == Ordering::Greater,
+            };
+            if is_beyond_cursor && range.contains(&child.0) {
+                results.push(child.1);
+            }
+        }
+        return Ok(());
+    }
+
+    // segments begin at their indexed time and end where the next newer sibling begins
+    let mut segments: Vec<(DateTime<Utc>, Option<DateTime<Utc>>, EntryHash)> = children.iter()
+        .enumerate()
+        .map(|(idx, (segment_time, segment_hash))| {
+            let segment_end = children.get(idx + 1).map(|(next_time, _)| *next_time).or(context_end);
+            (*segment_time, segment_end, segment_hash.to_owned())
+        })
+        .collect();
+    if direction == ReadDirection::Older {
+        segments.reverse();
+    }
 
-        // find all nodes in the tree at this depth that are older than the starting offset
-        let older_siblings = get_ordered_child_links_of_node_older_than(index_name, try_parent_hash, from_timestamp.to_owned())?;
-
-        match older_siblings.first() {
-            // there are no older siblings in this list
-            None => {
-                if this_depth >= INDEX_DEPTH.len() {
-                    // if we are at the root, there are no more index links to try
-                    return Ok(None);
-                } else {
-                    // if we are not yet at the root, go up a level and try reading more links
-                    this_depth += 1;
-                    continue;
-                }
-            },
-            // if there was an older sibling, come back down to the earliest leaf node
-            Some(link) => {
-                let _found_at_this_depth: IndexSegment = link.tag.to_owned().try_into()?;
-                while this_depth >= starting_depth {
-                    // :TODO: finish this, or scrap it and start again
-
-                    this_depth -= 1;
-                }
-                // this isn't what we want...
-                return Ok(Some(IndexSegment::new(from_timestamp, INDEX_DEPTH.get(starting_depth).unwrap())))
-            },
+    // still recursing downwards- load descendent nodes for any child which may hold entries beyond the cursor
+    for (segment_time, segment_end, segment_hash) in segments {
+        if results.len() >= limit {
+            break;
+        }
+        let may_contain_results = range.overlaps(&segment_time, &segment_end) && match (cursor, direction) {
+            (None, _) => true,
+            (Some((cursor_time, _)), ReadDirection::Older) => segment_time <= *cursor_time,
+            (Some((cursor_time, _)), ReadDirection::Newer) => segment_end.map_or(true, |end| end > *cursor_time),
+        };
+        if may_contain_results {
+            collect_entry_hashes(load_children, segment_hash, segment_end, context_depth - 1, cursor, range, direction, limit, results)?;
         }
     }
 
-    Ok(None)    // also probably wrong
+    Ok(())
 }
 
-/// Determine the hash of the most recent `IndexSegment` written for the given `index_name`.
-/// If the index has not been created yet, `None` is returned in the result.
+/// Determine the position of an (already indexed) `entry_hash` in `index_name`
 ///
-fn get_latest_indexed_entry_hash<I>(index_name: &I) -> TimeIndexResult<Option<EntryHash>>
+fn get_index_position<I>(index_name: &I, entry_hash: EntryHash) -> TimeIndexResult<IndexPosition>
     where I: AsRef<str>,
 {
-    match get_root_hash(index_name)? {
-        None => Ok(None),
-        // recurse into the tree, returning leafmost node
-        Some(hash) => Ok(Some(get_newest_leafmost_hash(hash, index_name)?)),
-    }
+    // inspect link from entry to index in order to determine indexed time
+    let parents = get_links(
+        entry_hash.to_owned(),
+        LinkTypes::TimeIndex,
+        Some(link_prefix_for_index(index_name)),
+    )?;
+    let leaf_link = parents.first().ok_or(
+        TimeIndexingError::NotIndexed(index_name.as_ref().to_string(), entry_hash.to_owned())
+    )?;
+    let this_index: IndexSegment = leaf_link.tag.to_owned().try_into()?;
+
+    Ok((this_index.into(), entry_hash))
 }
 
-/// Find the most recent indexed hash in all children of `current_segment_hash`
-/// by recursively performing a depth-first search of its children.
+/// Locate all the children of the node with hash `node_hash`, along with the time each was indexed at,
+/// ordered from oldest to newest.
 ///
-fn get_newest_leafmost_hash<I>(current_segment_hash: EntryHash, index_name: &I) -> TimeIndexResult<EntryHash>
+fn get_child_positions_of_node<I>(index_name: &I, node_hash: EntryHash) -> TimeIndexResult<Vec<IndexPosition>>
     where I: AsRef<str>,
 {
-    let mut children = get_links(
-        current_segment_hash.to_owned(),
+    let children = get_links(
+        node_hash,
         LinkTypes::TimeIndex,
         Some(link_prefix_for_index(index_name)),
     )?;
 
-    children.sort_unstable_by(|a, b| a.tag.cmp(&b.tag));
+    let mut positions = children.iter()
+        .map(|link| {
+            let segment: IndexSegment = link.tag.to_owned().try_into()?;
+            Ok((segment.into(), link.target.to_owned().into_entry_hash().unwrap()))
+        })
+        .collect::<TimeIndexResult<Vec<IndexPosition>>>()?;
+
+    // :NOTE: link tags cannot be compared directly, since chunk offsets are not zero-padded
+    positions.sort_unstable_by(compare_positions);
 
-    match children.last() {
-        None => Ok(current_segment_hash),
-        Some(l) => get_newest_leafmost_hash(l.target.to_owned().into_entry_hash().unwrap(), index_name),
-    }
+    Ok(positions)
+}
+
+/// Locate all the child links of the node with hash `leaf_hash`, ordered from newest to oldest.
+///
+fn get_ordered_child_links_of_node<I>(index_name: &I, leaf_hash: EntryHash) -> TimeIndexResult<Vec<Link>>
+    where I: AsRef<str>,
+{
+    // query children of parent node
+    let mut siblings = get_links(
+        leaf_hash,
+        LinkTypes::TimeIndex,
+        Some(link_prefix_for_index(index_name)),
+    )?;
+
+    // order them from newest to oldest
+    siblings.sort_unstable_by(|a, b| b.tag.cmp(&a.tag));
+
+    Ok(siblings)
 }
 
 /// Determine the hash of the root node for the given index.
@@ -86,3 +86,85 @@ fn segment_links_exist<I>(index_name: &I, base_hash: &EntryHash, target_segment:
     Ok(get_links(base_hash.to_owned(), LinkTypes::TimeIndex, Some(target_segment.tag_for_index(&index_name)))?
         .len() > 0)
 }
+
+/// Remove an entry with hash `entry_hash` from the time-ordered index identified by `index_name`.
+///
+/// Both links between the entry and its leaf index node are deleted, and any branches of the
+/// index tree left without children are pruned.
+///
+pub fn unindex_entry<I>(index_name: &I, entry_hash: EntryHash) -> TimeIndexResult<()>
+    where I: AsRef<str>,
+{
+    let existing = get_links(entry_hash.to_owned(), LinkTypes::TimeIndex, Some(link_prefix_for_index(index_name)))?;
+    if existing.len() == 0 {
+        return Err(TimeIndexingError::NotIndexed(index_name.as_ref().to_owned(), entry_hash));
+    }
+
+    for leaf_link in existing {
+        // determine the time the entry was indexed at, in order to locate the tree nodes above it
+        let target_entry_segment: IndexSegment = leaf_link.tag.to_owned().try_into()?;
+        let time: DateTime<Utc> = target_entry_segment.into();
+        let leafmost_hash = leaf_link.target.to_owned().into_entry_hash().unwrap();
+
+        // remove link from the target entry back to the leaf index node
+        delete_link(leaf_link.create_link_hash)?;
+
+        // remove link from the leaf index to the target entry
+        for link in get_links(leafmost_hash.to_owned(), LinkTypes::TimeIndex, Some(leaf_link.tag.to_owned()))? {
+            if link.target.to_owned().into_entry_hash().as_ref() == Some(&entry_hash) {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+
+        prune_time_index(index_name, time)?;
+    }
+
+    Ok(())
+}
+
+/// Remove any nodes of the index tree leading to the given `time` which no longer have children,
+/// working upwards from the leafmost node.
+///
+fn prune_time_index<I>(index_name: &I, time: DateTime<Utc>) -> TimeIndexResult<()>
+    where I: AsRef<str>,
+{
+    let root_hash = Path::from(index_name.as_ref()).typed(LinkTypes::TimeIndex)?.path_entry_hash()?;
+
+    // pair each node with the tag of the link leading to it from its parent
+    let mut branch = vec![(root_hash, link_prefix_for_index(index_name))];
+    for segment in get_index_segments(&time) {
+        branch.push((segment.hash()?, segment.tag_for_index(&index_name)));
+    }
+
+    prune_branch(
+        &branch,
+        |(node_hash, _)| Ok(get_links(node_hash.to_owned(), LinkTypes::TimeIndex, Some(link_prefix_for_index(index_name)))?.len() > 0),
+        |(parent_hash, _), (node_hash, node_tag)| {
+            for link in get_links(parent_hash.to_owned(), LinkTypes::TimeIndex, Some(node_tag.to_owned()))? {
+                if link.target.to_owned().into_entry_hash().as_ref() == Some(node_hash) {
+                    delete_link(link.create_link_hash)?;
+                }
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Detach the nodes of an index tree `branch` (ordered from the root to the leafmost node) which have no
+/// children from their parents, stopping at the first node which still has children. The root is never removed.
+///
+/// `unlink` is called with the parent and child node of each link to be removed.
+///
+pub fn prune_branch<N, C, U>(branch: &[N], has_children: C, mut unlink: U) -> TimeIndexResult<()>
+    where C: Fn(&N) -> TimeIndexResult<bool>,
+        U: FnMut(&N, &N) -> TimeIndexResult<()>,
+{
+    for idx in (1..branch.len()).rev() {
+        if has_children(&branch[idx])? {
+            break;
+        }
+        unlink(&branch[idx - 1], &branch[idx])?;
+    }
+
+    Ok(())
+}
@@ -1,20 +1,19 @@
 #[cfg(test)]
 mod empty_indexes {
     use ::fixt::prelude::*;
+    use assert_matches::assert_matches;
+    use chrono::{TimeZone, Utc};
     use hdk::prelude::{
         set_hdk, Entry, GetInput, GetOptions, HashInput, HashOutput, MockHdkT, Path, PathEntry,
     };
     use holo_hash::{fixt::EntryHashFixturator, AnyDhtHash};
 
-    // this test demonstrates that if the index is 'untouched' meaning
-    // it can't possibly have children then it will just early exit and
-    // return an empty set of results
-    #[test]
-    fn get_latest_entry_hashes_empty_scenario() {
+    // mock the calls made when checking for the root of an 'untouched' index,
+    // meaning it can't possibly have children
+    fn mock_untouched_index(index_name: &String) {
         let mut mock_hdk = MockHdkT::new();
 
-        let index_name = "test_index_name".to_string();
-        let root = Path::from(&index_name);
+        let root = Path::from(index_name);
 
         // mock the first call to `hash` via `hash_entry` in `Path.path_entry()`
         let path_hash = fixt!(EntryHash);
@@ -50,9 +49,57 @@ mod empty_indexes {
             .return_once(|_hash_input| Ok(vec![None]));
 
         set_hdk(mock_hdk);
+    }
+
+    // this test demonstrates that if the index is 'untouched' then it will
+    // just early exit and return an empty set of results
+    #[test]
+    fn get_latest_entry_hashes_empty_scenario() {
+        let index_name = "test_index_name".to_string();
+        mock_untouched_index(&index_name);
 
         let result = hdk_time_indexing::reading::get_latest_entry_hashes(&index_name, 2);
         assert!(result.is_ok());
         assert_eq!(result.ok().unwrap(), vec![]);
     }
+
+    // reading from the other end of an 'untouched' index is likewise empty
+    #[test]
+    fn get_earliest_entry_hashes_empty_scenario() {
+        let index_name = "test_index_name".to_string();
+        mock_untouched_index(&index_name);
+
+        let result = hdk_time_indexing::reading::get_earliest_entry_hashes(&index_name, 2);
+        assert!(result.is_ok());
+        assert_eq!(result.ok().unwrap(), vec![]);
+    }
+
+    // time range queries against an 'untouched' index are empty
+    #[test]
+    fn get_entry_hashes_between_empty_scenario() {
+        let index_name = "test_index_name".to_string();
+        mock_untouched_index(&index_name);
+
+        let from = Utc.ymd(2022, 3, 1).and_hms(0, 0, 0);
+        let to = Utc.ymd(2022, 4, 1).and_hms(0, 0, 0);
+        let result = hdk_time_indexing::reading::get_entry_hashes_between(&index_name, from, to, 2);
+        assert!(result.is_ok());
+        assert_eq!(result.ok().unwrap(), vec![]);
+    }
+
+    // removing an entry which was never indexed is reported as such, without modifying the index
+    #[test]
+    fn unindex_entry_not_indexed_scenario() {
+        let mut mock_hdk = MockHdkT::new();
+        mock_hdk
+            .expect_get_links()
+            .times(1)
+            .return_once(|_get_links_input| Ok(vec![vec![]]));
+        mock_hdk.expect_delete_link().never();
+        set_hdk(mock_hdk);
+
+        let index_name = "test_index_name".to_string();
+        let result = hdk_time_indexing::writing::unindex_entry(&index_name, fixt!(EntryHash));
+        assert_matches!(result, Err(hdk_time_indexing::TimeIndexingError::NotIndexed(_, _)));
+    }
 }
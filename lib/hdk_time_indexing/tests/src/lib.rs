@@ -1,5 +1,10 @@
 #[macro_use]
 mod helper_macros;
+#[cfg(test)]
+mod mock_tree;
 
 mod empty_indexes;
 mod data_structures;
+mod paging;
+mod time_ranges;
+mod unindexing;
@@ -0,0 +1,157 @@
+/**
+ * In-memory time index tree for exercising index traversal logic.
+ *
+ * The tree mirrors the structure written by `index_entry` when configured with a
+ * 2 hour chunk interval: year, month and day segments followed by chunk leaves.
+ */
+use std::cell::RefCell;
+use std::collections::HashMap;
+use ::fixt::prelude::*;
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+use hdk::prelude::{set_hdk, DnaInfo, DnaModifiers, EntryHash, MockHdkT, Timestamp};
+use holo_hash::fixt::DnaHashFixturator;
+use hdk_time_indexing::{
+    IndexConfiguration, TimeIndexResult,
+    reading::{read_entry_hashes_from, IndexPosition, ReadDirection, TimeRange},
+    writing::prune_branch,
+};
+
+pub const CHUNK_INTERVAL_MS: usize = 2 * 60 * 60 * 1000;
+
+/// Set the active HDK to provide a DNA configuration with 2 hour index chunks
+pub fn mock_index_config() {
+    let mut mock_hdk = MockHdkT::new();
+    mock_hdk
+        .expect_dna_info()
+        .returning(|_| Ok(DnaInfo {
+            name: "test_dna".to_string(),
+            hash: fixt!(DnaHash),
+            modifiers: DnaModifiers {
+                network_seed: "".to_string(),
+                properties: IndexConfiguration { time_index_chunk_interval_ms: CHUNK_INTERVAL_MS }.try_into().unwrap(),
+                origin_time: Timestamp::HOLOCHAIN_EPOCH,
+                quantum_time: std::time::Duration::from_secs(300),
+            },
+            zome_names: vec![],
+        }));
+    set_hdk(mock_hdk);
+}
+
+/// Hash for an indexed entry. Hashes compare in the order of `n`.
+pub fn entry(n: u8) -> EntryHash {
+    EntryHash::from_raw_36(vec![n; 36])
+}
+
+pub fn time(rfc3339: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(rfc3339).unwrap().into()
+}
+
+#[derive(Default)]
+pub struct MockIndexTree {
+    nodes: HashMap<String, EntryHash>,
+    children: HashMap<EntryHash, Vec<IndexPosition>>,
+    // nodes whose children have been read, in order of reading
+    pub loaded: RefCell<Vec<String>>,
+}
+
+impl MockIndexTree {
+    pub fn new() -> Self {
+        let mut tree = Self::default();
+        tree.node("root");
+        tree
+    }
+
+    /// Index `entry_hash` at the given time, creating any missing branches
+    pub fn index(&mut self, entry_hash: EntryHash, at: DateTime<Utc>) {
+        let mut parent = self.node("root");
+        for (name, start) in branch_segments(&at) {
+            let child = self.node(&name);
+            self.link(parent, (start, child.to_owned()));
+            parent = child;
+        }
+        self.link(parent, (at, entry_hash));
+    }
+
+    /// Remove `entry_hash` indexed at the given time, pruning any branches left without children
+    pub fn unindex(&mut self, entry_hash: EntryHash, at: DateTime<Utc>) {
+        let branch: Vec<EntryHash> = std::iter::once("root".to_string())
+            .chain(branch_segments(&at).into_iter().map(|(name, _)| name))
+            .map(|name| self.node(&name))
+            .collect();
+        self.unlink(branch.last().unwrap(), &entry_hash);
+
+        let children = RefCell::new(&mut self.children);
+        prune_branch(
+            &branch,
+            |node| Ok(children.borrow().get(node).map_or(false, |c| !c.is_empty())),
+            |parent, node| {
+                children.borrow_mut().get_mut(parent).unwrap().retain(|(_, child)| child != node);
+                Ok(())
+            },
+        ).unwrap();
+    }
+
+    pub fn child_count(&self, node_name: &str) -> usize {
+        self.nodes.get(node_name)
+            .and_then(|node| self.children.get(node))
+            .map_or(0, |c| c.len())
+    }
+
+    /// Read from the tree, recording which nodes were loaded
+    pub fn read(&self, cursor: Option<IndexPosition>, range: TimeRange, direction: ReadDirection, limit: usize) -> TimeIndexResult<Vec<EntryHash>> {
+        read_entry_hashes_from(
+            self.nodes["root"].to_owned(), cursor, &range, direction, limit,
+            &|node_hash| {
+                let name = self.nodes.iter().find(|(_, h)| **h == node_hash).unwrap().0;
+                self.loaded.borrow_mut().push(name.to_owned());
+                let mut children = self.children.get(&node_hash).cloned().unwrap_or_default();
+                children.sort_unstable_by(hdk_time_indexing::reading::compare_positions);
+                Ok(children)
+            },
+        )
+    }
+
+    pub fn was_loaded(&self, node_name: &str) -> bool {
+        self.loaded.borrow().iter().any(|n| n == node_name)
+    }
+
+    fn node(&mut self, name: &str) -> EntryHash {
+        let next_id = self.nodes.len() as u16;
+        self.nodes.entry(name.to_string())
+            .or_insert_with(|| {
+                let mut raw = vec![0xff, (next_id >> 8) as u8, next_id as u8];
+                raw.resize(36, 0);
+                EntryHash::from_raw_36(raw)
+            })
+            .to_owned()
+    }
+
+    fn link(&mut self, from: EntryHash, to: IndexPosition) {
+        let children = self.children.entry(from).or_default();
+        if !children.contains(&to) {
+            children.push(to);
+        }
+    }
+
+    fn unlink(&mut self, from: &EntryHash, to: &EntryHash) {
+        if let Some(children) = self.children.get_mut(from) {
+            children.retain(|(_, child)| child != to);
+        }
+    }
+}
+
+/// Names and start times of the year, month, day and chunk segments of the index tree
+/// for the given time, ordered from the root
+fn branch_segments(at: &DateTime<Utc>) -> Vec<(String, DateTime<Utc>)> {
+    let year = Utc.ymd(at.year(), 1, 1).and_hms(0, 0, 0);
+    let month = Utc.ymd(at.year(), at.month(), 1).and_hms(0, 0, 0);
+    let day = Utc.ymd(at.year(), at.month(), at.day()).and_hms(0, 0, 0);
+    let chunk_offset = (at.hour() as usize * 3600 * 1000) / CHUNK_INTERVAL_MS * CHUNK_INTERVAL_MS;
+
+    vec![
+        (format!("{}", at.format("%Y")), year),
+        (format!("{}", at.format("%Y-%m")), month),
+        (format!("{}", at.format("%Y-%m-%d")), day),
+        (format!("{}|{}", at.format("%Y-%m-%d"), chunk_offset), day + Duration::milliseconds(chunk_offset as i64)),
+    ]
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod paging {
+    use hdk_time_indexing::reading::{ReadDirection, TimeRange};
+
+    use crate::mock_tree::*;
+
+    // entries spread over several chunks, days, months and years
+    fn mock_populated_tree() -> MockIndexTree {
+        mock_index_config();
+
+        let mut tree = MockIndexTree::new();
+        tree.index(entry(1), time("2021-12-31T23:59:59Z"));
+        tree.index(entry(2), time("2022-01-01T00:00:00Z"));
+        tree.index(entry(3), time("2022-01-01T01:30:00Z"));
+        tree.index(entry(4), time("2022-01-01T02:00:00Z"));
+        tree.index(entry(5), time("2022-01-02T10:15:00Z"));
+        tree.index(entry(6), time("2022-02-14T09:00:00Z"));
+        tree
+    }
+
+    fn entries(ns: &[u8]) -> Vec<hdk::prelude::EntryHash> {
+        ns.iter().map(|n| entry(*n)).collect()
+    }
+
+    // reading without a cursor traverses every chunk leaf from either end of the index
+    #[test]
+    fn reads_all_chunk_leaves_in_order() {
+        let tree = mock_populated_tree();
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Older, 10).unwrap();
+        assert_eq!(result, entries(&[6, 5, 4, 3, 2, 1]));
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Newer, 10).unwrap();
+        assert_eq!(result, entries(&[1, 2, 3, 4, 5, 6]));
+    }
+
+    // entries in the same chunk and at the same time are ordered by hash
+    #[test]
+    fn orders_by_time_then_hash() {
+        mock_index_config();
+        let same_time = time("2022-03-01T12:00:00Z");
+
+        let mut tree = MockIndexTree::new();
+        tree.index(entry(2), same_time);
+        tree.index(entry(9), time("2022-03-01T12:30:00Z"));
+        tree.index(entry(3), same_time);
+        tree.index(entry(7), time("2022-03-01T11:00:00Z"));
+        tree.index(entry(1), same_time);
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Older, 10).unwrap();
+        assert_eq!(result, entries(&[9, 3, 2, 1, 7]));
+
+        let cursor = Some((same_time, entry(2)));
+        let result = tree.read(cursor.clone(), TimeRange::default(), ReadDirection::Older, 10).unwrap();
+        assert_eq!(result, entries(&[1, 7]), "older reads exclude entries at the cursor time with greater hashes");
+
+        let result = tree.read(cursor, TimeRange::default(), ReadDirection::Newer, 10).unwrap();
+        assert_eq!(result, entries(&[3, 9]), "newer reads exclude entries at the cursor time with lesser hashes");
+    }
+
+    // paging towards older entries continues across chunk, day and year boundaries
+    #[test]
+    fn pages_forwards_from_cursor() {
+        let tree = mock_populated_tree();
+
+        let result = tree.read(Some((time("2022-01-02T10:15:00Z"), entry(5))), TimeRange::default(), ReadDirection::Older, 2).unwrap();
+        assert_eq!(result, entries(&[4, 3]));
+
+        let result = tree.read(Some((time("2022-01-01T01:30:00Z"), entry(3))), TimeRange::default(), ReadDirection::Older, 2).unwrap();
+        assert_eq!(result, entries(&[2, 1]));
+
+        let result = tree.read(Some((time("2021-12-31T23:59:59Z"), entry(1))), TimeRange::default(), ReadDirection::Older, 2).unwrap();
+        assert_eq!(result, entries(&[]), "nothing is older than the oldest entry");
+    }
+
+    // paging towards newer entries returns the nearest entries to the cursor first
+    #[test]
+    fn pages_backwards_from_cursor() {
+        let tree = mock_populated_tree();
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Newer, 2).unwrap();
+        assert_eq!(result, entries(&[1, 2]));
+
+        let result = tree.read(Some((time("2022-01-01T00:00:00Z"), entry(2))), TimeRange::default(), ReadDirection::Newer, 2).unwrap();
+        assert_eq!(result, entries(&[3, 4]));
+
+        let result = tree.read(Some((time("2022-01-01T02:00:00Z"), entry(4))), TimeRange::default(), ReadDirection::Newer, 2).unwrap();
+        assert_eq!(result, entries(&[5, 6]));
+
+        let result = tree.read(Some((time("2022-02-14T09:00:00Z"), entry(6))), TimeRange::default(), ReadDirection::Newer, 2).unwrap();
+        assert_eq!(result, entries(&[]), "nothing is newer than the newest entry");
+    }
+
+    // reading one more entry than a page holds stops as soon as it is found
+    #[test]
+    fn stops_reading_at_limit() {
+        let tree = mock_populated_tree();
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Older, 3).unwrap();
+        assert_eq!(result, entries(&[6, 5, 4]));
+        assert!(!tree.was_loaded("2021"), "branches beyond the limit are not loaded");
+
+        let result = tree.read(None, TimeRange::default(), ReadDirection::Newer, 1).unwrap();
+        assert_eq!(result, entries(&[1]));
+        assert!(!tree.was_loaded("2022-02"), "branches beyond the limit are not loaded");
+    }
+
+    // branches entirely beyond the cursor are skipped without being loaded
+    #[test]
+    fn skips_branches_beyond_cursor() {
+        let tree = mock_populated_tree();
+
+        tree.read(Some((time("2022-01-01T00:00:00Z"), entry(2))), TimeRange::default(), ReadDirection::Older, 10).unwrap();
+        assert!(!tree.was_loaded("2022-02"));
+        assert!(!tree.was_loaded("2022-01-02"));
+        assert!(!tree.was_loaded("2022-01-01|7200000"));
+
+        tree.loaded.borrow_mut().clear();
+        tree.read(Some((time("2022-01-02T10:15:00Z"), entry(5))), TimeRange::default(), ReadDirection::Newer, 10).unwrap();
+        assert!(!tree.was_loaded("2021"));
+        assert!(!tree.was_loaded("2022-01-01"));
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod time_ranges {
+    use hdk::prelude::EntryHash;
+    use hdk_time_indexing::reading::{ReadDirection, TimeRange};
+
+    use crate::mock_tree::*;
+
+    // entries either side of year, month, day and chunk (2 hour) boundaries
+    fn mock_populated_tree() -> MockIndexTree {
+        mock_index_config();
+
+        let mut tree = MockIndexTree::new();
+        tree.index(entry(1), time("2021-12-31T23:59:59Z"));
+        tree.index(entry(2), time("2022-01-01T00:00:00Z"));
+        tree.index(entry(3), time("2022-01-01T01:59:59Z"));
+        tree.index(entry(4), time("2022-01-01T02:00:00Z"));
+        tree.index(entry(5), time("2022-01-01T23:00:00Z"));
+        tree.index(entry(6), time("2022-01-02T00:30:00Z"));
+        tree.index(entry(7), time("2022-01-31T23:30:00Z"));
+        tree.index(entry(8), time("2022-02-01T00:00:00Z"));
+        tree
+    }
+
+    fn between(tree: &MockIndexTree, from: &str, to: &str) -> Vec<EntryHash> {
+        tree.loaded.borrow_mut().clear();
+        let range = TimeRange { from: Some(time(from)), to: Some(time(to)) };
+        tree.read(None, range, ReadDirection::Older, usize::MAX).unwrap()
+    }
+
+    fn entries(ns: &[u8]) -> Vec<EntryHash> {
+        ns.iter().map(|n| entry(*n)).collect()
+    }
+
+    #[test]
+    fn start_inclusive_end_exclusive() {
+        let range = TimeRange { from: Some(time("2022-01-01T00:00:00Z")), to: Some(time("2022-01-01T02:00:00Z")) };
+        assert!(range.contains(&time("2022-01-01T00:00:00Z")), "range includes its start");
+        assert!(range.contains(&time("2022-01-01T01:59:59Z")));
+        assert!(!range.contains(&time("2022-01-01T02:00:00Z")), "range excludes its end");
+        assert!(!range.contains(&time("2021-12-31T23:59:59Z")));
+
+        let open = TimeRange { from: None, to: Some(time("2022-01-01T00:00:00Z")) };
+        assert!(open.contains(&time("1970-01-01T00:00:00Z")), "ranges without a start are open-ended");
+        let open = TimeRange { from: Some(time("2022-01-01T00:00:00Z")), to: None };
+        assert!(open.contains(&time("2999-01-01T00:00:00Z")), "ranges without an end are open-ended");
+
+        let tree = mock_populated_tree();
+        assert_eq!(between(&tree, "2022-01-01T00:00:00Z", "2022-01-01T02:00:00Z"), entries(&[3, 2]));
+        assert_eq!(between(&tree, "2022-01-01T00:00:01Z", "2022-01-01T02:00:01Z"), entries(&[4, 3]));
+        assert_eq!(between(&tree, "2022-01-01T00:00:00Z", "2022-01-01T00:00:00Z"), entries(&[]), "empty ranges match nothing");
+    }
+
+    #[test]
+    fn segment_overlaps() {
+        let range = TimeRange { from: Some(time("2022-01-01T02:00:00Z")), to: Some(time("2022-01-01T04:00:00Z")) };
+        let segment_end = |s: &str| Some(time(s));
+
+        assert!(range.overlaps(&time("2022-01-01T00:00:00Z"), &segment_end("2022-01-02T00:00:00Z")), "enclosing segments overlap");
+        assert!(range.overlaps(&time("2022-01-01T02:00:00Z"), &segment_end("2022-01-01T04:00:00Z")), "identical segments overlap");
+        assert!(!range.overlaps(&time("2022-01-01T00:00:00Z"), &segment_end("2022-01-01T02:00:00Z")), "segments ending at the range start do not overlap");
+        assert!(!range.overlaps(&time("2022-01-01T04:00:00Z"), &None), "segments starting at the range end do not overlap");
+        assert!(range.overlaps(&time("2021-01-01T00:00:00Z"), &None), "segments of unknown length may overlap");
+    }
+
+    #[test]
+    fn reads_across_year_boundary() {
+        let tree = mock_populated_tree();
+
+        assert_eq!(between(&tree, "2021-12-31T00:00:00Z", "2022-01-01T01:00:00Z"), entries(&[2, 1]));
+        assert_eq!(between(&tree, "2021-12-31T00:00:00Z", "2022-01-01T00:00:00Z"), entries(&[1]));
+        assert!(!tree.was_loaded("2022"), "year beginning at the end of the range is not loaded");
+        assert_eq!(between(&tree, "2022-01-01T00:00:00Z", "2022-12-31T00:00:00Z"), entries(&[8, 7, 6, 5, 4, 3, 2]));
+        assert!(!tree.was_loaded("2021-12"), "month ending at the start of the range is not loaded");
+    }
+
+    #[test]
+    fn reads_across_month_boundary() {
+        let tree = mock_populated_tree();
+
+        assert_eq!(between(&tree, "2022-01-31T00:00:00Z", "2022-02-02T00:00:00Z"), entries(&[8, 7]));
+        assert!(!tree.was_loaded("2021"), "years outside the range are not loaded");
+        assert!(!tree.was_loaded("2022-01-01"), "days outside the range are not loaded");
+        assert!(!tree.was_loaded("2022-01-02"), "days outside the range are not loaded");
+
+        assert_eq!(between(&tree, "2022-02-01T00:00:00Z", "2022-03-01T00:00:00Z"), entries(&[8]));
+        assert!(!tree.was_loaded("2022-01"), "month ending at the start of the range is not loaded");
+    }
+
+    #[test]
+    fn reads_across_day_boundary() {
+        let tree = mock_populated_tree();
+
+        assert_eq!(between(&tree, "2022-01-01T22:00:00Z", "2022-01-02T01:00:00Z"), entries(&[6, 5]));
+        assert!(!tree.was_loaded("2022-01-31"), "days outside the range are not loaded");
+        assert!(!tree.was_loaded("2022-01-01|0"), "chunks outside the range are not loaded");
+        assert!(!tree.was_loaded("2022-01-01|7200000"), "chunks outside the range are not loaded");
+    }
+
+    #[test]
+    fn reads_across_hour_boundary() {
+        let tree = mock_populated_tree();
+
+        assert_eq!(between(&tree, "2022-01-01T01:00:00Z", "2022-01-01T03:00:00Z"), entries(&[4, 3]));
+        assert_eq!(between(&tree, "2022-01-01T02:00:00Z", "2022-01-01T04:00:00Z"), entries(&[4]));
+        assert!(!tree.was_loaded("2022-01-01|0"), "chunk ending at the start of the range is not loaded");
+        assert!(!tree.was_loaded("2022-01-01|79200000"), "chunks after the range are not loaded");
+    }
+
+    #[test]
+    fn range_reads_respect_limit() {
+        let tree = mock_populated_tree();
+        let range = TimeRange { from: Some(time("2022-01-01T00:00:00Z")), to: Some(time("2022-02-01T00:00:00Z")) };
+
+        let result = tree.read(None, range, ReadDirection::Older, 2).unwrap();
+        assert_eq!(result, entries(&[7, 6]), "most recent entries within the range are read first");
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod unindexing {
+    use hdk::prelude::EntryHash;
+    use hdk_time_indexing::reading::{ReadDirection, TimeRange};
+
+    use crate::mock_tree::*;
+
+    fn read_all(tree: &MockIndexTree) -> Vec<EntryHash> {
+        tree.read(None, TimeRange::default(), ReadDirection::Older, usize::MAX).unwrap()
+    }
+
+    fn entries(ns: &[u8]) -> Vec<EntryHash> {
+        ns.iter().map(|n| entry(*n)).collect()
+    }
+
+    // removing an entry prunes the branches which led only to it, and leaves shared branches in place
+    #[test]
+    fn index_unindex_read() {
+        mock_index_config();
+
+        let mut tree = MockIndexTree::new();
+        tree.index(entry(1), time("2022-01-01T00:30:00Z"));
+        tree.index(entry(2), time("2022-01-01T01:00:00Z"));
+        tree.index(entry(3), time("2022-03-05T12:00:00Z"));
+        assert_eq!(read_all(&tree), entries(&[3, 2, 1]));
+
+        tree.unindex(entry(3), time("2022-03-05T12:00:00Z"));
+        assert_eq!(read_all(&tree), entries(&[2, 1]));
+        assert_eq!(tree.child_count("2022"), 1, "emptied month is detached from its year");
+        assert_eq!(tree.child_count("2022-03"), 0, "emptied branches are unlinked down to the leaf");
+
+        tree.unindex(entry(1), time("2022-01-01T00:30:00Z"));
+        assert_eq!(read_all(&tree), entries(&[2]));
+        assert_eq!(tree.child_count("2022-01-01|0"), 1, "chunks with remaining entries are not pruned");
+        assert_eq!(tree.child_count("2022-01-01"), 1);
+
+        tree.unindex(entry(2), time("2022-01-01T01:00:00Z"));
+        assert_eq!(read_all(&tree), entries(&[]));
+        assert_eq!(tree.child_count("root"), 0, "whole tree is pruned once empty");
+
+        tree.index(entry(3), time("2022-03-05T12:00:00Z"));
+        assert_eq!(read_all(&tree), entries(&[3]), "pruned branches are restored when re-indexing");
+    }
+
+    // only the links to emptied nodes are removed, stopping at the first node with other children
+    #[test]
+    fn prunes_up_to_first_shared_node() {
+        mock_index_config();
+
+        let mut tree = MockIndexTree::new();
+        tree.index(entry(1), time("2022-01-01T00:30:00Z"));
+        tree.index(entry(2), time("2022-01-01T20:00:00Z"));
+
+        tree.unindex(entry(2), time("2022-01-01T20:00:00Z"));
+        assert_eq!(tree.child_count("2022-01-01"), 1, "emptied chunk is detached from its day");
+        assert_eq!(tree.child_count("2022-01"), 1, "shared day is kept");
+        assert_eq!(tree.child_count("2022"), 1);
+        assert_eq!(tree.child_count("root"), 1);
+        assert_eq!(read_all(&tree), entries(&[1]));
+    }
+}
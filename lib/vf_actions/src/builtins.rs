@@ -7,12 +7,12 @@
  *
  * @see https://github.com/valueflows/valueflows/issues/487#issuecomment-482161938
  */
-use super::{Action, ActionEffect, ProcessType};
+use super::{Action, ActionEffect, ContainedEffect, LocationEffect, ProcessType};
 
 // setup for core actions as in-memory statics
 
 macro_rules! generate_builtin_actions {
-    ($key: expr; $( $a:ident => $e:expr, $f:expr, $g:expr, $h:expr );*) => {
+    ($key: expr; $( $a:ident => $e:expr, $f:expr, $c:expr, $l:expr, $g:expr, $h:expr );*) => {
         match &str::replace($key, "-", "_")[..] {
             $(
                 stringify!($a) => Some(Action {
@@ -20,6 +20,8 @@ macro_rules! generate_builtin_actions {
                     label: str::replace(stringify!($a), "_", "-"),
                     accounting_effect: $e,
                     onhand_effect: $f,
+                    contained_effect: $c,
+                    location_effect: $l,
                     input_output: $g,
                     pairs_with: stringify!($h).to_string(),
                 })
@@ -35,28 +37,34 @@ macro_rules! generate_builtin_actions {
     They are distinct because some actions mark a resource as 'in use';
     ie. it's not 'on hand' anymore, but it hasn't been removed from inventory
     for accounting purposes and is still 'owned' by the same Agent(s).
+    The ContainedEffect and LocationEffect which follow determine how the
+    resource's container and current location are altered by the action.
+    A combined resource is located wherever its container is, so has no
+    location of its own until it is separated again.
 */
 pub fn get_builtin_action(key: &str) -> Option<Action> {
     generate_builtin_actions!(
         key;
-        dropoff => ActionEffect::Increment, ActionEffect::Increment, ProcessType::Output, pickup;
-        pickup => ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::Input, dropoff;
-        consume => ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::Input, notApplicable;
-        use => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        work => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        cite => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        produce => ActionEffect::Increment, ActionEffect::Increment, ProcessType::Output, notApplicable;
-        accept => ActionEffect::NoEffect, ActionEffect::Decrement, ProcessType::Input, modify;
-        modify => ActionEffect::NoEffect, ActionEffect::Increment, ProcessType::Output, accept;
-        pass => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, accept;
-        fail => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, accept;
-        deliver_service => ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, notApplicable;
-        transfer_all_rights => ActionEffect::DecrementIncrement, ActionEffect::NoEffect, ProcessType::NotApplicable, notApplicable;
-        transfer_custody => ActionEffect::NoEffect, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        transfer => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        move => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        raise => ActionEffect::Increment, ActionEffect::Increment, ProcessType::NotApplicable, notApplicable;
-        lower => ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::NotApplicable, notApplicable
+        dropoff => ActionEffect::Increment, ActionEffect::Increment, ContainedEffect::NoEffect, LocationEffect::Update, ProcessType::Output, pickup;
+        pickup => ActionEffect::Decrement, ActionEffect::Decrement, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, dropoff;
+        consume => ActionEffect::Decrement, ActionEffect::Decrement, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, notApplicable;
+        use => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, notApplicable;
+        work => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, notApplicable;
+        cite => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, notApplicable;
+        combine => ActionEffect::NoEffect, ActionEffect::Decrement, ContainedEffect::Update, LocationEffect::Remove, ProcessType::Input, separate;
+        separate => ActionEffect::NoEffect, ActionEffect::Increment, ContainedEffect::Remove, LocationEffect::Update, ProcessType::Output, combine;
+        produce => ActionEffect::Increment, ActionEffect::Increment, ContainedEffect::NoEffect, LocationEffect::New, ProcessType::Output, notApplicable;
+        accept => ActionEffect::NoEffect, ActionEffect::Decrement, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Input, modify;
+        modify => ActionEffect::NoEffect, ActionEffect::Increment, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Output, accept;
+        pass => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Output, accept;
+        fail => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Output, accept;
+        deliver_service => ActionEffect::NoEffect, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::Output, notApplicable;
+        transfer_all_rights => ActionEffect::DecrementIncrement, ActionEffect::NoEffect, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::NotApplicable, notApplicable;
+        transfer_custody => ActionEffect::NoEffect, ActionEffect::DecrementIncrement, ContainedEffect::NoEffect, LocationEffect::Update, ProcessType::NotApplicable, notApplicable;
+        transfer => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ContainedEffect::NoEffect, LocationEffect::Update, ProcessType::NotApplicable, notApplicable;
+        move => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ContainedEffect::NoEffect, LocationEffect::Update, ProcessType::NotApplicable, notApplicable;
+        raise => ActionEffect::Increment, ActionEffect::Increment, ContainedEffect::NoEffect, LocationEffect::New, ProcessType::NotApplicable, notApplicable;
+        lower => ActionEffect::Decrement, ActionEffect::Decrement, ContainedEffect::NoEffect, LocationEffect::NoEffect, ProcessType::NotApplicable, notApplicable
     )
 }
 
@@ -68,6 +76,8 @@ pub fn get_all_builtin_actions() -> Vec<Action> {
         get_builtin_action("use").unwrap(),
         get_builtin_action("work").unwrap(),
         get_builtin_action("cite").unwrap(),
+        get_builtin_action("combine").unwrap(),
+        get_builtin_action("separate").unwrap(),
         get_builtin_action("produce").unwrap(),
         get_builtin_action("accept").unwrap(),
         get_builtin_action("modify").unwrap(),
@@ -94,10 +104,31 @@ mod tests {
             label: "consume".to_string(),
             accounting_effect: ActionEffect::Decrement,
             onhand_effect: ActionEffect::Decrement,
+            contained_effect: ContainedEffect::NoEffect,
+            location_effect: LocationEffect::NoEffect,
             input_output: ProcessType::Input,
             pairs_with: "notApplicable".to_string(),
         };
 
         assert_eq!(get_builtin_action("consume").unwrap(), action);
     }
+
+    #[test]
+    fn test_builtin_containment_actions() {
+        let combine = get_builtin_action("combine").unwrap();
+        assert_eq!(combine.contained_effect, ContainedEffect::Update);
+        assert_eq!(combine.location_effect, LocationEffect::Remove);
+        assert_eq!(combine.input_output, ProcessType::Input);
+        assert_eq!(combine.pairs_with, "separate");
+
+        let separate = get_builtin_action("separate").unwrap();
+        assert_eq!(separate.contained_effect, ContainedEffect::Remove);
+        assert_eq!(separate.location_effect, LocationEffect::Update);
+        assert_eq!(separate.input_output, ProcessType::Output);
+        assert_eq!(separate.pairs_with, "combine");
+
+        assert!(get_all_builtin_actions().iter()
+            .filter(|a| a.id != "combine" && a.id != "separate")
+            .all(|a| a.contained_effect == ContainedEffect::NoEffect));
+    }
 }
@@ -52,6 +52,86 @@ impl<'de> Deserialize<'de> for ActionEffect {
     }
 }
 
+/// Effect of an action upon the `current_location` of the resource it affects
+#[derive(SerializedBytes, Debug, Clone, Copy, PartialEq)]
+pub enum LocationEffect {
+    NoEffect,
+    // location is initialised from the event, for newly created resources
+    New,
+    // location is moved to that of the event
+    Update,
+    // resource no longer has a location
+    Remove,
+}
+
+impl Serialize for LocationEffect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(match *self {
+            LocationEffect::NoEffect => "noEffect",
+            LocationEffect::New => "new",
+            LocationEffect::Update => "update",
+            LocationEffect::Remove => "remove",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationEffect {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "noEffect" => LocationEffect::NoEffect,
+            "new" => LocationEffect::New,
+            "update" => LocationEffect::Update,
+            "remove" => LocationEffect::Remove,
+            &_ => Err(
+                D::Error::custom(format!("Invalid value for LocationEffect: {}", s.as_str()))
+            )?,
+        })
+    }
+}
+
+/// Effect of an action upon the `contained_in` container of the resource it affects
+#[derive(SerializedBytes, Debug, Clone, Copy, PartialEq)]
+pub enum ContainedEffect {
+    NoEffect,
+    // resource is placed in a (different) container
+    Update,
+    // resource is taken out of its container
+    Remove,
+}
+
+impl Serialize for ContainedEffect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(match *self {
+            ContainedEffect::NoEffect => "noEffect",
+            ContainedEffect::Update => "update",
+            ContainedEffect::Remove => "remove",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ContainedEffect {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "noEffect" => ContainedEffect::NoEffect,
+            "update" => ContainedEffect::Update,
+            "remove" => ContainedEffect::Remove,
+            &_ => Err(
+                D::Error::custom(format!("Invalid value for ContainedEffect: {}", s.as_str()))
+            )?,
+        })
+    }
+}
+
 // actual underlying operations applied to particular resources are a subset of higher-level ActionEffect
 #[derive(Debug)]
 pub enum ActionInventoryEffect {
@@ -106,6 +186,8 @@ pub struct Action {
     #[serde(rename = "resourceEffect")]
     pub accounting_effect: ActionEffect,
     pub onhand_effect: ActionEffect,
+    pub contained_effect: ContainedEffect,
+    pub location_effect: LocationEffect,
     pub input_output: ProcessType,
     pub pairs_with: String, // any of the action labels, or "notApplicable"
 }
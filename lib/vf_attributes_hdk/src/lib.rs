@@ -5,8 +5,8 @@ pub use chrono::{ FixedOffset, Utc, DateTime };
 pub use holo_hash::{ AgentPubKey, EntryHash, ActionHash };
 pub use holochain_zome_types::timestamp::Timestamp;
 pub use hdk_uuid_types::{DnaAddressable, DnaIdentifiable};
-pub use hdk_semantic_indexes_zome_rpc::{ByAction, ByAddress, ByRevision};
-pub use hdk_records::{RecordMeta, RevisionMeta};
+pub use hdk_semantic_indexes_zome_rpc::{ByAction, ByAddress, ByRevision, ByRevisionPair, RevisionHistoryParams};
+pub use hdk_records::{RecordMeta, RevisionMeta, RevisionHistory, RevisionDiff};
 
 simple_alias!(ActionId => String);
 
@@ -18,23 +18,35 @@ dna_scoped_string!(UnitId);
 addressable_identifier!(UnitInternalAddress => EntryHash);
 
 addressable_identifier!(AgentAddress => EntryHash);
+addressable_identifier!(AgentRelationshipAddress => EntryHash);
+addressable_identifier!(AgentRelationshipRoleAddress => EntryHash);
 
 addressable_identifier!(EconomicEventAddress => EntryHash);
 addressable_identifier!(EconomicResourceAddress => EntryHash);
 addressable_identifier!(ProductBatchAddress => EntryHash);
 addressable_identifier!(ProcessAddress => EntryHash);
+addressable_identifier!(AppreciationAddress => EntryHash);
 
 addressable_identifier!(CommitmentAddress => EntryHash);
 addressable_identifier!(FulfillmentAddress => EntryHash);
 addressable_identifier!(IntentAddress => EntryHash);
 addressable_identifier!(SatisfactionAddress => EntryHash);
+addressable_identifier!(ClaimAddress => EntryHash);
+addressable_identifier!(SettlementAddress => EntryHash);
 
 addressable_identifier!(PlanAddress => EntryHash);
+addressable_identifier!(ScenarioAddress => EntryHash);
+addressable_identifier!(ScenarioDefinitionAddress => EntryHash);
 addressable_identifier!(AgreementAddress => EntryHash);
 
 addressable_identifier!(ResourceSpecificationAddress => EntryHash);
 addressable_identifier!(ProcessSpecificationAddress => EntryHash);
 
+addressable_identifier!(RecipeResourceAddress => EntryHash);
+addressable_identifier!(RecipeProcessAddress => EntryHash);
+addressable_identifier!(RecipeFlowAddress => EntryHash);
+addressable_identifier!(RecipeExchangeAddress => EntryHash);
+
 addressable_identifier!(ProposedIntentAddress => EntryHash);
 addressable_identifier!(ProposalAddress => EntryHash);
 addressable_identifier!(ProposedToAddress => EntryHash);
@@ -65,3 +65,10 @@ pub fn subtract(q1: QuantityValue, q2: QuantityValue) -> RecordAPIResult<Quantit
         }
     )
 }
+
+pub fn scale(q: QuantityValue, factor: f64) -> QuantityValue {
+    QuantityValue {
+        has_numerical_value: q.has_numerical_value * factor,
+        has_unit: q.has_unit,
+    }
+}
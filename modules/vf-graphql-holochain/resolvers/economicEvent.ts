@@ -29,7 +29,8 @@ import {
 import agentQueries from '../queries/agent.js'
 import agreementQueries from '../queries/agreement.js'
 import resourceQueries from '../queries/economicResource.js'
-import { FulfillmentSearchInput, ProcessSearchInput, SatisfactionSearchInput } from './zomeSearchInputTypes.js'
+import eventQueries from '../queries/economicEvent.js'
+import { FulfillmentSearchInput, ProcessSearchInput, SatisfactionSearchInput, TraceParams, TraceResults } from './zomeSearchInputTypes.js'
 
 export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DNAIdMappings, conductorUri: string) => {
   const hasHistory = -1 !== enabledVFModules.indexOf(VfModule.History)
@@ -49,7 +50,10 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
   const readResourceSpecification = mapZomeFn<ReadParams, ResourceSpecificationResponse>(dnaConfig, conductorUri, 'specification', 'resource_specification', 'get_resource_specification')
   const readAgent = agentQueries(dnaConfig, conductorUri)['agent']
   const readAgreement = agreementQueries(dnaConfig, conductorUri)['agreement']
+  const traceEvent = mapZomeFn<TraceParams, TraceResults>(dnaConfig, conductorUri, 'observation', 'economic_event', 'trace_economic_event')
+  const trackEvent = mapZomeFn<TraceParams, TraceResults>(dnaConfig, conductorUri, 'observation', 'economic_event', 'track_economic_event')
   const readResource = resourceQueries(dnaConfig, conductorUri)['economicResource']
+  const readEvent = eventQueries(dnaConfig, conductorUri)['economicEvent']
 
   return Object.assign(
     {
@@ -73,11 +77,13 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
       next: () => {
         throw new Error('resolver unimplemented')
       },
-      track: () => {
-        throw new Error('resolver unimplemented')
+      track: async (record: EconomicEvent, args: Omit<TraceParams, 'address'>): Promise<EconomicEvent[]> => {
+        const { events } = await trackEvent({ ...args, address: record.id })
+        return Promise.all(events.map((id) => readEvent(record, { id })))
       },
-      trace: () => {
-        throw new Error('resolver unimplemented')
+      trace: async (record: EconomicEvent, args: Omit<TraceParams, 'address'>): Promise<EconomicEvent[]> => {
+        const { events } = await traceEvent({ ...args, address: record.id })
+        return Promise.all(events.map((id) => readEvent(record, { id })))
       },
     },
     (hasProcess ? {
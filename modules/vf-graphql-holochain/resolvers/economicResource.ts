@@ -21,10 +21,12 @@ import {
   ProcessSpecificationResponse,
   ResourceSpecificationResponse,
   Agent,
+  EconomicEvent,
 } from '@valueflows/vf-graphql'
-import { EconomicResourceSearchInput } from './zomeSearchInputTypes.js'
+import { EconomicResourceSearchInput, TraceParams, TraceResults } from './zomeSearchInputTypes.js'
 import { AgentResponse } from '../mutations/agent'
 import agentQueries from '../queries/agent.js'
+import eventQueries from '../queries/economicEvent.js'
 
 export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DNAIdMappings, conductorUri: string) => {
   const hasHistory = -1 !== enabledVFModules.indexOf(VfModule.History)
@@ -42,7 +44,10 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
   const readProcessSpecification = mapZomeFn<ReadParams, ProcessSpecificationResponse>(dnaConfig, conductorUri, 'specification', 'process_specification', 'get_process_specification')
   const readAction = mapZomeFn<ById, Action>(dnaConfig, conductorUri, 'specification', 'action', 'get_action')
   const readResourceSpecification = mapZomeFn<ReadParams, ResourceSpecificationResponse>(dnaConfig, conductorUri, 'specification', 'resource_specification', 'get_resource_specification')
+  const traceResource = mapZomeFn<TraceParams, TraceResults>(dnaConfig, conductorUri, 'observation', 'economic_resource', 'trace_economic_resource')
+  const trackResource = mapZomeFn<TraceParams, TraceResults>(dnaConfig, conductorUri, 'observation', 'economic_resource', 'track_economic_resource')
   const readAgent = agentQueries(dnaConfig, conductorUri)['agent']
+  const readEvent = eventQueries(dnaConfig, conductorUri)['economicEvent']
 
   return Object.assign(
     {
@@ -73,11 +78,13 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
       next: () => {
         throw new Error('resolver unimplemented')
       },
-      track: () => {
-        throw new Error('resolver unimplemented')
+      track: async (record: EconomicResource, args: Omit<TraceParams, 'address'>): Promise<EconomicEvent[]> => {
+        const { events } = await trackResource({ ...args, address: record.id })
+        return Promise.all(events.map((id) => readEvent(record, { id })))
       },
-      trace: () => {
-        throw new Error('resolver unimplemented')
+      trace: async (record: EconomicResource, args: Omit<TraceParams, 'address'>): Promise<EconomicEvent[]> => {
+        const { events } = await traceResource({ ...args, address: record.id })
+        return Promise.all(events.map((id) => readEvent(record, { id })))
       },
     },
     (hasCommitment ? {
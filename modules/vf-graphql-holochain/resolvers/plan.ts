@@ -5,7 +5,7 @@
  * @since:   2019-08-27
  */
 
-import { DNAIdMappings, DEFAULT_VF_MODULES, VfModule, AgentAddress, ByRevision, AddressableIdentifier } from '../types.js'
+import { DNAIdMappings, DEFAULT_VF_MODULES, VfModule, ReadParams, ScenarioAddress, ByRevision, AddressableIdentifier, addTypename } from '../types.js'
 import { extractEdges, mapZomeFn } from '../connection.js'
 
 import {
@@ -16,10 +16,9 @@ import {
   Commitment,
   ProcessConnection,
   CommitmentConnection,
-  AccountingScope
+  Scenario,
 } from '@valueflows/vf-graphql'
 import { CommitmentSearchInput, ProcessSearchInput } from './zomeSearchInputTypes.js'
-import agentQueries from '../queries/agent.js'
 
 
 export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DNAIdMappings, conductorUri: string) => {
@@ -31,7 +30,7 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
   const readRevision = mapZomeFn<ByRevision, PlanResponse>(dnaConfig, conductorUri, 'plan', 'plan', 'get_revision')
   const readProcesses = mapZomeFn<ProcessSearchInput, ProcessConnection>(dnaConfig, conductorUri, 'observation', 'process_index', 'query_processes')
   const queryCommitments = mapZomeFn<CommitmentSearchInput, CommitmentConnection>(dnaConfig, conductorUri, 'planning', 'commitment_index', 'query_commitments')
-  const readAgent = agentQueries(dnaConfig, conductorUri)['agent']
+  const readScenario = mapZomeFn<ReadParams, { scenario: Scenario }>(dnaConfig, conductorUri, 'plan', 'scenario', 'get_scenario')
 
   return Object.assign(
     (hasProcess ? {
@@ -54,10 +53,13 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
       involvedAgents: () => {
         throw new Error('resolver unimplemented')
       },
-      inScopeOf: async (record: { inScopeOf: AgentAddress[] }): Promise<AccountingScope[]> => {
-        return (await Promise.all((record.inScopeOf || []).map((address)=>readAgent(record, {address}))))
-      },
     } : {}),
+    {
+      inScopeOf: async (record: { inScopeOf: ScenarioAddress }): Promise<Scenario[]> => {
+        if (!record.inScopeOf) return []
+        return [addTypename<Scenario>('Scenario')((await readScenario({ address: record.inScopeOf })).scenario)]
+      },
+    },
     (hasHistory ? {
       revision: async (record: Plan, args: { revisionId: AddressableIdentifier }): Promise<Plan> => {
         return (await readRevision(args)).plan
@@ -5,7 +5,7 @@
  * @since:   2019-09-12
  */
 
-import { DNAIdMappings, injectTypename, DEFAULT_VF_MODULES, VfModule, ReadParams, ByRevision, ProcessSpecificationAddress, AgentAddress, AddressableIdentifier } from '../types.js'
+import { DNAIdMappings, injectTypename, addTypename, DEFAULT_VF_MODULES, VfModule, ReadParams, ByRevision, ProcessSpecificationAddress, AgentAddress, ScenarioAddress, AddressableIdentifier } from '../types.js'
 import { mapZomeFn, extractEdges } from '../connection.js'
 
 import {
@@ -21,9 +21,10 @@ import {
   IntentConnection,
   ProcessSpecificationResponse,
   Agent,
-  AccountingScope
+  Scenario,
 } from '@valueflows/vf-graphql'
 import planQueries from '../queries/plan.js'
+import eventQueries from '../queries/economicEvent.js'
 import { CommitmentSearchInput, EconomicEventSearchInput, IntentSearchInput } from './zomeSearchInputTypes.js'
 import { AgentResponse } from '../mutations/agent.js'
 
@@ -43,6 +44,10 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
   const readProcessBasedOn = mapZomeFn<ReadParams, ProcessSpecificationResponse>(dnaConfig, conductorUri, 'specification', 'process_specification', 'get_process_specification')
   const readPlan = planQueries(dnaConfig, conductorUri)['plan']
   const readAgent = mapZomeFn<ReadParams, AgentResponse>(dnaConfig, conductorUri, 'agent', 'agent', 'get_agent')
+  const readEvent = eventQueries(dnaConfig, conductorUri)['economicEvent']
+  const readUnplannedEvents = mapZomeFn<ReadParams, { unplannedInputs: AddressableIdentifier[], unplannedOutputs: AddressableIdentifier[] }>(dnaConfig, conductorUri, 'observation', 'process', 'get_unplanned_economic_events')
+  const readWorkingAgents = mapZomeFn<ReadParams, { workingAgents: AgentAddress[] }>(dnaConfig, conductorUri, 'observation', 'process', 'get_working_agents')
+  const readScenario = mapZomeFn<ReadParams, { scenario: Scenario }>(dnaConfig, conductorUri, 'plan', 'scenario', 'get_scenario')
 
   return Object.assign(
     {
@@ -64,11 +69,13 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
         return extractEdges(results)
       }),
 
-      unplannedInputs: () => {
-        throw new Error('resolver unimplemented')
+      unplannedInputs: async (record: Process): Promise<EconomicEvent[]> => {
+        const { unplannedInputs } = await readUnplannedEvents({ address: record.id })
+        return Promise.all(unplannedInputs.map((id) => readEvent(record, { id })))
       },
-      unplannedOutputs: () => {
-        throw new Error('resolver unimplemented')
+      unplannedOutputs: async (record: Process): Promise<EconomicEvent[]> => {
+        const { unplannedOutputs } = await readUnplannedEvents({ address: record.id })
+        return Promise.all(unplannedOutputs.map((id) => readEvent(record, { id })))
       },
       previous: () => {
         throw new Error('resolver unimplemented')
@@ -108,16 +115,18 @@ export default (enabledVFModules: VfModule[] = DEFAULT_VF_MODULES, dnaConfig: DN
       plannedWithin: async (record: Process): Promise<Plan> => {
         return (await readPlan(record, { id: record.plannedWithin }))
       },
+      inScopeOf: async (record: { inScopeOf: ScenarioAddress }): Promise<Scenario[]> => {
+        if (!record.inScopeOf) return []
+        return [addTypename<Scenario>('Scenario')((await readScenario({ address: record.inScopeOf })).scenario)]
+      },
     } : {}),
     (hasAgent ? {
       involvedAgents: () => {
         throw new Error('resolver unimplemented')
       },
-      workingAgents: async (record: { workingAgents: AgentAddress[] }): Promise<Agent[]> => {
-        return (await Promise.all((record.workingAgents || []).map((address)=>readAgent({address})))).map((agentResponse) => agentResponse.agent)
-      },
-      inScopeOf: async (record: { inScopeOf: AgentAddress[] }): Promise<AccountingScope[]> => {
-        return (await Promise.all((record.inScopeOf || []).map((address)=>readAgent({address})))).map((agentResponse) => agentResponse.agent)
+      workingAgents: async (record: Process): Promise<Agent[]> => {
+        const { workingAgents } = await readWorkingAgents({ address: record.id })
+        return (await Promise.all((workingAgents || []).map((address)=>readAgent({address})))).map((agentResponse) => agentResponse.agent)
       },
     } : {}),
     (hasHistory ? {
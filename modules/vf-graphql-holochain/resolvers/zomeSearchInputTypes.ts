@@ -1,18 +1,33 @@
-import { EntryHash } from "@holochain/client"
 import { AgentAddress, AgreementAddress, CommitmentAddress, EconomicEventAddress, EconomicResourceAddress, FulfillmentAddress, IntentAddress, PlanAddress, ProcessAddress, ProposedIntentAddress, ResourceSpecificationAddress, SatisfactionAddress } from "../types";
 
 // this type name
 // matches the Rust side type name
 export interface PagingParams {
-    // TODO: forwards pagination
-    // first: number
-    // after: string
+    first?: number,
+    after?: string,
     last?: number,
-    before?: EntryHash
+    before?: string,
 }
 
-interface SearchInput<QueryParamType> {
+// matches `TraceParams` / `TraceResults` in the EconomicEvent RPC crate
+export interface TraceParams {
+  address: EconomicEventAddress | EconomicResourceAddress | ProcessAddress,
+  maxDepth?: number,
+  first?: number,
+  after?: EconomicEventAddress,
+}
+
+export interface TraceResults {
+  events: EconomicEventAddress[],
+  resources: EconomicResourceAddress[],
+  hasNextPage: boolean,
+}
+
+interface SearchInput<QueryParamType> extends PagingParams {
   params: QueryParamType,
+  // ISO8601 timestamps; `createdAfter` is inclusive and `createdBefore` exclusive
+  createdAfter?: string,
+  createdBefore?: string,
 }
 
 export type CommitmentSearchInput = SearchInput<CommitmentQueryParam>
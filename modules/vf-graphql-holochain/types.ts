@@ -89,6 +89,7 @@ export type FulfillmentAddress = AddressableIdentifier
 export type SatisfactionAddress = AddressableIdentifier
 export type AgreementAddress = AddressableIdentifier
 export type PlanAddress = AddressableIdentifier
+export type ScenarioAddress = AddressableIdentifier
 export type ProposalAddress = AddressableIdentifier
 export type IntentAddress = AddressableIdentifier
 export type AgentAddress = AddressableIdentifier
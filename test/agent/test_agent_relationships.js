@@ -0,0 +1,123 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import { buildPlayer } from '../init.js'
+
+const examplePerson = {
+  agentType: 'Person',
+  name: 'test person',
+}
+const exampleOrganization = {
+  agentType: 'Organization',
+  name: 'test organization',
+}
+const exampleRole = {
+  roleLabel: 'member of',
+  inverseRoleLabel: 'has member',
+  note: 'membership role',
+}
+const exampleOtherRole = {
+  roleLabel: 'supplier to',
+}
+
+test('AgentRelationship & AgentRelationshipRole record API', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['agent'])
+  try {
+    const { cells: [agent] } = alice
+
+    let resp = await agent.call('agent', 'create_agent', { agent: examplePerson })
+    await pause(100)
+    const personId = resp.agent.id
+    resp = await agent.call('agent', 'create_agent', { agent: exampleOrganization })
+    await pause(100)
+    const orgId = resp.agent.id
+
+    // SCENARIO: AgentRelationshipRole CRUD
+    resp = await agent.call('agent_relationship_role', 'create_agent_relationship_role', { agentRelationshipRole: exampleRole })
+    await pause(100)
+    t.ok(resp.agentRelationshipRole && resp.agentRelationshipRole.id, 'role created successfully')
+    const roleId = resp.agentRelationshipRole.id
+
+    resp = await agent.call('agent_relationship_role', 'get_agent_relationship_role', { address: roleId })
+    t.equal(resp.agentRelationshipRole.roleLabel, exampleRole.roleLabel, 'role label read OK')
+    t.equal(resp.agentRelationshipRole.inverseRoleLabel, exampleRole.inverseRoleLabel, 'inverse role label read OK')
+
+    resp = await agent.call('agent_relationship_role', 'update_agent_relationship_role', {
+      agentRelationshipRole: { revisionId: resp.agentRelationshipRole.revisionId, note: 'updated role' },
+    })
+    await pause(100)
+    t.equal(resp.agentRelationshipRole.note, 'updated role', 'role updated')
+    t.equal(resp.agentRelationshipRole.roleLabel, exampleRole.roleLabel, 'unchanged role fields preserved on update')
+
+    resp = await agent.call('agent_relationship_role', 'create_agent_relationship_role', { agentRelationshipRole: exampleOtherRole })
+    await pause(100)
+    const otherRoleId = resp.agentRelationshipRole.id
+
+    // SCENARIO: AgentRelationship CRUD
+    resp = await agent.call('agent_relationship', 'create_agent_relationship', {
+      agentRelationship: { subject: personId, object: orgId, relationship: roleId, note: 'membership' },
+    })
+    await pause(100)
+    t.ok(resp.agentRelationship && resp.agentRelationship.id, 'relationship created successfully')
+    const relationshipId = resp.agentRelationship.id
+
+    resp = await agent.call('agent_relationship', 'get_agent_relationship', { address: relationshipId })
+    t.deepLooseEqual(resp.agentRelationship.subject, personId, 'subject read OK')
+    t.deepLooseEqual(resp.agentRelationship.object, orgId, 'object read OK')
+    t.deepLooseEqual(resp.agentRelationship.relationship, roleId, 'role read OK')
+
+    resp = await agent.call('agent_relationship', 'update_agent_relationship', {
+      agentRelationship: { revisionId: resp.agentRelationship.revisionId, note: 'updated membership' },
+    })
+    await pause(100)
+    t.equal(resp.agentRelationship.note, 'updated membership', 'relationship updated')
+
+    resp = await agent.call('agent_relationship', 'create_agent_relationship', {
+      agentRelationship: { subject: orgId, object: personId, relationship: otherRoleId },
+    })
+    await pause(100)
+    const otherRelationship = resp.agentRelationship
+
+    // SCENARIO: index queries
+    resp = await agent.call('agent_relationship_index', 'query_agent_relationships', { params: { subject: personId } })
+    t.equal(resp.edges.length, 1, 'relationships queryable by subject')
+    t.deepLooseEqual(resp.edges[0].node.id, relationshipId, 'subject query returns the correct relationship')
+
+    resp = await agent.call('agent_relationship_index', 'query_agent_relationships', { params: { relationship: otherRoleId } })
+    t.equal(resp.edges.length, 1, 'relationships queryable by role')
+    t.deepLooseEqual(resp.edges[0].node.id, otherRelationship.id, 'role query returns the correct relationship')
+
+    resp = await agent.call('agent_relationship_role_index', 'query_agent_relationship_roles', { params: { agentRelationships: relationshipId } })
+    t.equal(resp.edges.length, 1, 'roles queryable by relationship')
+    t.deepLooseEqual(resp.edges[0].node.id, roleId, 'relationship query returns the correct role')
+
+    resp = await agent.call('agent_relationship', 'get_members_of', { agent: orgId })
+    t.deepLooseEqual(resp, [personId], 'members of an organization read OK')
+
+    resp = await agent.call('agent_relationship', 'get_memberships_of', { agent: personId, role: roleId })
+    t.deepLooseEqual(resp, [orgId], 'memberships filtered by role OK')
+
+    resp = await agent.call('agent_relationship', 'get_memberships_of', { agent: personId, role: otherRoleId })
+    t.deepLooseEqual(resp, [], 'memberships via other roles excluded')
+
+    // SCENARIO: delete
+    resp = await agent.call('agent_relationship', 'delete_agent_relationship', { revisionId: otherRelationship.revisionId })
+    t.ok(resp, 'relationship deleted successfully')
+    await pause(100)
+
+    resp = await agent.call('agent_relationship_index', 'query_agent_relationships', { params: { relationship: otherRoleId } })
+    t.equal(resp.edges.length, 0, 'deleted relationship removed from indexes')
+
+    try {
+      await agent.call('agent_relationship', 'get_agent_relationship', { address: otherRelationship.id })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'relationship not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,113 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+  mockAddress,
+} from '../init.js'
+
+const testEventProps = {
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+test('Claim record API & settlement against events', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['planning', 'observation'])
+  try {
+    const { cells: [planning, observation] } = alice
+
+    // SCENARIO: a delivered event triggers a claim for payment
+    let resp = await observation.call('economic_event', 'create_economic_event', { event: { action: 'transfer_custody', ...testEventProps } })
+    await pause(100)
+    const triggerEventId = resp.economicEvent.id
+
+    resp = await planning.call('claim', 'create_claim', {
+      claim: {
+        action: 'transfer',
+        provider: testEventProps.receiver,
+        receiver: testEventProps.provider,
+        triggeredBy: triggerEventId,
+        resourceQuantity: { hasNumericalValue: 10, hasUnit: mockIdentifier(false) },
+        note: 'payment due for delivery',
+      },
+    })
+    await pause(100)
+    t.ok(resp.claim && resp.claim.id, 'claim created successfully')
+    const claim = resp.claim
+
+    resp = await planning.call('claim', 'get_claim', { address: claim.id })
+    t.deepLooseEqual(resp.claim.triggeredBy, triggerEventId, 'Claim.triggeredBy saved')
+    t.equal(resp.claim.action, 'transfer', 'Claim.action saved')
+
+    resp = await planning.call('claim', 'update_claim', { claim: { revisionId: claim.revisionId, note: 'updated claim' } })
+    await pause(100)
+    t.equal(resp.claim.note, 'updated claim', 'claim updated')
+    t.deepLooseEqual(resp.claim.triggeredBy, triggerEventId, 'unchanged claim fields preserved on update')
+
+    // SCENARIO: settle the claim with a payment event
+    resp = await observation.call('economic_event', 'create_economic_event', { event: { action: 'transfer', ...testEventProps } })
+    await pause(100)
+    const paymentEventId = resp.economicEvent.id
+
+    resp = await planning.call('settlement', 'create_settlement', {
+      settlement: { settles: claim.id, settledBy: paymentEventId, note: 'paid in full' },
+    })
+    await pause(100)
+    t.ok(resp.settlement && resp.settlement.id, 'settlement created successfully')
+    const settlement = resp.settlement
+    const settlementIdObs = [paymentEventId[0], settlement.id[1]] // :NOTE: ID in dest network will be same EntryHash, different DnaHash
+
+    // ASSERT: origin network
+    resp = await planning.call('settlement', 'get_settlement', { address: settlement.id })
+    t.deepLooseEqual(resp.settlement.settles, claim.id, 'Settlement.settles saved in planning DNA')
+    t.deepLooseEqual(resp.settlement.settledBy, paymentEventId, 'Settlement.settledBy saved in planning DNA')
+
+    resp = await planning.call('claim', 'get_claim', { address: claim.id })
+    t.deepLooseEqual(resp.claim.settledBy, [settlement.id], 'Claim.settledBy reciprocal reference saved')
+
+    resp = await planning.call('settlement_index', 'query_settlements', { params: { settles: claim.id } })
+    t.equal(resp.edges.length, 1, 'settlements queryable by claim')
+    t.deepLooseEqual(resp.edges[0].node.id, settlement.id, 'claim query returns the correct settlement')
+
+    resp = await planning.call('claim_index', 'query_claims', { params: { settledBy: settlement.id } })
+    t.equal(resp.edges.length, 1, 'claims queryable by settlement')
+    t.deepLooseEqual(resp.edges[0].node.id, claim.id, 'settlement query returns the correct claim')
+
+    // ASSERT: destination network
+    resp = await observation.call('settlement', 'get_settlement', { address: settlementIdObs })
+    t.deepLooseEqual(resp.settlement.settles, claim.id, 'Settlement.settles saved in observation DNA')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: paymentEventId })
+    t.deepLooseEqual(resp.economicEvent.settles, [settlementIdObs], 'EconomicEvent.settles reference saved in observation DNA')
+
+    resp = await observation.call('settlement_index', 'query_settlements', { params: { settledBy: paymentEventId } })
+    t.equal(resp.edges.length, 1, 'settlements queryable by event')
+
+    // SCENARIO: delete settlement
+    resp = await planning.call('settlement', 'delete_settlement', { revisionId: settlement.revisionId })
+    t.ok(resp, 'settlement deleted successfully')
+    await pause(100)
+
+    resp = await planning.call('claim', 'get_claim', { address: claim.id })
+    t.notOk(resp.claim.settledBy, 'Claim.settledBy reference removed after deletion')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: paymentEventId })
+    t.notOk(resp.economicEvent.settles, 'EconomicEvent.settles reference removed after deletion')
+
+    try {
+      await planning.call('settlement', 'get_settlement', { address: settlement.id })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'settlement not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
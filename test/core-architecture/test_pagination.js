@@ -0,0 +1,94 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const testEventProps = {
+  action: 'transfer_custody',
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+}
+
+const ids = (resp) => resp.edges.map(e => e.node.id.toString())
+const expected = (...events) => events.map(id => id.toString())
+const cursorOf = (resp, idx) => resp.edges[idx].cursor
+
+test('index zome pagination', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const player = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = player
+
+    // events spread across chunks, days and months of the time index, created out of order
+    const eventIds = {}
+    for (const [idx, hasPointInTime] of [
+      [3, '2022-01-02T10:00:00Z'],
+      [1, '2021-12-31T23:00:00Z'],
+      [5, '2022-03-01T00:00:00Z'],
+      [2, '2022-01-01T01:00:00Z'],
+      [4, '2022-01-02T14:00:00Z'],
+    ]) {
+      const resp = await observation.call('economic_event', 'create_economic_event', { event: { ...testEventProps, hasPointInTime } })
+      await pause(100)
+      eventIds[idx] = resp.economicEvent.id
+    }
+    const readPage = (args) => observation.call('economic_event_index', 'read_all_economic_events_by_has_point_in_time', args)
+
+    // SCENARIO: paging forwards with first / after
+    let resp = await readPage({ first: 2 })
+    t.deepEqual(ids(resp), expected(eventIds[5], eventIds[4]), 'first page contains most recent events')
+    t.equal(resp.pageInfo.hasPreviousPage, false, 'first page has no previous page')
+    t.equal(resp.pageInfo.hasNextPage, true, 'first page has a next page')
+
+    resp = await readPage({ first: 2, after: cursorOf(resp, 1) })
+    t.deepEqual(ids(resp), expected(eventIds[3], eventIds[2]), 'second page continues from cursor')
+    t.equal(resp.pageInfo.hasPreviousPage, true, 'second page has a previous page')
+    t.equal(resp.pageInfo.hasNextPage, true, 'second page has a next page')
+
+    resp = await readPage({ first: 2, after: cursorOf(resp, 1) })
+    t.deepEqual(ids(resp), expected(eventIds[1]), 'last page contains remaining events')
+    t.equal(resp.pageInfo.hasPreviousPage, true, 'last page has a previous page')
+    t.equal(resp.pageInfo.hasNextPage, false, 'last page has no next page')
+
+    resp = await readPage({ first: 5 })
+    t.deepEqual(ids(resp), expected(eventIds[5], eventIds[4], eventIds[3], eventIds[2], eventIds[1]), 'page of exactly all events')
+    t.equal(resp.pageInfo.hasNextPage, false, 'page of exactly all events has no next page')
+
+    // SCENARIO: paging backwards with last / before
+    resp = await readPage({ last: 2 })
+    t.deepEqual(ids(resp), expected(eventIds[2], eventIds[1]), 'last page contains oldest events, most recent first')
+    t.equal(resp.pageInfo.hasPreviousPage, true, 'oldest page has a previous page')
+    t.equal(resp.pageInfo.hasNextPage, false, 'oldest page has no next page')
+
+    resp = await readPage({ last: 2, before: cursorOf(resp, 0) })
+    t.deepEqual(ids(resp), expected(eventIds[4], eventIds[3]), 'backward page continues from cursor')
+    t.equal(resp.pageInfo.hasPreviousPage, true, 'middle page has a previous page')
+    t.equal(resp.pageInfo.hasNextPage, true, 'middle page has a next page')
+
+    resp = await readPage({ last: 2, before: cursorOf(resp, 0) })
+    t.deepEqual(ids(resp), expected(eventIds[5]), 'backward paging reaches most recent events')
+    t.equal(resp.pageInfo.hasPreviousPage, false, 'most recent page has no previous page')
+    t.equal(resp.pageInfo.hasNextPage, true, 'most recent page has a next page')
+
+    // SCENARIO: paginated queries
+    resp = await observation.call('economic_event_index', 'query_economic_events', { params: { provider: testEventProps.provider }, first: 3 })
+    t.equal(resp.edges.length, 3, 'query results limited to page size')
+    t.equal(resp.pageInfo.hasNextPage, true, 'query page has a next page')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', { params: { provider: testEventProps.provider }, first: 3, after: cursorOf(resp, 2) })
+    t.equal(resp.edges.length, 2, 'query results continue from cursor')
+    t.equal(resp.pageInfo.hasPreviousPage, true, 'second query page has a previous page')
+    t.equal(resp.pageInfo.hasNextPage, false, 'second query page has no next page')
+  } catch (e) {
+    await player.scenario.cleanUp()
+    throw e
+  }
+  await player.scenario.cleanUp()
+})
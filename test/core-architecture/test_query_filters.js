@@ -0,0 +1,68 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const alice = mockAddress(false)
+const bob = mockAddress(false)
+const carol = mockAddress(false)
+const testEventProps = {
+  action: 'transfer_custody',
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  hasPointInTime: '2022-01-01T00:00:00Z',
+}
+
+const ids = (resp) => resp.edges.map(e => e.node.id.toString()).sort()
+const expected = (...events) => events.map(id => id.toString()).sort()
+
+test('index zome query combinators', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const player = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = player
+
+    const eventIds = {}
+    for (const [name, provider, receiver] of [['ab', alice, bob], ['ac', alice, carol], ['bc', bob, carol]]) {
+      const resp = await observation.call('economic_event', 'create_economic_event', { event: { provider, receiver, ...testEventProps } })
+      await pause(100)
+      eventIds[name] = resp.economicEvent.id
+    }
+
+    let resp = await observation.call('economic_event_index', 'query_economic_events', { params: { provider: alice, receiver: carol } })
+    t.deepEqual(ids(resp), expected(eventIds.ac), 'all params must match')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', {
+      params: { provider: alice },
+      filter: { and: [{ receiver: bob }] },
+    })
+    t.deepEqual(ids(resp), expected(eventIds.ab), 'and sets intersect with params')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', {
+      params: {},
+      filter: { or: [{ receiver: bob }, { provider: bob }] },
+    })
+    t.deepEqual(ids(resp), expected(eventIds.ab, eventIds.bc), 'or sets match any')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', {
+      params: { receiver: carol },
+      filter: { not: [{ provider: bob }] },
+    })
+    t.deepEqual(ids(resp), expected(eventIds.ac), 'not sets exclude from params')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', {
+      params: {},
+      filter: { not: [{ provider: alice }] },
+    })
+    t.deepEqual(ids(resp), expected(eventIds.bc), 'not-only queries exclude from all records')
+  } catch (e) {
+    await player.scenario.cleanUp()
+    throw e
+  }
+  await player.scenario.cleanUp()
+})
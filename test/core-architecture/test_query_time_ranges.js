@@ -0,0 +1,75 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const testEventProps = {
+  action: 'transfer_custody',
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  hasPointInTime: '2022-01-01T00:00:00Z',
+}
+
+const ids = (resp) => resp.edges.map(e => e.node.id.toString())
+const expected = (...events) => events.map(id => id.toString())
+
+test('index zome creation time range queries', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const player = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = player
+
+    // events are created either side of two points in time
+    const eventIds = []
+    const boundaries = []
+    for (const batch of [1, 2, 1]) {
+      if (eventIds.length) {
+        await pause(1000)
+        boundaries.push(new Date().toISOString())
+        await pause(1000)
+      }
+      for (let i = 0; i < batch; ++i) {
+        const resp = await observation.call('economic_event', 'create_economic_event', { event: testEventProps })
+        await pause(100)
+        eventIds.push(resp.economicEvent.id)
+      }
+    }
+    const [firstBoundary, secondBoundary] = boundaries
+    const params = { provider: testEventProps.provider }
+    const query = (args) => observation.call('economic_event_index', 'query_economic_events', { params, ...args })
+
+    let resp = await query({ createdAfter: firstBoundary })
+    t.deepEqual(ids(resp), expected(eventIds[3], eventIds[2], eventIds[1]), 'createdAfter excludes earlier records')
+
+    resp = await query({ createdBefore: secondBoundary })
+    t.deepEqual(ids(resp), expected(eventIds[2], eventIds[1], eventIds[0]), 'createdBefore excludes later records')
+
+    resp = await query({ createdAfter: firstBoundary, createdBefore: secondBoundary })
+    t.deepEqual(ids(resp), expected(eventIds[2], eventIds[1]), 'createdAfter and createdBefore combine')
+
+    resp = await query({ createdAfter: secondBoundary, createdBefore: firstBoundary })
+    t.deepEqual(ids(resp), [], 'inverted ranges match nothing')
+
+    resp = await query({ createdAfter: firstBoundary, createdBefore: secondBoundary, first: 1 })
+    t.deepEqual(ids(resp), expected(eventIds[2]), 'time ranges are paginated')
+    t.equal(resp.pageInfo.hasNextPage, true, 'paginated time range has a next page')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', {
+      params: {},
+      filter: { not: [{ receiver: mockAddress(false) }] },
+      createdBefore: firstBoundary,
+    })
+    t.deepEqual(ids(resp), expected(eventIds[0]), 'time ranges restrict not-only queries')
+  } catch (e) {
+    await player.scenario.cleanUp()
+    throw e
+  }
+  await player.scenario.cleanUp()
+})
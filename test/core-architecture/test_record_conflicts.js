@@ -0,0 +1,133 @@
+import test from 'tape'
+import { pause, dhtSync } from '@holochain/tryorama'
+import {
+  buildPlayer,
+} from '../init.js'
+
+test('record conflict detection & resolution API', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['plan'])
+  try {
+    const { cells: [planCell] } = alice
+
+    let resp = await planCell.call('plan', 'create_plan', { plan: { name: 'original plan' } })
+    await pause(100)
+    const planId = resp.plan.id
+    const r1Id = resp.plan.revisionId
+
+    resp = await planCell.call('plan', 'update_plan', { plan: { revisionId: r1Id, name: 'updated plan' } })
+    await pause(100)
+    const r2Id = resp.plan.revisionId
+
+    resp = await planCell.call('plan', 'get_plan', { address: planId })
+    t.deepLooseEqual(resp.plan.revisionId, r2Id, 'latest revision read')
+    t.notOk(resp.plan.meta.conflictingRevisions, 'no conflicts reported for linear history')
+
+    // SCENARIO: stale & partial writes are rejected rather than forking the history
+    try {
+      await planCell.call('plan', 'update_plan', { plan: { revisionId: r1Id, name: 'stale plan' } })
+      t.fail('update of superseded revision should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('Conflicting revisions'), 'update of superseded revision rejected')
+    }
+
+    try {
+      await planCell.call('plan', 'resolve_plan_conflict', {
+        conflictingRevisions: [r1Id],
+        plan: { revisionId: r1Id, name: 'stale merge' },
+      })
+      t.fail('resolution not listing the current heads should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('Conflicting revisions'), 'resolution not listing the current heads rejected')
+    }
+
+    // SCENARIO: resolution against the current heads writes a merge revision
+    resp = await planCell.call('plan', 'resolve_plan_conflict', {
+      conflictingRevisions: [r2Id],
+      plan: { revisionId: r2Id, name: 'merged plan' },
+    })
+    await pause(100)
+    t.equal(resp.plan.name, 'merged plan', 'merge revision written')
+    const r3Id = resp.plan.revisionId
+
+    resp = await planCell.call('plan', 'get_plan', { address: planId })
+    t.deepLooseEqual(resp.plan.revisionId, r3Id, 'merge revision read as latest')
+    t.equal(resp.plan.name, 'merged plan', 'merged data read')
+
+    resp = await planCell.call('plan', 'get_revision_history', { address: planId })
+    t.equal(resp.totalCount, 3, 'all revisions retained in history')
+    t.ok(resp.revisions.every(r => r.revisionType !== 'Delete'), 'merge not recorded as a deletion')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
+
+test('reading records with conflicting revisions', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['plan'])
+  try {
+    const bob = await buildPlayer(['plan'], undefined, alice.scenario)
+    await alice.scenario.shareAllAgents()
+    const { cells: [alicePlan] } = alice
+    const { cells: [bobPlan] } = bob
+    const sync = () => dhtSync([alice.player, bob.player], alicePlan.cell_id[0])
+
+    let resp = await alicePlan.call('plan', 'create_plan', { plan: { name: 'original plan' } })
+    const planId = resp.plan.id
+    const r1Id = resp.plan.revisionId
+    await sync()
+
+    // SCENARIO: both agents edit the same revision before seeing each other's changes
+    const edits = await Promise.all([
+      alicePlan.call('plan', 'update_plan', { plan: { revisionId: r1Id, name: 'alice plan' } }),
+      bobPlan.call('plan', 'update_plan', { plan: { revisionId: r1Id, name: 'bob plan' } }),
+    ])
+    await sync()
+    const heads = edits.map(e => e.plan.revisionId)
+
+    resp = await alicePlan.call('plan', 'get_plan', { address: planId })
+    const latestId = resp.plan.revisionId
+    const conflictingIds = heads.filter(h => h.toString() !== latestId.toString())
+    t.ok(heads.find(h => h.toString() === latestId.toString()), 'one of the conflicting revisions read as latest')
+    t.equal(conflictingIds.length, 1, 'revision history forked')
+    t.deepLooseEqual(resp.plan.meta.conflictingRevisions.map(r => r.id), conflictingIds, 'conflict reported by record read')
+    t.deepLooseEqual(resp.plan.meta.latestRevision.id, latestId, 'latest head reported by record read')
+
+    resp = await bobPlan.call('plan', 'get_plan', { address: planId })
+    t.deepLooseEqual(resp.plan.revisionId, latestId, 'all agents read the same head')
+    t.deepLooseEqual(resp.plan.meta.conflictingRevisions.map(r => r.id), conflictingIds, 'all agents see the conflict')
+
+    resp = await alicePlan.call('plan_index', 'read_all_plans', {})
+    const edge = resp.edges.find(e => e.node.id.toString() === planId.toString())
+    t.deepLooseEqual(edge.node.revisionId, latestId, 'list reads return the same head')
+    t.deepLooseEqual(edge.node.meta.conflictingRevisions.map(r => r.id), conflictingIds, 'conflict reported by list reads')
+
+    resp = await alicePlan.call('plan', 'get_revision', { revisionId: r1Id })
+    t.deepLooseEqual(resp.plan.meta.conflictingRevisions.map(r => r.id), conflictingIds, 'conflict reported when reading earlier revisions')
+
+    // SCENARIO: resolving the conflict clears it from reads
+    resp = await bobPlan.call('plan', 'resolve_plan_conflict', {
+      conflictingRevisions: heads,
+      plan: { revisionId: latestId, name: 'merged plan' },
+    })
+    await sync()
+    const mergedId = resp.plan.revisionId
+
+    resp = await alicePlan.call('plan', 'get_plan', { address: planId })
+    t.deepLooseEqual(resp.plan.revisionId, mergedId, 'merge revision read as latest')
+    t.notOk(resp.plan.meta.conflictingRevisions, 'no conflicts reported once resolved')
+
+    resp = await alicePlan.call('plan_index', 'read_all_plans', {})
+    t.notOk(resp.edges[0].node.meta.conflictingRevisions, 'no conflicts reported by list reads once resolved')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
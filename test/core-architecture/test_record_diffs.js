@@ -0,0 +1,64 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+  mockAddress,
+} from '../init.js'
+
+test('record revision diffs', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['planning'])
+  try {
+    const { cells: [planning] } = alice
+
+    const unitId = mockIdentifier(false)
+    const originalProvider = mockAddress(false)
+    const updatedProvider = mockAddress(false)
+
+    let resp = await planning.call('commitment', 'create_commitment', {
+      commitment: {
+        action: 'produce',
+        note: 'original note',
+        resourceClassifiedAs: ['some-resource-type'],
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+        provider: originalProvider,
+        receiver: mockAddress(false),
+      },
+    })
+    await pause(100)
+    const r1Id = resp.commitment.revisionId
+
+    resp = await planning.call('commitment', 'update_commitment', {
+      commitment: {
+        revisionId: r1Id,
+        resourceQuantity: { hasNumericalValue: 2, hasUnit: unitId },
+        provider: updatedProvider,
+      },
+    })
+    await pause(100)
+    const r2Id = resp.commitment.revisionId
+
+    resp = await planning.call('commitment', 'diff_revisions', { fromRevisionId: r1Id, toRevisionId: r2Id })
+    t.deepLooseEqual(resp.fromRevision.id, r1Id, 'diff reports source revision')
+    t.deepLooseEqual(resp.toRevision.id, r2Id, 'diff reports target revision')
+    t.deepLooseEqual(resp.changes.map(c => c.field).sort(), ['provider', 'resourceQuantity'], 'only changed response fields listed, with camelCase names')
+
+    const quantityChange = resp.changes.find(c => c.field === 'resourceQuantity')
+    t.deepLooseEqual(quantityChange.oldValue, { hasNumericalValue: 1, hasUnit: unitId }, 'QuantityValue old value decoded as a typed response value')
+    t.deepLooseEqual(quantityChange.newValue, { hasNumericalValue: 2, hasUnit: unitId }, 'QuantityValue new value decoded as a typed response value')
+
+    const providerChange = resp.changes.find(c => c.field === 'provider')
+    t.deepLooseEqual(providerChange.oldValue, originalProvider, 'DNA-scoped address old value decoded as an address')
+    t.deepLooseEqual(providerChange.newValue, updatedProvider, 'DNA-scoped address new value decoded as an address')
+
+    resp = await planning.call('commitment', 'diff_revisions', { fromRevisionId: r2Id, toRevisionId: r2Id })
+    t.equal(resp.changes.length, 0, 'diff of a revision against itself is empty')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
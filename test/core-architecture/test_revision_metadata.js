@@ -80,3 +80,58 @@ test('record previous revision metadata', async (t) => {
   }
   await alice.scenario.cleanUp()
 })
+
+test('record full revision metadata', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['agreement'])
+  try {
+    const { cells: [agreement] } = alice
+
+    let resp = await agreement.call('agreement', 'create_agreement', { agreement: exampleEntry })
+    await pause(100)
+    const recordId = resp.agreement.id
+    const r1Id = resp.agreement.revisionId
+
+    resp = await agreement.call('agreement', 'update_agreement', { agreement: { revisionId: r1Id, ...update } })
+    await pause(100)
+    const r2Id = resp.agreement.revisionId
+
+    // ASSERT: metadata after update
+    resp = await agreement.call('agreement', 'get_agreement', { address: recordId })
+    let { meta } = resp.agreement
+    t.deepLooseEqual(meta.originalRevision.id, r1Id, 'original revision read')
+    t.equal(meta.previousRevisionsCount, 1, 'previous revisions counted')
+    t.deepLooseEqual(meta.latestRevision.id, r2Id, 'latest revision read')
+    t.equal(meta.futureRevisionsCount, 0, 'no future revisions for latest revision')
+
+    resp = await agreement.call('agreement', 'get_revision', { revisionId: r1Id })
+    meta = resp.agreement.meta
+    t.deepLooseEqual(meta.originalRevision.id, r1Id, 'original revision read for earlier revision')
+    t.equal(meta.previousRevisionsCount, 0, 'no previous revisions for original revision')
+    t.deepLooseEqual(meta.latestRevision.id, r2Id, 'latest revision read for earlier revision')
+    t.equal(meta.futureRevisionsCount, 1, 'future revisions counted')
+
+    // ASSERT: metadata after delete
+    resp = await agreement.call('agreement', 'delete_agreement', { revisionId: r2Id })
+    await pause(100)
+    t.ok(resp, 'record deleted')
+
+    resp = await agreement.call('agreement', 'get_revision', { revisionId: r1Id })
+    meta = resp.agreement.meta
+    t.deepLooseEqual(meta.originalRevision.id, r1Id, 'original revision read after deletion')
+    t.equal(meta.latestRevision.revisionType, 'Delete', 'deletion read as latest revision')
+    t.equal(meta.futureRevisionsCount, 2, 'deletion counted in future revisions')
+
+    resp = await agreement.call('agreement', 'get_revision', { revisionId: r2Id })
+    meta = resp.agreement.meta
+    t.equal(meta.previousRevisionsCount, 1, 'previous revisions counted for deleted revision')
+    t.equal(meta.latestRevision.revisionType, 'Delete', 'deletion read as latest revision of deleted revision')
+    t.equal(meta.futureRevisionsCount, 1, 'deletion counted in future revisions of deleted revision')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
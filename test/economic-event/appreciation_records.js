@@ -0,0 +1,96 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+  mockAddress,
+} from '../init.js'
+
+const testEventProps = {
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+test('Appreciation record API & reciprocal event links', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    // SCENARIO: a gift is appreciated with a reciprocal gift
+    let resp = await observation.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'gift', ...testEventProps } })
+    await pause(100)
+    const giftId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'thanks', ...testEventProps } })
+    await pause(100)
+    const thanksId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'more thanks', ...testEventProps } })
+    await pause(100)
+    const moreThanksId = resp.economicEvent.id
+
+    resp = await observation.call('appreciation', 'create_appreciation', {
+      appreciation: { appreciationOf: giftId, appreciationWith: thanksId, note: 'thank you' },
+    })
+    await pause(100)
+    t.ok(resp.appreciation && resp.appreciation.id, 'appreciation created successfully')
+    const appreciation = resp.appreciation
+
+    resp = await observation.call('appreciation', 'get_appreciation', { address: appreciation.id })
+    t.deepLooseEqual(resp.appreciation.appreciationOf, giftId, 'Appreciation.appreciationOf saved')
+    t.deepLooseEqual(resp.appreciation.appreciationWith, thanksId, 'Appreciation.appreciationWith saved')
+
+    // ASSERT: reciprocal event links
+    resp = await observation.call('economic_event', 'get_economic_event', { address: giftId })
+    t.deepLooseEqual(resp.economicEvent.appreciatedBy, [appreciation.id], 'EconomicEvent.appreciatedBy reciprocal reference saved')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: thanksId })
+    t.deepLooseEqual(resp.economicEvent.appreciation, [appreciation.id], 'EconomicEvent.appreciation reciprocal reference saved')
+
+    // ASSERT: index queries
+    resp = await observation.call('appreciation_index', 'query_appreciations', { params: { appreciationOf: giftId } })
+    t.equal(resp.edges.length, 1, 'appreciations queryable by appreciated event')
+    t.deepLooseEqual(resp.edges[0].node.id, appreciation.id, 'appreciated event query returns the correct appreciation')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', { params: { appreciation: appreciation.id } })
+    t.equal(resp.edges.length, 1, 'events queryable by appreciation')
+    t.deepLooseEqual(resp.edges[0].node.id, thanksId, 'appreciation query returns the reciprocal event')
+
+    // SCENARIO: update moves the reciprocal event link
+    resp = await observation.call('appreciation', 'update_appreciation', {
+      appreciation: { revisionId: appreciation.revisionId, appreciationWith: moreThanksId },
+    })
+    await pause(100)
+    t.deepLooseEqual(resp.appreciation.appreciationWith, moreThanksId, 'appreciation updated')
+    const updatedRevisionId = resp.appreciation.revisionId
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: thanksId })
+    t.notOk(resp.economicEvent.appreciation, 'previous reciprocal event unlinked')
+    resp = await observation.call('economic_event', 'get_economic_event', { address: moreThanksId })
+    t.deepLooseEqual(resp.economicEvent.appreciation, [appreciation.id], 'new reciprocal event linked')
+
+    // SCENARIO: delete
+    resp = await observation.call('appreciation', 'delete_appreciation', { revisionId: updatedRevisionId })
+    t.ok(resp, 'appreciation deleted successfully')
+    await pause(100)
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: giftId })
+    t.notOk(resp.economicEvent.appreciatedBy, 'appreciated event unlinked after deletion')
+
+    try {
+      await observation.call('appreciation', 'get_appreciation', { address: appreciation.id })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'appreciation not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,100 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const unitId = mockIdentifier(false)
+const testEventProps = {
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['some-resource-type'],
+  hasPointInTime: '2022-01-01T00:00:00Z',
+}
+
+test('EconomicEvent corrections', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    // SCENARIO: correcting the quantity of an event affecting a resource
+    let resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'raise', resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId }, ...testEventProps },
+      newInventoriedResource: { name: 'corrected resource' },
+    })
+    await pause(100)
+    const resourceId = resp.economicResource.id
+    const originalId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: originalId,
+      event: { action: 'raise', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 7, hasUnit: unitId }, ...testEventProps },
+    })
+    await pause(100)
+    const correctionId = resp.economicEvent.id
+    t.deepLooseEqual(resp.economicEvent.corrects, originalId, 'EconomicEvent.corrects saved')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: originalId })
+    t.deepLooseEqual(resp.economicEvent.correctedBy, [correctionId], 'EconomicEvent.correctedBy reciprocal reference saved')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 7, 'corrected event reversed from resource accounting quantity')
+    t.equal(resp.economicResource.onhandQuantity.hasNumericalValue, 7, 'corrected event reversed from resource onhand quantity')
+
+    // ASSERT: index queries
+    resp = await observation.call('economic_event_index', 'query_economic_events', { params: { corrects: originalId } })
+    t.equal(resp.edges.length, 1, 'events queryable by corrected event')
+    t.deepLooseEqual(resp.edges[0].node.id, correctionId, 'corrected event query returns the correction')
+
+    resp = await observation.call('economic_event_index', 'query_economic_events', { params: { correctedBy: correctionId } })
+    t.equal(resp.edges.length, 1, 'events queryable by correction')
+    t.deepLooseEqual(resp.edges[0].node.id, originalId, 'correction query returns the corrected event')
+
+    try {
+      await observation.call('economic_event', 'correct_economic_event', {
+        corrects: originalId,
+        event: { action: 'raise', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 5, hasUnit: unitId }, ...testEventProps },
+      })
+      t.fail('correcting an event twice should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('has already been corrected'), 'events can only be corrected once')
+    }
+
+    // SCENARIO: deleting a correction restores the corrected event
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'transfer_custody', resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId }, ...testEventProps, hasPointInTime: '2022-02-01T00:00:00Z' },
+    })
+    await pause(100)
+    const custodyId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: custodyId,
+      event: { action: 'transfer_custody', resourceQuantity: { hasNumericalValue: 2, hasUnit: unitId }, ...testEventProps, hasPointInTime: '2022-02-02T00:00:00Z' },
+    })
+    await pause(100)
+    const custodyCorrection = resp.economicEvent
+
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_point_in_time', {})
+    t.notOk(resp.edges.find(e => e.node.id.toString() === custodyId.toString()), 'corrected event removed from time index')
+
+    resp = await observation.call('economic_event', 'delete_economic_event', { revisionId: custodyCorrection.revisionId })
+    t.ok(resp, 'correction deleted')
+    await pause(100)
+
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_point_in_time', {})
+    t.ok(resp.edges.find(e => e.node.id.toString() === custodyId.toString()), 'corrected event restored to time index')
+    t.notOk(resp.edges.find(e => e.node.id.toString() === custodyCorrection.id.toString()), 'deleted correction removed from time index')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: custodyId })
+    t.notOk(resp.economicEvent.correctedBy, 'corrected event unlinked from deleted correction')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,70 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const testEventProps = {
+  action: 'transfer_custody',
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+}
+
+test('EconomicEvent time indexes on domain timestamps', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    // SCENARIO: events with durations recorded out of order
+    let resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { hasBeginning: '2022-01-02T00:00:00Z', hasEnd: '2022-01-05T00:00:00Z', ...testEventProps },
+    })
+    await pause(100)
+    const laterId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { hasBeginning: '2022-01-01T00:00:00Z', hasEnd: '2022-01-10T00:00:00Z', ...testEventProps },
+    })
+    await pause(100)
+    const earlierId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { hasPointInTime: '2022-01-03T00:00:00Z', ...testEventProps },
+    })
+    await pause(100)
+
+    // ASSERT: ordering by each timestamp
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_beginning', {})
+    t.equal(resp.edges.length, 2, 'only events with a beginning indexed by beginning')
+    t.deepLooseEqual(resp.edges.map(e => e.node.id), [laterId, earlierId], 'events ordered by beginning, most recent first')
+
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_end', {})
+    t.equal(resp.edges.length, 2, 'only events with an end indexed by end')
+    t.deepLooseEqual(resp.edges.map(e => e.node.id), [earlierId, laterId], 'events ordered by end, most recent first')
+
+    // SCENARIO: a correction replaces the corrected event in time indexes
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: laterId,
+      event: { hasBeginning: '2021-12-31T00:00:00Z', hasEnd: '2022-01-04T00:00:00Z', ...testEventProps },
+    })
+    await pause(100)
+    const correctionId = resp.economicEvent.id
+
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_beginning', {})
+    t.deepLooseEqual(resp.edges.map(e => e.node.id), [earlierId, correctionId], 'corrected event replaced by correction in beginning index')
+
+    resp = await observation.call('economic_event_index', 'read_all_economic_events_by_has_end', {})
+    t.deepLooseEqual(resp.edges.map(e => e.node.id), [earlierId, correctionId], 'corrected event replaced by correction in end index')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
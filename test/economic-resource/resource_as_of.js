@@ -0,0 +1,73 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const unitId = mockIdentifier(false)
+const testEventProps = {
+  action: 'raise',
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['test-classification'],
+}
+
+test('EconomicResource point-in-time reconstruction with later corrections', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    let resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId }, hasPointInTime: '2022-01-01T00:00:00.000Z' },
+      newInventoriedResource: { name: 'test resource' },
+    })
+    await pause(100)
+    const resourceId = resp.economicResource.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 5, hasUnit: unitId }, hasPointInTime: '2022-02-01T00:00:00.000Z' },
+    })
+    await pause(100)
+    const eventId = resp.economicEvent.id
+
+    // SCENARIO: the second event is corrected after the end of the following month
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: eventId,
+      event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 3, hasUnit: unitId }, hasPointInTime: '2022-03-15T00:00:00.000Z' },
+    })
+    await pause(100)
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 13, 'current state reflects the correction')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource_as_of', { address: resourceId, asOf: '2022-01-15T00:00:00.000Z' })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 10, 'later events excluded from reconstruction')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource_as_of', { address: resourceId, asOf: '2022-03-01T00:00:00.000Z' })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 15, 'event replayed as originally recorded when its correction falls after the cutoff')
+    t.ok(resp.economicResource.reconstructedAsOf, 'response flagged as a historical reconstruction')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource_as_of', { address: resourceId, asOf: '2022-04-01T00:00:00.000Z' })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 13, 'correction replayed in place of the corrected event once within the cutoff')
+
+    // SCENARIO: reading a resource as of before it was created
+    for (const asOf of ['2021-12-31T00:00:00.000Z', '2022-01-01T00:00:00.000Z']) {
+      try {
+        await observation.call('economic_resource', 'get_economic_resource_as_of', { address: resourceId, asOf })
+        t.fail('reading a resource before its creation should fail')
+      } catch (err) {
+        t.ok(err.message.includes('No entry at this address'), `resource not found as of ${asOf}, before its creating event took effect`)
+      }
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,267 @@
+import test from 'tape'
+import { pause, dhtSync } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const unitId = mockIdentifier(false)
+const testEventProps = {
+  action: 'raise',
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceClassifiedAs: ['test-classification'],
+  hasPointInTime: '2022-01-01T00:00:00.000Z',
+}
+
+test('EconomicResource revisions are caused by events, once per event', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    let resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId } },
+      newInventoriedResource: { name: 'test resource' },
+    })
+    await pause(100)
+    const resourceId = resp.economicResource.id
+    const creationId = resp.economicEvent.id
+    t.deepLooseEqual(resp.economicEvent.resourceInventoriedAs, resourceId, 'created resource returned with the event')
+
+    resp = await observation.call('economic_event', 'get_economic_event', { address: creationId })
+    t.deepLooseEqual(resp.economicEvent.resourceInventoriedAs, resourceId, 'created resource read back via the affects index')
+
+    resp = await observation.call('economic_event', 'get_revision_history', { address: creationId })
+    t.equal(resp.totalCount, 1, 'event not rewritten to reference the created resource')
+
+    resp = await observation.call('economic_resource', 'get_revision_history', { address: resourceId })
+    t.equal(resp.totalCount, 1, 'resource created in a single revision')
+
+    // SCENARIO: correcting the event which created the resource
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: creationId,
+      event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 8, hasUnit: unitId } },
+    })
+    await pause(100)
+    const correctionId = resp.economicEvent.id
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 8, 'creating event reversed and correction applied')
+
+    resp = await observation.call('economic_resource', 'get_revision_history', { address: resourceId })
+    t.equal(resp.totalCount, 2, 'reversal and correction applied in a single revision')
+
+    try {
+      await observation.call('economic_event', 'create_economic_event', {
+        event: { ...testEventProps, corrects: correctionId, resourceQuantity: { hasNumericalValue: 8, hasUnit: unitId } },
+        newInventoriedResource: { name: 'duplicate resource' },
+      })
+      t.fail('correction creating a resource should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('cannot create a new EconomicResource in an EconomicEvent correction'), 'corrections cannot create resources')
+    }
+
+    // SCENARIO: an event cannot be applied to a resource more than once
+    try {
+      await observation.call('economic_resource', '_internal_update_inventory', {
+        event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 8, hasUnit: unitId }, recordedEvent: correctionId },
+      })
+      t.fail('reapplying an event should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('already been changed by this EconomicEvent'), 'reapplied event rejected by validation')
+    }
+
+    try {
+      await observation.call('economic_resource', '_internal_update_inventory', {
+        event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId }, recordedEvent: creationId },
+      })
+      t.fail('reapplying the creating event should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('already been changed by this EconomicEvent'), 'reapplied creating event rejected by validation')
+    }
+
+    resp = await observation.call('economic_resource', 'recalculate_economic_resource', { address: resourceId, apply: true })
+    t.ok(resp.isConsistent, 'stored quantities consistent with replayed events')
+    t.equal(resp.calculatedAccountingQuantity.hasNumericalValue, 8, 'corrected event replaced by its correction on replay')
+    t.notOk(resp.correctedRevisionId, 'no corrective revision written for a consistent resource')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
+
+test('EconomicResource quantities drifting from their events are recalculated', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+
+  const alice = await buildPlayer(['observation'])
+  try {
+    const bob = await buildPlayer(['observation'], undefined, alice.scenario)
+    await alice.scenario.shareAllAgents()
+    const { cells: [aliceObs] } = alice
+    const { cells: [bobObs] } = bob
+    const sync = () => dhtSync([alice.player, bob.player], aliceObs.cell_id[0])
+
+    let resp = await aliceObs.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId } },
+      newInventoriedResource: { name: 'test resource' },
+    })
+    const resourceId = resp.economicResource.id
+    const creationId = resp.economicEvent.id
+    await sync()
+
+    // SCENARIO: both agents apply events to the same revision, so neither fork of the
+    // resource's history reflects the other agent's event
+    const events = await Promise.all([
+      aliceObs.call('economic_event', 'create_economic_event', {
+        event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 2, hasUnit: unitId } },
+      }),
+      bobObs.call('economic_event', 'create_economic_event', {
+        event: { ...testEventProps, resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 3, hasUnit: unitId } },
+      }),
+    ])
+    await sync()
+    const eventIds = [creationId, ...events.map(e => e.economicEvent.id)]
+
+    resp = await aliceObs.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    const storedQuantity = resp.economicResource.accountingQuantity.hasNumericalValue
+    const driftedRevisionId = resp.economicResource.revisionId
+    t.ok(storedQuantity === 12 || storedQuantity === 13, 'latest revision reflects only one of the concurrent events')
+
+    resp = await aliceObs.call('economic_resource', 'get_revision_history', { address: resourceId })
+    const revisionCount = resp.totalCount
+
+    // SCENARIO: report-only recalculation
+    resp = await aliceObs.call('economic_resource', 'recalculate_economic_resource', { address: resourceId })
+    t.notOk(resp.isConsistent, 'drift from replayed events reported')
+    t.deepLooseEqual(resp.revisionId, driftedRevisionId, 'latest revision checked')
+    t.deepLooseEqual(resp.eventsReplayed.map(e => e.toString()).sort(), eventIds.map(e => e.toString()).sort(), 'all affecting events replayed')
+    t.equal(resp.storedAccountingQuantity.hasNumericalValue, storedQuantity, 'stored accounting quantity reported')
+    t.equal(resp.storedOnhandQuantity.hasNumericalValue, storedQuantity, 'stored onhand quantity reported')
+    t.equal(resp.calculatedAccountingQuantity.hasNumericalValue, 15, 'accounting quantity calculated from all events')
+    t.equal(resp.calculatedOnhandQuantity.hasNumericalValue, 15, 'onhand quantity calculated from all events')
+    t.notOk(resp.correctedRevisionId, 'no corrective revision written when reporting')
+
+    resp = await aliceObs.call('economic_resource', 'get_revision_history', { address: resourceId })
+    t.equal(resp.totalCount, revisionCount, 'report-only recalculation does not write revisions')
+    resp = await aliceObs.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, storedQuantity, 'report-only recalculation leaves quantities unchanged')
+
+    // SCENARIO: applying the missing event
+    resp = await aliceObs.call('economic_resource', 'recalculate_economic_resource', { address: resourceId, apply: true })
+    t.notOk(resp.isConsistent, 'drift reported against the revision which was corrected')
+    t.equal(resp.calculatedAccountingQuantity.hasNumericalValue, 15, 'corrected quantity reported')
+    t.ok(resp.correctedRevisionId, 'corrective revision written')
+    const correctedRevisionId = resp.correctedRevisionId
+    await sync()
+
+    resp = await aliceObs.call('economic_resource', 'get_revision', { revisionId: correctedRevisionId })
+    t.equal(resp.economicResource.accountingQuantity.hasNumericalValue, 15, 'QuantityCorrection sets the calculated accounting quantity')
+    t.equal(resp.economicResource.onhandQuantity.hasNumericalValue, 15, 'QuantityCorrection sets the calculated onhand quantity')
+    t.equal(resp.economicResource.name, 'test resource', 'QuantityCorrection leaves other fields unchanged')
+
+    resp = await bobObs.call('economic_resource', 'get_economic_resource', { address: resourceId })
+    t.deepLooseEqual(resp.economicResource.revisionId, correctedRevisionId, 'QuantityCorrection read as the latest revision')
+
+    resp = await aliceObs.call('economic_resource', 'recalculate_economic_resource', { address: resourceId, apply: true })
+    t.ok(resp.isConsistent, 'corrected resource consistent with its events')
+    t.notOk(resp.correctedRevisionId, 'consistent resources are not corrected again')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
+
+test('EconomicResource location & containment are updated by action effects', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+    const initialLocation = mockAddress(false)
+    const separatedLocation = mockAddress(false)
+
+    let resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, atLocation: initialLocation, resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId } },
+      newInventoriedResource: { name: 'container' },
+    })
+    await pause(100)
+    const containerId = resp.economicResource.id
+    t.deepLooseEqual(resp.economicResource.currentLocation, initialLocation, 'RAISE initialises the location of new resources')
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { ...testEventProps, atLocation: initialLocation, resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId } },
+      newInventoriedResource: { name: 'component' },
+    })
+    await pause(100)
+    const componentId = resp.economicResource.id
+
+    resp = await observation.call('process', 'create_process', { process: { name: 'packing' } })
+    await pause(100)
+    const packingId = resp.process.id
+
+    resp = await observation.call('process', 'create_process', { process: { name: 'unpacking' } })
+    await pause(100)
+    const unpackingId = resp.process.id
+
+    // SCENARIO: component placed in the container
+    await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        ...testEventProps,
+        action: 'combine',
+        inputOf: packingId,
+        resourceInventoriedAs: componentId,
+        toResourceInventoriedAs: containerId,
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+      },
+    })
+    await pause(100)
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: componentId })
+    t.deepLooseEqual(resp.economicResource.containedIn, containerId, 'COMBINE places the resource in the referenced container')
+    t.notOk(resp.economicResource.currentLocation, 'COMBINE removes the location of the contained resource')
+    t.equal(resp.economicResource.onhandQuantity.hasNumericalValue, 0, 'COMBINE decrements the onhand quantity of the contained resource')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: containerId })
+    t.deepLooseEqual(resp.economicResource.contains, [componentId], 'container indexed as containing the resource')
+    t.equal(resp.economicResource.onhandQuantity.hasNumericalValue, 1, 'container quantities unaffected by COMBINE')
+
+    // SCENARIO: component taken out of the container
+    await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        ...testEventProps,
+        action: 'separate',
+        outputOf: unpackingId,
+        resourceInventoriedAs: componentId,
+        atLocation: separatedLocation,
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+      },
+    })
+    await pause(100)
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: componentId })
+    t.notOk(resp.economicResource.containedIn, 'SEPARATE takes the resource out of its container')
+    t.deepLooseEqual(resp.economicResource.currentLocation, separatedLocation, 'SEPARATE updates the resource location')
+    t.equal(resp.economicResource.onhandQuantity.hasNumericalValue, 1, 'SEPARATE increments the onhand quantity of the resource')
+
+    resp = await observation.call('economic_resource', 'get_economic_resource', { address: containerId })
+    t.notOk(resp.economicResource.contains && resp.economicResource.contains.length, 'container no longer indexed as containing the resource')
+
+    resp = await observation.call('economic_resource', 'recalculate_economic_resource', { address: componentId })
+    t.ok(resp.isConsistent, 'containment events replayed consistently with stored quantities')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,128 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  mockAddress,
+  mockIdentifier,
+  buildPlayer,
+  serializeId, // :NOTE: needed due to mixing of direct API and GraphQL in same test
+} from '../init.js'
+
+const testEventProps = {
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+test('EconomicResource & EconomicEvent track and trace', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation', 'specification'])
+  try {
+    const { graphQL, cells: [observation] } = alice
+
+    // SCENARIO: raise a resource, consume it in a process and produce another resource
+    const resourceUnitId = mockIdentifier(false)
+
+    const pResp = await observation.call('process', 'create_process', { process: { name: 'test process for track & trace' } })
+    await pause(100)
+    t.ok(pResp.process && pResp.process.id, 'process created successfully')
+    const processId = pResp.process.id
+
+    const rResp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        note: 'raw material',
+        action: 'raise',
+        resourceQuantity: { hasNumericalValue: 8, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+      newInventoriedResource: { note: 'raw material resource' },
+    })
+    await pause(100)
+    t.ok(rResp.economicResource, 'input resource created successfully')
+    const raiseEventId = rResp.economicEvent.id
+    const inputResourceId = rResp.economicResource.id
+
+    const cResp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'consume',
+        inputOf: processId,
+        resourceInventoriedAs: inputResourceId,
+        resourceQuantity: { hasNumericalValue: 8, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+    })
+    await pause(100)
+    t.ok(cResp.economicEvent, 'input event created successfully')
+    const consumeEventId = cResp.economicEvent.id
+
+    const oResp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'produce',
+        outputOf: processId,
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+      newInventoriedResource: { note: 'finished good' },
+    })
+    await pause(100)
+    t.ok(oResp.economicResource, 'output resource created successfully')
+    const produceEventId = oResp.economicEvent.id
+    const outputResourceId = oResp.economicResource.id
+
+    // SCENARIO: zome API traversal
+    let traced = await observation.call('economic_resource', 'trace_economic_resource', { address: outputResourceId })
+    t.deepLooseEqual(traced.events, [produceEventId, consumeEventId, raiseEventId], 'resource trace follows flows back through the process, nearest first')
+    t.notOk(traced.hasNextPage, 'full trace fits in one page')
+
+    let tracked = await observation.call('economic_resource', 'track_economic_resource', { address: inputResourceId })
+    t.deepLooseEqual(tracked.events, [consumeEventId, produceEventId], 'resource track follows flows forward through the process, nearest first')
+
+    tracked = await observation.call('economic_event', 'track_economic_event', { address: consumeEventId })
+    t.deepLooseEqual(tracked.events, [produceEventId], 'event track continues through the process the event was input to')
+
+    traced = await observation.call('economic_event', 'trace_process', { address: processId })
+    t.deepLooseEqual(traced.events, [consumeEventId, raiseEventId], 'process trace starts from the observed inputs')
+
+    traced = await observation.call('economic_resource', 'trace_economic_resource', { address: outputResourceId, maxDepth: 1 })
+    t.deepLooseEqual(traced.events, [produceEventId], 'traversal depth is limited by maxDepth')
+
+    // SCENARIO: pagination
+    traced = await observation.call('economic_resource', 'trace_economic_resource', { address: outputResourceId, first: 1 })
+    t.deepLooseEqual(traced.events, [produceEventId], 'first page of trace OK')
+    t.ok(traced.hasNextPage, 'further pages are indicated')
+
+    traced = await observation.call('economic_resource', 'trace_economic_resource', { address: outputResourceId, first: 1, after: produceEventId })
+    t.deepLooseEqual(traced.events, [consumeEventId], 'second page of trace resumes after the cursor')
+    t.ok(traced.hasNextPage, 'further pages are indicated')
+
+    traced = await observation.call('economic_resource', 'trace_economic_resource', { address: outputResourceId, first: 1, after: consumeEventId })
+    t.deepLooseEqual(traced.events, [raiseEventId], 'last page of trace OK')
+    t.notOk(traced.hasNextPage, 'no further pages after the last')
+
+    // SCENARIO: GraphQL resolvers
+    const resp = await graphQL(`
+      query($id: ID!) {
+        economicResource(id: $id) {
+          trace {
+            __typename
+            ... on EconomicEvent {
+              id
+              action {
+                id
+              }
+            }
+          }
+        }
+      }
+    `, {
+      id: serializeId(outputResourceId),
+    })
+    t.deepLooseEqual(resp.data.economicResource.trace.map(e => e.action.id), ['produce', 'consume', 'raise'], 'GraphQL trace resolves events in order')
+    t.ok(resp.data.economicResource.trace.every(e => e.__typename === 'EconomicEvent'), 'trace items resolve as EconomicEvents')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,69 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const provider = mockAddress(false)
+const receiver = mockAddress(false)
+const eventProvider = mockAddress(false)
+const correctedProvider = mockAddress(false)
+const testFlowProps = {
+  action: 'transfer_custody',
+  receiver,
+  resourceClassifiedAs: ['resource-type-uri'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+}
+
+test('Commitment involved agents follow fulfilling events', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['planning', 'observation'])
+  try {
+    const { cells: [planning, observation] } = alice
+
+    // SCENARIO: a commitment fulfilled by an event from a different provider
+    let resp = await planning.call('commitment', 'create_commitment', { commitment: { provider, ...testFlowProps } })
+    await pause(100)
+    const commitmentId = resp.commitment.id
+    t.equal(resp.commitment.involvedAgents.length, 2, 'commitment parties are involved agents')
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { provider: eventProvider, hasPointInTime: '2019-11-19T04:29:55.056Z', ...testFlowProps },
+    })
+    await pause(100)
+    const eventId = resp.economicEvent.id
+
+    await planning.call('fulfillment', 'create_fulfillment', { fulfillment: { fulfills: commitmentId, fulfilledBy: eventId } })
+    await pause(100)
+
+    resp = await planning.call('commitment', 'get_commitment', { address: commitmentId })
+    t.equal(resp.commitment.involvedAgents.length, 3, 'fulfilling event provider added to involved agents')
+
+    resp = await planning.call('commitment_index', 'query_commitments', { params: { involvedAgents: eventProvider } })
+    t.equal(resp.edges.length, 1, 'commitment queryable by fulfilling event provider')
+
+    // SCENARIO: the fulfilling event is corrected to a different provider
+    resp = await observation.call('economic_event', 'correct_economic_event', {
+      corrects: eventId,
+      event: { provider: correctedProvider, hasPointInTime: '2019-11-19T04:29:55.056Z', ...testFlowProps },
+    })
+    await pause(100)
+
+    resp = await planning.call('commitment_index', 'query_commitments', { params: { involvedAgents: correctedProvider } })
+    t.equal(resp.edges.length, 1, 'commitment queryable by provider of the correcting event')
+
+    resp = await planning.call('commitment_index', 'query_commitments', { params: { involvedAgents: eventProvider } })
+    t.equal(resp.edges.length, 0, 'provider of the corrected event no longer involved')
+
+    resp = await planning.call('commitment', 'get_commitment', { address: commitmentId })
+    t.equal(resp.commitment.involvedAgents.length, 3, 'involved agents resynced after correction')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -113,10 +113,12 @@ const buildGraphQL = async (player, apiOptions = {}, appCellMapping) => {
 /**
  * Creates bindings for a player against a single hApp, returning a GraphQL client
  * as well as the underlying Holochain DNA `cells`.
+ *
+ * Pass the `scenario` of a previously built player to add another agent to the same network.
  */
-const buildPlayer = async (dnasToInstall, graphQLAPIOptions) => {
-  // Create an empty scenario.
-  const scenario = new Scenario({
+const buildPlayer = async (dnasToInstall, graphQLAPIOptions, existingScenario) => {
+  // Create an empty scenario, unless joining an existing one.
+  const scenario = existingScenario || new Scenario({
     timeout: 60000,
   })
   try {
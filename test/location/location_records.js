@@ -0,0 +1,97 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+} from '../init.js'
+
+// Melbourne CBD, Melbourne suburbs, and Sydney
+const cbd = { name: 'Flinders St Station', lat: -37.8183, long: 144.9671, note: 'station' }
+const suburb = { name: 'Brunswick', lat: -37.7670, long: 144.9620 }
+const interstate = { name: 'Central Station', lat: -33.8832, long: 151.2070 }
+const unmapped = { name: 'Somewhere', mappableAddress: '1 Nowhere Rd' }
+
+const melbourneBounds = { minLat: -38.0, minLong: 144.5, maxLat: -37.5, maxLong: 145.5 }
+
+const sortedIds = (resp) => resp.edges.map(e => e.node.id.toString()).sort()
+
+test('Location record API & spatial queries', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+
+    // SCENARIO: create
+    let resp = await observation.call('location', 'create_location', { location: cbd })
+    await pause(100)
+    t.ok(resp.location && resp.location.id, 'location created successfully')
+    t.equal(resp.location.lat, cbd.lat, 'latitude stored')
+    t.equal(resp.location.long, cbd.long, 'longitude stored')
+    const cbdLocation = resp.location
+
+    resp = await observation.call('location', 'create_location', { location: suburb })
+    await pause(100)
+    const suburbLocation = resp.location
+
+    resp = await observation.call('location', 'create_location', { location: interstate })
+    await pause(100)
+    const interstateLocation = resp.location
+
+    resp = await observation.call('location', 'create_location', { location: unmapped })
+    await pause(100)
+    t.ok(resp.location && resp.location.id, 'location without coordinates created successfully')
+    t.equal(resp.location.mappableAddress, unmapped.mappableAddress, 'mappable address stored')
+
+    // SCENARIO: read
+    resp = await observation.call('location', 'get_location', { address: cbdLocation.id })
+    t.equal(resp.location.name, cbd.name, 'record read OK')
+    t.equal(resp.location.note, cbd.note, 'note read OK')
+
+    // SCENARIO: spatial queries
+    resp = await observation.call('location_index', 'query_locations_within_bounds', { bounds: melbourneBounds })
+    t.deepEqual(sortedIds(resp), [cbdLocation.id, suburbLocation.id].map(id => id.toString()).sort(), 'bounding box query returns only locations within the box')
+    t.notOk(resp.pageInfo.hasNextPage, 'all results fit in one page')
+    t.equal(resp.pageInfo.totalCount, 2, 'total result count reported')
+
+    resp = await observation.call('location_index', 'query_locations_within_radius', { lat: cbd.lat, long: cbd.long, radius: 1000 })
+    t.deepEqual(sortedIds(resp), [cbdLocation.id.toString()], 'radius query excludes locations within the bounding box but outside the radius')
+
+    resp = await observation.call('location_index', 'query_locations_within_radius', { lat: cbd.lat, long: cbd.long, radius: 10000 })
+    t.deepEqual(sortedIds(resp), [cbdLocation.id, suburbLocation.id].map(id => id.toString()).sort(), 'radius query includes all nearby locations')
+
+    // SCENARIO: pagination
+    resp = await observation.call('location_index', 'query_locations_within_bounds', { bounds: melbourneBounds, first: 1 })
+    t.equal(resp.edges.length, 1, 'page size respected')
+    t.ok(resp.pageInfo.hasNextPage, 'further pages are indicated')
+    const firstId = resp.edges[0].node.id.toString()
+
+    resp = await observation.call('location_index', 'query_locations_within_bounds', { bounds: melbourneBounds, first: 1, after: resp.pageInfo.endCursor })
+    t.equal(resp.edges.length, 1, 'second page read OK')
+    t.notOk(resp.pageInfo.hasNextPage, 'no further pages after the last')
+    t.notEqual(resp.edges[0].node.id.toString(), firstId, 'second page continues after the cursor')
+
+    // SCENARIO: update moves the record between geohash cells
+    resp = await observation.call('location', 'update_location', { location: { revisionId: suburbLocation.revisionId, lat: interstate.lat, long: interstate.long } })
+    await pause(100)
+    t.equal(resp.location.lat, interstate.lat, 'location updated')
+
+    resp = await observation.call('location_index', 'query_locations_within_bounds', { bounds: melbourneBounds })
+    t.deepEqual(sortedIds(resp), [cbdLocation.id.toString()], 'updated location removed from its previous area')
+
+    resp = await observation.call('location_index', 'query_locations_within_radius', { lat: interstate.lat, long: interstate.long, radius: 1000 })
+    t.deepEqual(sortedIds(resp), [suburbLocation.id, interstateLocation.id].map(id => id.toString()).sort(), 'updated location found in its new area')
+
+    // SCENARIO: delete
+    resp = await observation.call('location', 'delete_location', { revisionId: cbdLocation.revisionId })
+    await pause(100)
+    t.equal(resp, true, 'location deleted successfully')
+
+    resp = await observation.call('location_index', 'query_locations_within_bounds', { bounds: melbourneBounds })
+    t.equal(resp.edges.length, 0, 'deleted location removed from spatial index')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
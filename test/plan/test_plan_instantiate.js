@@ -0,0 +1,86 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+  mockAddress,
+} from '../init.js'
+
+const DAY = 86400000
+const templateDue = new Date('2022-01-10T00:00:00.000Z')
+const newDue = new Date('2022-02-10T00:00:00.000Z')
+
+const testCommitmentProps = {
+  action: 'consume',
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 3, hasUnit: mockIdentifier() },
+  provider: mockAddress(),
+  receiver: mockAddress(),
+}
+
+test('Plan instantiation from a template', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation', 'planning', 'plan'])
+  try {
+    const { cells: [observation, planning, planCell] } = alice
+
+    // SCENARIO: set up template plan
+    let resp = await planCell.call('plan', 'create_plan', { plan: { name: 'template plan', due: templateDue } })
+    await pause(100)
+    const templateId = resp.plan.id
+
+    resp = await observation.call('process', 'create_process', {
+      process: { name: 'templated process', plannedWithin: templateId, hasEnd: new Date(templateDue.getTime() - DAY) },
+    })
+    await pause(100)
+    const processId = resp.process.id
+
+    resp = await planning.call('commitment', 'create_commitment', {
+      commitment: { inputOf: processId, plannedWithin: templateId, due: new Date(templateDue.getTime() - 2 * DAY), ...testCommitmentProps },
+    })
+    await pause(100)
+
+    // SCENARIO: input validation
+    for (const scaleFactor of [0, -1]) {
+      try {
+        await planCell.call('plan', 'instantiate_plan_from', { plan: templateId, scaleFactor, newDue })
+        t.fail(`scale factor ${scaleFactor} should be rejected`)
+      } catch (err) {
+        t.ok(err.message.includes('scale factor'), `scale factor ${scaleFactor} rejected`)
+      }
+    }
+
+    resp = await planCell.call('plan', 'create_plan', { plan: { name: 'undated plan' } })
+    await pause(100)
+    try {
+      await planCell.call('plan', 'instantiate_plan_from', { plan: resp.plan.id, scaleFactor: 1, newDue })
+      t.fail('plan without due date should be rejected')
+    } catch (err) {
+      t.ok(err.message.includes('no due date'), 'plan without any due date to shift from rejected')
+    }
+
+    // SCENARIO: instantiation
+    resp = await planCell.call('plan', 'instantiate_plan_from', { plan: templateId, scaleFactor: 2, newDue })
+    await pause(100)
+    t.notOk(resp.error, 'instantiation completed without error')
+    t.notDeepLooseEqual(resp.plan.id, templateId, 'new plan created')
+    t.equal(new Date(resp.plan.due).getTime(), newDue.getTime(), 'new plan due date set')
+    t.equal(resp.processes.length, 1, 'process copied')
+    t.equal(resp.commitments.length, 1, 'commitment copied')
+
+    resp = await observation.call('process', 'get_process', { address: resp.processes[0] })
+    t.equal(resp.process.name, 'templated process', 'process fields copied')
+    t.equal(new Date(resp.process.hasEnd).getTime(), newDue.getTime() - DAY, 'process dates shifted')
+    t.equal(resp.process.committedInputs.length, 1, 'copied commitment linked to copied process')
+
+    resp = await planning.call('commitment', 'get_commitment', { address: resp.process.committedInputs[0] })
+    t.equal(resp.commitment.resourceQuantity.hasNumericalValue, 6, 'commitment quantities scaled')
+    t.equal(new Date(resp.commitment.due).getTime(), newDue.getTime() - 2 * DAY, 'commitment dates shifted')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
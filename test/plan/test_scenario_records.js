@@ -0,0 +1,124 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+  serializeId,
+} from '../init.js'
+
+test('Scenario & ScenarioDefinition record API & links', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation', 'plan'])
+  try {
+    const { cells: [observation, planCell] } = alice
+
+    // SCENARIO: ScenarioDefinition CRUD
+    let resp = await planCell.call('scenario_definition', 'create_scenario_definition', {
+      scenarioDefinition: { name: 'quarterly budget', hasDuration: { hasNumericalValue: 3, hasUnit: mockIdentifier(false) } },
+    })
+    await pause(100)
+    t.ok(resp.scenarioDefinition && resp.scenarioDefinition.id, 'scenario definition created successfully')
+    const definitionId = resp.scenarioDefinition.id
+
+    resp = await planCell.call('scenario_definition', 'update_scenario_definition', {
+      scenarioDefinition: { revisionId: resp.scenarioDefinition.revisionId, note: 'budgeting exercise' },
+    })
+    await pause(100)
+    t.equal(resp.scenarioDefinition.note, 'budgeting exercise', 'scenario definition updated')
+    t.equal(resp.scenarioDefinition.name, 'quarterly budget', 'unchanged scenario definition fields preserved on update')
+
+    // SCENARIO: Scenario CRUD & refinement
+    resp = await planCell.call('scenario', 'create_scenario', {
+      scenario: { name: 'Q1 budget', definedAs: definitionId, hasBeginning: '2022-01-01T00:00:00.000Z' },
+    })
+    await pause(100)
+    t.ok(resp.scenario && resp.scenario.id, 'scenario created successfully')
+    const scenarioId = resp.scenario.id
+
+    resp = await planCell.call('scenario', 'create_scenario', {
+      scenario: { name: 'Q1 budget, optimistic', refinementOf: scenarioId },
+    })
+    await pause(100)
+    const refinement = resp.scenario
+
+    resp = await planCell.call('scenario', 'get_scenario', { address: scenarioId })
+    t.deepLooseEqual(resp.scenario.definedAs, definitionId, 'Scenario.definedAs saved')
+    t.deepLooseEqual(resp.scenario.refinements, [refinement.id], 'Scenario.refinements reciprocal reference saved')
+
+    resp = await planCell.call('scenario_definition', 'get_scenario_definition', { address: definitionId })
+    t.deepLooseEqual(resp.scenarioDefinition.definesScenario, [scenarioId], 'ScenarioDefinition.definesScenario reciprocal reference saved')
+
+    // SCENARIO: plans & processes scoped to a scenario
+    resp = await planCell.call('plan', 'create_plan', { plan: { name: 'budgeted plan', inScopeOf: scenarioId } })
+    await pause(100)
+    const planId = resp.plan.id
+
+    resp = await observation.call('process', 'create_process', { process: { name: 'budgeted process', inScopeOf: scenarioId } })
+    await pause(100)
+    const processId = resp.process.id
+
+    resp = await planCell.call('scenario', 'get_scenario', { address: scenarioId })
+    t.deepLooseEqual(resp.scenario.plans, [planId], 'Scenario.plans reciprocal reference saved')
+    t.deepLooseEqual(resp.scenario.processes, [processId], 'Scenario.processes reciprocal reference saved across DNAs')
+
+    resp = await alice.graphQL(`
+      query($planId: ID!, $processId: ID!) {
+        plan(id: $planId) {
+          inScopeOf {
+            __typename
+            ... on Scenario { id name }
+          }
+        }
+        process(id: $processId) {
+          inScopeOf {
+            __typename
+            ... on Scenario { id name }
+          }
+        }
+      }
+    `, {
+      planId: serializeId(planId),
+      processId: serializeId(processId),
+    })
+    t.equal(resp.data.plan.inScopeOf.length, 1, 'Plan.inScopeOf resolves a single scope')
+    t.equal(resp.data.plan.inScopeOf[0].__typename, 'Scenario', 'Plan.inScopeOf resolves as a Scenario')
+    t.equal(resp.data.plan.inScopeOf[0].id, serializeId(scenarioId), 'Plan.inScopeOf resolves the scoping Scenario')
+    t.equal(resp.data.process.inScopeOf.length, 1, 'Process.inScopeOf resolves a single scope')
+    t.equal(resp.data.process.inScopeOf[0].name, 'Q1 budget', 'Process.inScopeOf resolves the scoping Scenario across DNAs')
+
+    // ASSERT: index queries
+    resp = await planCell.call('scenario_index', 'query_scenarios', { params: { refinementOf: scenarioId } })
+    t.equal(resp.edges.length, 1, 'scenarios queryable by refined scenario')
+    t.deepLooseEqual(resp.edges[0].node.id, refinement.id, 'refinement query returns the correct scenario')
+
+    resp = await planCell.call('scenario_index', 'query_scenarios', { params: { definedAs: definitionId } })
+    t.equal(resp.edges.length, 1, 'scenarios queryable by definition')
+
+    resp = await planCell.call('scenario_index', 'query_scenarios', { params: { plans: planId } })
+    t.equal(resp.edges.length, 1, 'scenarios queryable by plan')
+    t.deepLooseEqual(resp.edges[0].node.id, scenarioId, 'plan query returns the correct scenario')
+
+    resp = await planCell.call('scenario_definition_index', 'query_scenario_definitions', { params: { definesScenario: scenarioId } })
+    t.equal(resp.edges.length, 1, 'scenario definitions queryable by scenario')
+
+    // SCENARIO: delete
+    resp = await planCell.call('scenario', 'delete_scenario', { revisionId: refinement.revisionId })
+    t.ok(resp, 'scenario deleted successfully')
+    await pause(100)
+
+    resp = await planCell.call('scenario', 'get_scenario', { address: scenarioId })
+    t.notOk(resp.scenario.refinements, 'deleted scenario unlinked from refined scenario')
+
+    try {
+      await planCell.call('scenario', 'get_scenario', { address: refinement.id })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'scenario not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,70 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const worker = mockAddress(false)
+const supplier = mockAddress(false)
+const testEventProps = {
+  receiver: mockAddress(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+  resourceClassifiedAs: ['resource-type-uri'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+}
+
+test('Process unplanned events & working agents', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['planning', 'observation'])
+  try {
+    const { cells: [planning, observation] } = alice
+
+    // SCENARIO: a process with one planned and one unplanned input
+    let resp = await observation.call('process', 'create_process', { process: { name: 'process with derived fields' } })
+    await pause(100)
+    const processId = resp.process.id
+
+    resp = await planning.call('commitment', 'create_commitment', {
+      commitment: { action: 'consume', inputOf: processId, provider: supplier, ...testEventProps },
+    })
+    await pause(100)
+    const commitmentId = resp.commitment.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'consume', inputOf: processId, provider: supplier, ...testEventProps },
+    })
+    await pause(100)
+    const plannedEventId = resp.economicEvent.id
+
+    resp = await planning.call('fulfillment', 'create_fulfillment', { fulfillment: { fulfills: commitmentId, fulfilledBy: plannedEventId } })
+    await pause(100)
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'work', inputOf: processId, provider: worker, ...testEventProps },
+    })
+    await pause(100)
+    const workEventId = resp.economicEvent.id
+
+    // ASSERT: derived fields are not computed on read
+    resp = await observation.call('process', 'get_process', { address: processId })
+    t.notOk(resp.process.unplannedEconomicEvents, 'unplanned events not part of the Process record')
+    t.notOk(resp.process.workingAgents, 'working agents not part of the Process record')
+
+    // ASSERT: derived fields available on request
+    resp = await observation.call('process', 'get_unplanned_economic_events', { address: processId })
+    t.deepLooseEqual(resp.unplannedInputs, [workEventId], 'only the event fulfilling no commitment is unplanned')
+    t.deepLooseEqual(resp.unplannedOutputs, [], 'no unplanned outputs')
+
+    resp = await observation.call('process', 'get_working_agents', { address: processId })
+    t.notOk(resp.errors, 'working agents read without error')
+    t.equal(resp.workingAgents.length, 3, 'working agents include work provider and commitment parties')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,112 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const testFlowProps = {
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+}
+const testEventProps = {
+  ...testFlowProps,
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+async function assertSequenced (t, observation, upstreamId, downstreamId, flowType) {
+  let resp = await observation.call('process', 'get_process', { address: upstreamId })
+  t.deepLooseEqual(resp.process.nextProcesses, [downstreamId], `${flowType} output links to the next process`)
+  resp = await observation.call('process', 'get_process', { address: downstreamId })
+  t.deepLooseEqual(resp.process.previousProcesses, [upstreamId], `${flowType} input links to the previous process`)
+
+  resp = await observation.call('process_index', 'query_processes', { params: { nextProcesses: downstreamId } })
+  t.equal(resp.edges.length, 1, `processes queryable by next process via ${flowType}s`)
+  t.deepLooseEqual(resp.edges[0].node.id, upstreamId, `next process query returns the upstream process via ${flowType}s`)
+
+  resp = await observation.call('process_index', 'query_processes', { params: { previousProcesses: upstreamId } })
+  t.equal(resp.edges.length, 1, `processes queryable by previous process via ${flowType}s`)
+  t.deepLooseEqual(resp.edges[0].node.id, downstreamId, `previous process query returns the downstream process via ${flowType}s`)
+}
+
+test('Process sequencing via resource flows', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['planning', 'observation', 'plan'])
+  try {
+    const { cells: [planning, observation, planCell] } = alice
+
+    // SCENARIO: output event of one process inventories the resource consumed by another
+    let resp = await observation.call('process', 'create_process', { process: { name: 'event producer' } })
+    await pause(100)
+    const eventUpstreamId = resp.process.id
+    resp = await observation.call('process', 'create_process', { process: { name: 'event consumer' } })
+    await pause(100)
+    const eventDownstreamId = resp.process.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'produce', outputOf: eventUpstreamId, resourceClassifiedAs: ['some-resource-type'], ...testEventProps },
+      newInventoriedResource: { name: 'intermediate product' },
+    })
+    await pause(100)
+    const resourceId = resp.economicResource.id
+
+    resp = await observation.call('process', 'get_process', { address: eventUpstreamId })
+    t.notOk(resp.process.nextProcesses, 'no next processes before the resource is used')
+
+    await observation.call('economic_event', 'create_economic_event', {
+      event: { action: 'consume', inputOf: eventDownstreamId, resourceInventoriedAs: resourceId, ...testEventProps },
+    })
+    await pause(100)
+
+    await assertSequenced(t, observation, eventUpstreamId, eventDownstreamId, 'EconomicEvent')
+
+    // SCENARIO: planned flows are matched by resource specification within a plan
+    resp = await planCell.call('plan', 'create_plan', { plan: { name: 'sequenced plan' } })
+    await pause(100)
+    const planId = resp.plan.id
+
+    const processIds = []
+    for (const name of ['commitment producer', 'commitment consumer', 'intent producer', 'intent consumer']) {
+      resp = await observation.call('process', 'create_process', { process: { name, plannedWithin: planId } })
+      await pause(100)
+      processIds.push(resp.process.id)
+    }
+    const [commitmentUpstreamId, commitmentDownstreamId, intentUpstreamId, intentDownstreamId] = processIds
+
+    const committedSpec = mockAddress(false)
+    await planning.call('commitment', 'create_commitment', {
+      commitment: { action: 'produce', outputOf: commitmentUpstreamId, resourceConformsTo: committedSpec, ...testFlowProps },
+    })
+    await pause(100)
+    await planning.call('commitment', 'create_commitment', {
+      commitment: { action: 'consume', inputOf: commitmentDownstreamId, resourceConformsTo: committedSpec, ...testFlowProps },
+    })
+    await pause(100)
+
+    await assertSequenced(t, observation, commitmentUpstreamId, commitmentDownstreamId, 'Commitment')
+
+    const intendedSpec = mockAddress(false)
+    await planning.call('intent', 'create_intent', {
+      intent: { action: 'produce', outputOf: intentUpstreamId, resourceConformsTo: intendedSpec, ...testFlowProps },
+    })
+    await pause(100)
+    await planning.call('intent', 'create_intent', {
+      intent: { action: 'consume', inputOf: intentDownstreamId, resourceConformsTo: intendedSpec, ...testFlowProps },
+    })
+    await pause(100)
+
+    await assertSequenced(t, observation, intentUpstreamId, intentDownstreamId, 'Intent')
+
+    // ASSERT: flows of differing specifications do not connect processes
+    resp = await observation.call('process', 'get_process', { address: commitmentUpstreamId })
+    t.deepLooseEqual(resp.process.nextProcesses, [commitmentDownstreamId], 'processes planned alongside are not sequenced by unrelated flows')
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -0,0 +1,133 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockAddress,
+  mockIdentifier,
+} from '../init.js'
+
+const testEventProps = {
+  provider: mockAddress(false),
+  receiver: mockAddress(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+const exampleEntry = {
+  batchNumber: 'LOT-0001',
+  productionDate: '2019-11-01T00:00:00.000Z',
+  expiryDate: '2020-11-01T00:00:00.000Z',
+}
+const updatedEntry = {
+  batchNumber: 'LOT-0001a',
+}
+
+test('ProductBatch record API & lot recall', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['observation'])
+  try {
+    const { cells: [observation] } = alice
+    const resourceUnitId = mockIdentifier(false)
+
+    // SCENARIO: CRUD
+    let resp = await observation.call('product_batch', 'create_product_batch', { productBatch: exampleEntry })
+    await pause(100)
+    t.ok(resp.productBatch && resp.productBatch.id, 'batch created successfully')
+    const batchId = resp.productBatch.id
+    const batchRevisionId = resp.productBatch.revisionId
+
+    resp = await observation.call('product_batch', 'get_product_batch', { address: batchId })
+    t.equal(resp.productBatch.batchNumber, exampleEntry.batchNumber, 'batch number read OK')
+    t.equal(resp.productBatch.productionDate, exampleEntry.productionDate, 'production date read OK')
+    t.equal(resp.productBatch.expiryDate, exampleEntry.expiryDate, 'expiry date read OK')
+
+    resp = await observation.call('product_batch', 'update_product_batch', { productBatch: { revisionId: batchRevisionId, ...updatedEntry } })
+    await pause(100)
+    t.equal(resp.productBatch.batchNumber, updatedEntry.batchNumber, 'batch updated')
+    t.equal(resp.productBatch.expiryDate, exampleEntry.expiryDate, 'unchanged fields preserved on update')
+
+    // SCENARIO: resources in lot are indexed
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'raise',
+        resourceQuantity: { hasNumericalValue: 10, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+      newInventoriedResource: { note: 'resource in batch', lot: batchId },
+    })
+    await pause(100)
+    t.ok(resp.economicResource, 'resource in lot created successfully')
+    const lotResourceId = resp.economicResource.id
+
+    resp = await observation.call('product_batch', 'get_product_batch', { address: batchId })
+    t.deepLooseEqual(resp.productBatch.economicResources, [lotResourceId], 'lot resources listed on batch')
+
+    resp = await observation.call('economic_resource_index', 'query_economic_resources', { params: { lot: batchId } })
+    t.equal(resp.edges.length, 1, 'resources queryable by lot')
+    t.deepLooseEqual(resp.edges[0].node.id, lotResourceId, 'lot query returns the resource in the batch')
+
+    resp = await observation.call('product_batch_index', 'query_product_batches', { params: { economicResources: lotResourceId } })
+    t.equal(resp.edges.length, 1, 'batches queryable by resource')
+    t.deepLooseEqual(resp.edges[0].node.id, batchId, 'resource query returns the batch')
+
+    // SCENARIO: recall follows resources derived from the lot
+    resp = await observation.call('process', 'create_process', { process: { name: 'packing' } })
+    await pause(100)
+    const processId = resp.process.id
+
+    await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'consume',
+        inputOf: processId,
+        resourceInventoriedAs: lotResourceId,
+        resourceQuantity: { hasNumericalValue: 10, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+    })
+    await pause(100)
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'produce',
+        outputOf: processId,
+        resourceQuantity: { hasNumericalValue: 2, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+      newInventoriedResource: { note: 'packed goods' },
+    })
+    await pause(100)
+    const derivedResourceId = resp.economicResource.id
+
+    resp = await observation.call('economic_event', 'create_economic_event', {
+      event: {
+        action: 'raise',
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: resourceUnitId },
+        ...testEventProps,
+      },
+      newInventoriedResource: { note: 'unrelated resource' },
+    })
+    await pause(100)
+
+    resp = await observation.call('product_batch', 'recall_product_batch', { address: batchId })
+    t.deepLooseEqual(resp.economicResources, [lotResourceId, derivedResourceId], 'recall returns resources in the lot and all resources derived from them')
+
+    resp = await observation.call('product_batch', 'recall_product_batch', { address: batchId, maxDepth: 1 })
+    t.deepLooseEqual(resp.economicResources, [lotResourceId], 'recall depth is limited by maxDepth')
+
+    // SCENARIO: delete
+    resp = await observation.call('product_batch', 'get_product_batch', { address: batchId })
+    resp = await observation.call('product_batch', 'delete_product_batch', { revisionId: resp.productBatch.revisionId })
+    t.ok(resp, 'batch deleted successfully')
+    await pause(100)
+
+    try {
+      await observation.call('product_batch', 'get_product_batch', { address: batchId })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'batch not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
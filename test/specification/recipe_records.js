@@ -0,0 +1,131 @@
+import test from 'tape'
+import { pause } from '@holochain/tryorama'
+import {
+  buildPlayer,
+  mockIdentifier,
+} from '../init.js'
+
+test('Recipe record API & links', async (t) => {
+  // display the filename for context in the terminal and use .warn
+  // to override the tap testing log filters
+  console.warn(`\n\n${import.meta.url}`)
+  const alice = await buildPlayer(['specification'])
+  try {
+    const { cells: [specification] } = alice
+    const unitId = mockIdentifier(false)
+
+    // SCENARIO: RecipeResource CRUD
+    let resp = await specification.call('recipe_resource', 'create_recipe_resource', {
+      recipeResource: { name: 'flour', unitOfResource: unitId, substitutable: true },
+    })
+    await pause(100)
+    t.ok(resp.recipeResource && resp.recipeResource.id, 'recipe resource created successfully')
+    const flourId = resp.recipeResource.id
+
+    resp = await specification.call('recipe_resource', 'create_recipe_resource', { recipeResource: { name: 'bread' } })
+    await pause(100)
+    const breadId = resp.recipeResource.id
+
+    resp = await specification.call('recipe_resource', 'get_recipe_resource', { address: flourId })
+    t.equal(resp.recipeResource.name, 'flour', 'recipe resource read OK')
+    t.equal(resp.recipeResource.substitutable, true, 'substitutable flag read OK')
+
+    resp = await specification.call('recipe_resource', 'update_recipe_resource', {
+      recipeResource: { revisionId: resp.recipeResource.revisionId, note: 'wholemeal' },
+    })
+    await pause(100)
+    t.equal(resp.recipeResource.note, 'wholemeal', 'recipe resource updated')
+    t.equal(resp.recipeResource.name, 'flour', 'unchanged recipe resource fields preserved on update')
+
+    // SCENARIO: RecipeProcess & RecipeExchange CRUD
+    resp = await specification.call('recipe_process', 'create_recipe_process', {
+      recipeProcess: { name: 'baking', hasDuration: { hasNumericalValue: 45, hasUnit: unitId } },
+    })
+    await pause(100)
+    t.ok(resp.recipeProcess && resp.recipeProcess.id, 'recipe process created successfully')
+    const processId = resp.recipeProcess.id
+
+    resp = await specification.call('recipe_process', 'update_recipe_process', {
+      recipeProcess: { revisionId: resp.recipeProcess.revisionId, name: 'baking bread' },
+    })
+    await pause(100)
+    t.equal(resp.recipeProcess.name, 'baking bread', 'recipe process updated')
+
+    resp = await specification.call('recipe_exchange', 'create_recipe_exchange', { recipeExchange: { name: 'sale of bread' } })
+    await pause(100)
+    t.ok(resp.recipeExchange && resp.recipeExchange.id, 'recipe exchange created successfully')
+    const exchangeId = resp.recipeExchange.id
+
+    // SCENARIO: RecipeFlows link resources, processes & exchanges
+    resp = await specification.call('recipe_flow', 'create_recipe_flow', {
+      recipeFlow: {
+        action: 'consume',
+        recipeFlowResource: flourId,
+        recipeInputOf: processId,
+        resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+      },
+    })
+    await pause(100)
+    t.ok(resp.recipeFlow && resp.recipeFlow.id, 'input recipe flow created successfully')
+    const inputFlow = resp.recipeFlow
+
+    resp = await specification.call('recipe_flow', 'create_recipe_flow', {
+      recipeFlow: { action: 'produce', recipeFlowResource: breadId, recipeOutputOf: processId },
+    })
+    await pause(100)
+    const outputFlowId = resp.recipeFlow.id
+
+    resp = await specification.call('recipe_flow', 'create_recipe_flow', {
+      recipeFlow: { action: 'transfer', recipeFlowResource: breadId, recipeClauseOf: exchangeId },
+    })
+    await pause(100)
+    const clauseFlowId = resp.recipeFlow.id
+
+    resp = await specification.call('recipe_process', 'get_recipe_process', { address: processId })
+    t.deepLooseEqual(resp.recipeProcess.recipeInputs, [inputFlow.id], 'process inputs linked')
+    t.deepLooseEqual(resp.recipeProcess.recipeOutputs, [outputFlowId], 'process outputs linked')
+
+    resp = await specification.call('recipe_exchange', 'get_recipe_exchange', { address: exchangeId })
+    t.deepLooseEqual(resp.recipeExchange.recipeClauses, [clauseFlowId], 'exchange clauses linked')
+
+    resp = await specification.call('recipe_resource', 'get_recipe_resource', { address: breadId })
+    t.equal(resp.recipeResource.recipeFlows.length, 2, 'resource flows linked')
+
+    // SCENARIO: index queries
+    resp = await specification.call('recipe_flow_index', 'query_recipe_flows', { params: { recipeInputOf: processId } })
+    t.equal(resp.edges.length, 1, 'recipe flows queryable by process input')
+    t.deepLooseEqual(resp.edges[0].node.id, inputFlow.id, 'input query returns the correct flow')
+
+    resp = await specification.call('recipe_flow_index', 'query_recipe_flows', { params: { recipeFlowResource: breadId } })
+    t.equal(resp.edges.length, 2, 'recipe flows queryable by resource')
+
+    resp = await specification.call('recipe_process_index', 'query_recipe_processes', { params: { recipeOutputs: outputFlowId } })
+    t.equal(resp.edges.length, 1, 'recipe processes queryable by output flow')
+    t.deepLooseEqual(resp.edges[0].node.id, processId, 'output query returns the correct process')
+
+    resp = await specification.call('recipe_exchange_index', 'query_recipe_exchanges', { params: { recipeClauses: clauseFlowId } })
+    t.equal(resp.edges.length, 1, 'recipe exchanges queryable by clause')
+
+    resp = await specification.call('recipe_resource_index', 'query_recipe_resources', { params: { recipeFlows: inputFlow.id } })
+    t.equal(resp.edges.length, 1, 'recipe resources queryable by flow')
+    t.deepLooseEqual(resp.edges[0].node.id, flourId, 'flow query returns the correct resource')
+
+    // SCENARIO: delete
+    resp = await specification.call('recipe_flow', 'delete_recipe_flow', { revisionId: inputFlow.revisionId })
+    t.ok(resp, 'recipe flow deleted successfully')
+    await pause(100)
+
+    resp = await specification.call('recipe_process', 'get_recipe_process', { address: processId })
+    t.notOk(resp.recipeProcess.recipeInputs, 'deleted flow unlinked from process')
+
+    try {
+      await specification.call('recipe_flow', 'get_recipe_flow', { address: inputFlow.id })
+    } catch (err) {
+      t.ok(err.message.includes('No entry at this address'), 'recipe flow not retrievable once deleted')
+    }
+  } catch (e) {
+    await alice.scenario.cleanUp()
+    throw e
+  }
+  await alice.scenario.cleanUp()
+})
@@ -18,7 +18,7 @@ test('Built-in action API', async (t) => {
       }
     `, {})
 
-    t.equal(queryAllResp.data.actions.length, 18, 'all action builtins present')
+    t.equal(queryAllResp.data.actions.length, 20, 'all action builtins present')
 
     const getResp = await alice.graphQL(`
       query($id: ID!) {
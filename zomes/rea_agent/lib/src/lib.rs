@@ -14,10 +14,12 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
     SignedActionHashed,
     DataIntegrityError,
     DnaAddressable,
@@ -92,7 +94,9 @@ pub fn handle_whois_query(agent_pubkey: AgentPubKey) -> RecordAPIResult<Response
 pub fn handle_get_agent(address: AgentAddress) -> RecordAPIResult<ResponseData>
 {
     let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)?;
+    response.agent.meta = read_revision_metadata_full(&revision)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
@@ -101,20 +105,44 @@ pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseD
     construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
 }
 
+pub fn handle_get_revision_history(page: RevisionHistoryParams<AgentAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, AgentAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.agent)
+    })
+}
+
 pub fn handle_update_agent(agent: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agent(None, agent)
+}
+
+pub fn handle_resolve_agent_conflict(conflicting_revisions: Vec<ActionHash>, agent: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agent(Some(&conflicting_revisions), agent)
+}
+
+fn update_or_resolve_agent(conflicting_revisions: Option<&[ActionHash]>, agent: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let revision_hash = agent.get_revision_id().clone();
-    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&revision_hash, agent)?;
+    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, agent)?;
     construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
 }
 
-pub fn handle_delete_agent(revision_id: ActionHash) -> RecordAPIResult<bool> {
+pub fn handle_delete_agent<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
 
     // load the record to ensure it is of the correct type
     let (_revision, _base_address, _entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
     // This is where indexes would be updated if necessary
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 /// Create response from input DHT primitives
@@ -134,6 +162,8 @@ fn construct_response<'a>(
         economic_events_as_provider,
         economic_events_as_receiver,
         inventoried_economic_resources,
+        relationships_as_subject,
+        relationships_as_object,
     ): (
         // Vec<CommitmentAddress>,
         // Vec<EconomicEventAddress>,
@@ -149,6 +179,8 @@ fn construct_response<'a>(
         Vec<EconomicEventAddress>,
         Vec<EconomicEventAddress>,
         Vec<EconomicResourceAddress>,
+        Vec<AgentRelationshipAddress>,
+        Vec<AgentRelationshipAddress>,
     ),
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
@@ -175,6 +207,8 @@ fn construct_response<'a>(
             economic_events_as_provider: economic_events_as_provider.to_owned(),
             economic_events_as_receiver: economic_events_as_receiver.to_owned(),
             inventoried_economic_resources: inventoried_economic_resources.to_owned(),
+            relationships_as_subject: relationships_as_subject.to_owned(),
+            relationships_as_object: relationships_as_object.to_owned(),
         }
     })
 }
@@ -202,6 +236,8 @@ fn get_link_fields(base_address: &AgentAddress) -> RecordAPIResult<(
     Vec<EconomicEventAddress>,
     Vec<EconomicEventAddress>,
     Vec<EconomicResourceAddress>,
+    Vec<AgentRelationshipAddress>,
+    Vec<AgentRelationshipAddress>,
 )> {
     Ok((
         // read_index!(agent(base_address).commitments)?,
@@ -218,5 +254,7 @@ fn get_link_fields(base_address: &AgentAddress) -> RecordAPIResult<(
         read_index!(agent(base_address).economic_events_as_provider)?,
         read_index!(agent(base_address).economic_events_as_receiver)?,
         read_index!(agent(base_address).inventoried_economic_resources)?,
+        read_index!(agent(base_address).relationships_as_subject)?,
+        read_index!(agent(base_address).relationships_as_object)?,
     ))
 }
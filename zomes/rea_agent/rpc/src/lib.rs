@@ -11,6 +11,7 @@ use hdk_uuid_types::{ DnaHash, addressable_identifier };
 pub use hdk::prelude::*;
 pub use vf_attributes_hdk::{
     AgentAddress,
+    AgentRelationshipAddress,
     ProcessAddress,
     EconomicEventAddress,
     CommitmentAddress,
@@ -21,6 +22,8 @@ pub use vf_attributes_hdk::{
     PlanAddress,
     ProposalAddress,
     ByRevision, RecordMeta, RevisionMeta, ByAddress,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
 };
 
 // internal type for indexing against agent_type string
@@ -65,6 +68,12 @@ pub struct Response {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub inventoried_economic_resources: Vec<EconomicResourceAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub relationships_as_subject: Vec<AgentRelationshipAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub relationships_as_object: Vec<AgentRelationshipAddress>,
 }
 
 /// I/O struct to describe what is returned outside the gateway.
@@ -152,6 +161,8 @@ pub struct QueryParams {
     pub economic_events_as_provider: Option<EconomicEventAddress>,
     pub economic_events_as_receiver: Option<EconomicEventAddress>,
     pub inventoried_economic_resources: Option<EconomicResourceAddress>,
+    pub relationships_as_subject: Option<AgentRelationshipAddress>,
+    pub relationships_as_object: Option<AgentRelationshipAddress>,
 
     // for internal use in order to query for people or organizations specifically
     pub agent_type: Option<String>,
@@ -52,6 +52,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<AgentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct WhoisParams {
@@ -74,7 +84,19 @@ fn update_agent(UpdateParams { agent }: UpdateParams) -> ExternResult<ResponseDa
     Ok(handle_update_agent(agent)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub agent: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_agent_conflict(ResolveConflictParams { conflicting_revisions, agent }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_agent_conflict(conflicting_revisions, agent)?)
+}
+
 #[hdk_extern]
 fn delete_agent(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_agent(revision_id)?)
+    Ok(handle_delete_agent(AGENT_ENTRY_TYPE, revision_id)?)
 }
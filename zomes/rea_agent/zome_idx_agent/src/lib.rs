@@ -24,6 +24,8 @@ struct Agent {
     economic_events_as_provider: Remote<economic_event, provider>,
     economic_events_as_receiver: Remote<economic_event, receiver>,
     inventoried_economic_resources: Remote<economic_resource, primary_accountable>,
+    relationships_as_subject: Local<agent_relationship, subject>,
+    relationships_as_object: Local<agent_relationship, object>,
 
     // query agents by type
     agent_type: Local<agent, agent_type_internal>::String,
@@ -0,0 +1,217 @@
+/**
+ * hREA agent relationship zome library API
+ *
+ * Contains helper methods that can be used to manipulate `AgentRelationship` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_agent_relationship_storage::*;
+use hc_zome_rea_agent_relationship_rpc::*;
+
+pub use hc_zome_rea_agent_relationship_storage::AGENT_RELATIONSHIP_ENTRY_TYPE;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.agent_relationship.index_zome)
+}
+
+pub fn handle_create_agent_relationship<S>(entry_def_id: S, agent_relationship: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, agent_relationship)?;
+
+    // handle link fields
+    let e = create_index!(agent_relationship.subject(&entry_resp.subject), agent.relationships_as_subject(&base_address));
+    hdk::prelude::debug!("handle_create_agent_relationship::subject index {:?}", e);
+    let e = create_index!(agent_relationship.object(&entry_resp.object), agent.relationships_as_object(&base_address));
+    hdk::prelude::debug!("handle_create_agent_relationship::object index {:?}", e);
+    let e = create_index!(agent_relationship.relationship(&entry_resp.relationship), agent_relationship_role.agent_relationships(&base_address));
+    hdk::prelude::debug!("handle_create_agent_relationship::relationship index {:?}", e);
+
+    construct_response(&base_address, &meta, &entry_resp)
+}
+
+pub fn handle_get_agent_relationship(address: AgentRelationshipAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.agent_relationship.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.agent_relationship.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<AgentRelationshipAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, AgentRelationshipAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry)?.agent_relationship)
+    })
+}
+
+pub fn handle_update_agent_relationship(agent_relationship: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agent_relationship(None, agent_relationship)
+}
+
+pub fn handle_resolve_agent_relationship_conflict(conflicting_revisions: Vec<ActionHash>, agent_relationship: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agent_relationship(Some(&conflicting_revisions), agent_relationship)
+}
+
+fn update_or_resolve_agent_relationship(conflicting_revisions: Option<&[ActionHash]>, agent_relationship: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let revision_hash = agent_relationship.get_revision_id().clone();
+    let (meta, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, agent_relationship)?;
+
+    if entry.subject != prev_entry.subject {
+        let e = update_index!(
+            agent_relationship
+                .subject(&vec![entry.subject.to_owned()])
+                .not(&vec![prev_entry.subject]),
+            agent.relationships_as_subject(&identity_address)
+        );
+        hdk::prelude::debug!("handle_update_agent_relationship::subject index {:?}", e);
+    }
+    if entry.object != prev_entry.object {
+        let e = update_index!(
+            agent_relationship
+                .object(&vec![entry.object.to_owned()])
+                .not(&vec![prev_entry.object]),
+            agent.relationships_as_object(&identity_address)
+        );
+        hdk::prelude::debug!("handle_update_agent_relationship::object index {:?}", e);
+    }
+    if entry.relationship != prev_entry.relationship {
+        let e = update_index!(
+            agent_relationship
+                .relationship(&vec![entry.relationship.to_owned()])
+                .not(&vec![prev_entry.relationship]),
+            agent_relationship_role.agent_relationships(&identity_address)
+        );
+        hdk::prelude::debug!("handle_update_agent_relationship::relationship index {:?}", e);
+    }
+
+    construct_response(&identity_address, &meta, &entry)
+}
+
+pub fn handle_delete_agent_relationship<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // read any referencing indexes
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    let e = update_index!(agent_relationship.subject.not(&vec![entry.subject]), agent.relationships_as_subject(&base_address));
+    hdk::prelude::debug!("handle_delete_agent_relationship::subject index {:?}", e);
+    let e = update_index!(agent_relationship.object.not(&vec![entry.object]), agent.relationships_as_object(&base_address));
+    hdk::prelude::debug!("handle_delete_agent_relationship::object index {:?}", e);
+    let e = update_index!(agent_relationship.relationship.not(&vec![entry.relationship]), agent_relationship_role.agent_relationships(&base_address));
+    hdk::prelude::debug!("handle_delete_agent_relationship::relationship index {:?}", e);
+
+    // delete entry last as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Determine the agents which are members of (ie. the subjects of relationships with) `agent`,
+/// optionally only including those related via the given `role`.
+///
+pub fn handle_get_members_of(agent: AgentAddress, role: Option<AgentRelationshipRoleAddress>) -> RecordAPIResult<Vec<AgentAddress>>
+{
+    let relationships: Vec<AgentRelationshipAddress> = read_index!(agent(&agent).relationships_as_object)?;
+    collect_related_agents(relationships, role, |entry| entry.subject)
+}
+
+/// Determine the agents which `agent` is a member of (ie. the objects of relationships where it
+/// is the subject), optionally only including those related via the given `role`.
+///
+pub fn handle_get_memberships_of(agent: AgentAddress, role: Option<AgentRelationshipRoleAddress>) -> RecordAPIResult<Vec<AgentAddress>>
+{
+    let relationships: Vec<AgentRelationshipAddress> = read_index!(agent(&agent).relationships_as_subject)?;
+    collect_related_agents(relationships, role, |entry| entry.object)
+}
+
+fn collect_related_agents<F>(
+    relationships: Vec<AgentRelationshipAddress>,
+    role: Option<AgentRelationshipRoleAddress>,
+    related_agent: F,
+) -> RecordAPIResult<Vec<AgentAddress>>
+    where F: Fn(EntryData) -> AgentAddress,
+{
+    let mut agents: Vec<AgentAddress> = vec![];
+    for relationship in relationships {
+        let (_meta, _base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(relationship.as_ref())?;
+        if let Some(role) = &role {
+            if &entry.relationship != role {
+                continue;
+            }
+        }
+        let agent = related_agent(entry);
+        if !agents.contains(&agent) {
+            agents.push(agent);
+        }
+    }
+    Ok(agents)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &AgentRelationshipAddress, meta: &SignedActionHashed, e: &EntryData,
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        agent_relationship: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            subject: e.subject.to_owned(),
+            object: e.object.to_owned(),
+            relationship: e.relationship.to_owned(),
+            in_scope_of: e.in_scope_of.to_owned(),
+            note: e.note.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_agent_relationship_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.agent_relationship.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_agent_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.agent_relationship.agent_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_agent_relationship_role_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.agent_relationship.agent_relationship_role_index_zome
+}
@@ -0,0 +1,125 @@
+/**
+ * hREA agent relationship zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    AgentRelationshipAddress,
+    AgentRelationshipRoleAddress,
+    AgentAddress,
+    ActionHash,
+    ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: AgentRelationshipAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub subject: AgentAddress,
+    pub object: AgentAddress,
+    pub relationship: AgentRelationshipRoleAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_scope_of: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub agent_relationship: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub subject: AgentAddress,
+    pub object: AgentAddress,
+    pub relationship: AgentRelationshipRoleAddress,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub subject: MaybeUndefined<AgentAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub object: MaybeUndefined<AgentAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub relationship: MaybeUndefined<AgentRelationshipRoleAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&self) -> ActionHash {
+        self.revision_id.to_owned().into()
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub subject: Option<AgentAddress>,
+    pub object: Option<AgentAddress>,
+    pub relationship: Option<AgentRelationshipRoleAddress>,
+}
+
+/// Parameters for locating the agents related to `agent`, optionally only via relationships
+/// of the given `role`
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedAgentsParams {
+    pub agent: AgentAddress,
+    #[serde(default)]
+    pub role: Option<AgentRelationshipRoleAddress>,
+}
@@ -0,0 +1,134 @@
+/**
+ * hREA agent relationship zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    generate_record_entry,
+    record_interface::{Updateable},
+};
+
+use vf_attributes_hdk::{
+    AgentAddress,
+    AgentRelationshipRoleAddress,
+};
+
+use hc_zome_rea_agent_relationship_rpc::{ CreateRequest, UpdateRequest };
+
+pub use vf_attributes_hdk::AgentRelationshipAddress;
+pub use hc_zome_rea_agent_relationship_storage_consts::AGENT_RELATIONSHIP_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub agent_relationship: AgentRelationshipZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct AgentRelationshipZomeConfig {
+    pub index_zome: String,
+    pub agent_index_zome: Option<String>,
+    pub agent_relationship_role_index_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct EntryData {
+    pub subject: AgentAddress,
+    pub object: AgentAddress,
+    pub relationship: AgentRelationshipRoleAddress,
+    pub in_scope_of: Option<Vec<String>>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.subject == self.object {
+            return Err("AgentRelationship cannot relate an agent to itself".into());
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, AgentRelationshipAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    AgentRelationship(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::AgentRelationship(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            subject: e.subject.into(),
+            object: e.object.into(),
+            relationship: e.relationship.into(),
+            in_scope_of: e.in_scope_of.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            subject: if !e.subject.is_some() { self.subject.to_owned() } else { e.subject.to_owned().unwrap() },
+            object: if !e.object.is_some() { self.object.to_owned() } else { e.object.to_owned().unwrap() },
+            relationship: if !e.relationship.is_some() { self.relationship.to_owned() } else { e.relationship.to_owned().unwrap() },
+            in_scope_of: if e.in_scope_of.is_undefined() { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,14 @@
+/**
+ * Agent relationship query indexes for agent DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_agent_relationship_rpc::*;
+
+#[index_zome]
+struct AgentRelationship {
+    subject: Local<agent, relationships_as_subject>,
+    object: Local<agent, relationships_as_object>,
+    relationship: Local<agent_relationship_role, agent_relationships>,
+}
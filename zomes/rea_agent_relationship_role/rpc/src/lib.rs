@@ -0,0 +1,113 @@
+/**
+ * hREA agent relationship role zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    AgentRelationshipRoleAddress,
+    AgentRelationshipAddress,
+    ExternalURL,
+    ActionHash,
+    ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: AgentRelationshipRoleAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub role_label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inverse_role_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_behavior: Option<ExternalURL>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub agent_relationships: Vec<AgentRelationshipAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub agent_relationship_role: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub role_label: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub inverse_role_label: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub role_behavior: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub role_label: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub inverse_role_label: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub role_behavior: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&self) -> ActionHash {
+        self.revision_id.to_owned().into()
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub agent_relationships: Option<AgentRelationshipAddress>,
+}
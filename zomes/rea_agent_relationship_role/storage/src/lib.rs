@@ -0,0 +1,128 @@
+/**
+ * hREA agent relationship role zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    generate_record_entry,
+    record_interface::{Updateable},
+};
+
+use vf_attributes_hdk::{
+    ExternalURL,
+};
+
+use hc_zome_rea_agent_relationship_role_rpc::{ CreateRequest, UpdateRequest };
+
+pub use vf_attributes_hdk::AgentRelationshipRoleAddress;
+pub use hc_zome_rea_agent_relationship_role_storage_consts::AGENT_RELATIONSHIP_ROLE_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub agent_relationship_role: AgentRelationshipRoleZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct AgentRelationshipRoleZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct EntryData {
+    pub role_label: String,
+    pub inverse_role_label: Option<String>,
+    pub role_behavior: Option<ExternalURL>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.role_label.trim().is_empty() {
+            return Err("AgentRelationshipRole must have a role label".into());
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, AgentRelationshipRoleAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    AgentRelationshipRole(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::AgentRelationshipRole(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            role_label: e.role_label.into(),
+            inverse_role_label: e.inverse_role_label.into(),
+            role_behavior: e.role_behavior.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            role_label: if !e.role_label.is_some() { self.role_label.to_owned() } else { e.role_label.to_owned().unwrap() },
+            inverse_role_label: if e.inverse_role_label.is_undefined() { self.inverse_role_label.to_owned() } else { e.inverse_role_label.to_owned().into() },
+            role_behavior: if e.role_behavior.is_undefined() { self.role_behavior.to_owned() } else { e.role_behavior.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,77 @@
+/**
+ * hREA agent_relationship_role zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_agent_relationship_role_rpc::*;
+use hc_zome_rea_agent_relationship_role_lib::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateParams {
+    pub agent_relationship_role: CreateRequest,
+}
+
+#[hdk_extern]
+fn create_agent_relationship_role(CreateParams { agent_relationship_role }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_agent_relationship_role(AGENT_RELATIONSHIP_ROLE_ENTRY_TYPE, agent_relationship_role)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadParams {
+    pub address: AgentRelationshipRoleAddress,
+}
+
+#[hdk_extern]
+fn get_agent_relationship_role(ReadParams { address }: ReadParams) -> ExternResult<ResponseData> {
+    Ok(handle_get_agent_relationship_role(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<AgentRelationshipRoleAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateParams {
+    pub agent_relationship_role: UpdateRequest,
+}
+
+#[hdk_extern]
+fn update_agent_relationship_role(UpdateParams { agent_relationship_role }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_agent_relationship_role(agent_relationship_role)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub agent_relationship_role: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_agent_relationship_role_conflict(ResolveConflictParams { conflicting_revisions, agent_relationship_role }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_agent_relationship_role_conflict(conflicting_revisions, agent_relationship_role)?)
+}
+
+#[hdk_extern]
+fn delete_agent_relationship_role(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_agent_relationship_role(AGENT_RELATIONSHIP_ROLE_ENTRY_TYPE, revision_id)?)
+}
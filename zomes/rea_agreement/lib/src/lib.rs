@@ -13,10 +13,12 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -41,24 +43,52 @@ pub fn handle_create_agreement<S>(entry_def_id: S, agreement: CreateRequest) ->
 pub fn handle_get_agreement(address: AgreementAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.agreement.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.agreement.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<AgreementAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, AgreementAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.agreement)
+    })
 }
 
 pub fn handle_update_agreement(agreement: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agreement(None, agreement)
+}
+
+pub fn handle_resolve_agreement_conflict(conflicting_revisions: Vec<ActionHash>, agreement: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_agreement(Some(&conflicting_revisions), agreement)
+}
+
+fn update_or_resolve_agreement(conflicting_revisions: Option<&[ActionHash]>, agreement: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let revision_hash = agreement.get_revision_id().clone();
-    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&revision_hash, agreement)?;
+    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, agreement)?;
     construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
 }
 
-pub fn handle_delete_agreement(address: ActionHash) -> RecordAPIResult<bool> {
-    delete_record::<EntryStorage>(&address)
+pub fn handle_delete_agreement<S>(entry_def_id: S, address: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &address)
 }
 
 /// Create response from input DHT primitives
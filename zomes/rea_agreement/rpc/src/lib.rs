@@ -17,6 +17,8 @@ pub use vf_attributes_hdk::{
     FixedOffset,
     ByAction, ActionHash,
     ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     AgentAddress,
 };
 
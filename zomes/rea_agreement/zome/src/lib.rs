@@ -38,6 +38,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<AgreementAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -49,7 +59,19 @@ fn update_agreement(UpdateParams { agreement }: UpdateParams) -> ExternResult<Re
     Ok(handle_update_agreement(agreement)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub agreement: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_agreement_conflict(ResolveConflictParams { conflicting_revisions, agreement }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_agreement_conflict(conflicting_revisions, agreement)?)
+}
+
 #[hdk_extern]
 fn delete_agreement(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_agreement(revision_id)?)
+    Ok(handle_delete_agreement(AGREEMENT_ENTRY_TYPE, revision_id)?)
 }
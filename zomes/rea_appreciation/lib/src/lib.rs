@@ -0,0 +1,151 @@
+/**
+ * hREA appreciation zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Appreciation` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_appreciation_storage::*;
+use hc_zome_rea_appreciation_rpc::*;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.appreciation.index_zome)
+}
+
+pub fn handle_create_appreciation<S>(entry_def_id: S, appreciation: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, appreciation.to_owned())?;
+
+    // handle link fields
+    let e1 = create_index!(appreciation.appreciation_of(appreciation.get_appreciation_of()), economic_event.appreciated_by(&base_address));
+    hdk::prelude::debug!("handle_create_appreciation::appreciation_of index {:?}", e1);
+    let e2 = create_index!(appreciation.appreciation_with(appreciation.get_appreciation_with()), economic_event.appreciation(&base_address));
+    hdk::prelude::debug!("handle_create_appreciation::appreciation_with index {:?}", e2);
+
+    construct_response(&base_address, &meta, &entry_resp)
+}
+
+pub fn handle_get_appreciation(address: AppreciationAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.appreciation.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.appreciation.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<AppreciationAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, AppreciationAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry)?.appreciation)
+    })
+}
+
+pub fn handle_update_appreciation(appreciation: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_appreciation(None, appreciation)
+}
+
+pub fn handle_resolve_appreciation_conflict(conflicting_revisions: Vec<ActionHash>, appreciation: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_appreciation(Some(&conflicting_revisions), appreciation)
+}
+
+fn update_or_resolve_appreciation(conflicting_revisions: Option<&[ActionHash]>, appreciation: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, new_entry, prev_entry): (_, AppreciationAddress, EntryData, EntryData) = update_or_resolve_record(&appreciation.get_revision_id(), conflicting_revisions, appreciation.to_owned())?;
+
+    if new_entry.appreciation_of != prev_entry.appreciation_of {
+        let e = update_index!(
+            appreciation
+                .appreciation_of(&vec![new_entry.appreciation_of.clone()])
+                .not(&vec![prev_entry.appreciation_of]),
+            economic_event.appreciated_by(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_appreciation::appreciation_of index {:?}", e);
+    }
+    if new_entry.appreciation_with != prev_entry.appreciation_with {
+        let e = update_index!(
+            appreciation
+                .appreciation_with(&vec![new_entry.appreciation_with.clone()])
+                .not(&vec![prev_entry.appreciation_with]),
+            economic_event.appreciation(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_appreciation::appreciation_with index {:?}", e);
+    }
+
+    construct_response(&base_address, &meta, &new_entry)
+}
+
+pub fn handle_delete_appreciation<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // load the record to ensure it is of the correct type
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    let e1 = update_index!(appreciation.appreciation_of.not(&vec![entry.appreciation_of]), economic_event.appreciated_by(&base_address));
+    hdk::prelude::debug!("handle_delete_appreciation::appreciation_of index {:?}", e1);
+    let e2 = update_index!(appreciation.appreciation_with.not(&vec![entry.appreciation_with]), economic_event.appreciation(&base_address));
+    hdk::prelude::debug!("handle_delete_appreciation::appreciation_with index {:?}", e2);
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &AppreciationAddress, meta: &SignedActionHashed, e: &EntryData,
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        appreciation: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            appreciation_of: e.appreciation_of.to_owned(),
+            appreciation_with: e.appreciation_with.to_owned(),
+            note: e.note.to_owned(),
+        }
+    })
+}
+
+/// Properties accessor for zome config
+fn read_appreciation_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.appreciation.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_economic_event_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.appreciation.economic_event_index_zome)
+}
@@ -0,0 +1,116 @@
+/**
+ * hREA appreciation zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    ActionHash, ByAction, ByAddress, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+    EconomicEventAddress,
+};
+
+/// Toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub appreciation: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    pub appreciation: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+// Export external type interface to allow consuming zomes to easily import & define zome API
+pub use vf_attributes_hdk::{ AppreciationAddress };
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: AppreciationAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub appreciation_of: EconomicEventAddress,
+    pub appreciation_with: EconomicEventAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub appreciation: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub appreciation_of: EconomicEventAddress,
+    pub appreciation_with: EconomicEventAddress,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    pub fn get_appreciation_of(&'a self) -> &EconomicEventAddress {
+        &self.appreciation_of
+    }
+
+    pub fn get_appreciation_with(&'a self) -> &EconomicEventAddress {
+        &self.appreciation_with
+    }
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    pub appreciation_of: MaybeUndefined<EconomicEventAddress>,
+    #[serde(default)]
+    pub appreciation_with: MaybeUndefined<EconomicEventAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &ActionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub appreciation_of: Option<EconomicEventAddress>,
+    pub appreciation_with: Option<EconomicEventAddress>,
+}
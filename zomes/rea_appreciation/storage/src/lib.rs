@@ -0,0 +1,125 @@
+/**
+ * hREA appreciation zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+pub use vf_attributes_hdk::{
+    AppreciationAddress,
+    EconomicEventAddress,
+};
+
+use hc_zome_rea_appreciation_rpc::{ CreateRequest, UpdateRequest };
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub appreciation: AppreciationZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct AppreciationZomeConfig {
+    pub index_zome: String,
+    pub economic_event_index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub appreciation_of: EconomicEventAddress,
+    pub appreciation_with: EconomicEventAddress,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.appreciation_of == self.appreciation_with {
+            return Err("Appreciation must link two different EconomicEvents".into());
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, AppreciationAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    Appreciation(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::Appreciation(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            appreciation_of: e.appreciation_of.into(),
+            appreciation_with: e.appreciation_with.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            appreciation_of: if !e.appreciation_of.is_some() { self.appreciation_of.to_owned() } else { e.appreciation_of.to_owned().unwrap() },
+            appreciation_with: if !e.appreciation_with.is_some() { self.appreciation_with.to_owned() } else { e.appreciation_with.to_owned().unwrap() },
+            note: if e.note == MaybeUndefined::Undefined { self.note.clone() } else { e.note.clone().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
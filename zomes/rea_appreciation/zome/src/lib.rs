@@ -0,0 +1,61 @@
+/**
+ * hREA appreciation zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_appreciation_rpc::*;
+use hc_zome_rea_appreciation_lib::*;
+use hc_zome_rea_appreciation_storage_consts::*;
+
+#[hdk_extern]
+fn create_appreciation(CreateParams { appreciation }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_appreciation(APPRECIATION_ENTRY_TYPE, appreciation)?)
+}
+
+#[hdk_extern]
+fn get_appreciation(ByAddress { address }: ByAddress<AppreciationAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_appreciation(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<AppreciationAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn update_appreciation(UpdateParams { appreciation }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_appreciation(appreciation)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub appreciation: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_appreciation_conflict(ResolveConflictParams { conflicting_revisions, appreciation }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_appreciation_conflict(conflicting_revisions, appreciation)?)
+}
+
+#[hdk_extern]
+fn delete_appreciation(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_appreciation(APPRECIATION_ENTRY_TYPE, revision_id)?)
+}
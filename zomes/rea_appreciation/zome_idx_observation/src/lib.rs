@@ -0,0 +1,14 @@
+/**
+ * Appreciation query indexes for observation DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_appreciation_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct Appreciation {
+    appreciation_of: Local<economic_event, appreciated_by>,
+    appreciation_with: Local<economic_event, appreciation>,
+}
@@ -0,0 +1,209 @@
+/**
+ * hREA claim zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Claim` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+use vf_measurement::{QuantityValue, add, subtract};
+
+use hc_zome_rea_claim_storage::*;
+use hc_zome_rea_claim_rpc::*;
+use hc_zome_rea_settlement_storage::{EntryData as SettlementData, EntryStorage as SettlementStorage};
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.claim.index_zome)
+}
+
+pub fn handle_create_claim<S>(entry_def_id: S, claim: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, claim.to_owned())?;
+
+    // handle link fields
+    let e = create_index!(claim.triggered_by(claim.get_triggered_by()), economic_event.triggers(&base_address));
+    hdk::prelude::debug!("handle_create_claim::triggered_by index {:?}", e);
+
+    // :TODO: pass results from link creation rather than re-reading
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_claim(address: ClaimAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+    response.claim.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.claim.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ClaimAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ClaimAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.claim)
+    })
+}
+
+pub fn handle_update_claim(claim: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_claim(None, claim)
+}
+
+pub fn handle_resolve_claim_conflict(conflicting_revisions: Vec<ActionHash>, claim: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_claim(Some(&conflicting_revisions), claim)
+}
+
+fn update_or_resolve_claim(conflicting_revisions: Option<&[ActionHash]>, claim: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let address = claim.get_revision_id().to_owned();
+    let (meta, base_address, new_entry, prev_entry): (_, ClaimAddress, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, claim.to_owned())?;
+
+    if new_entry.triggered_by != prev_entry.triggered_by {
+        let new_value = vec![new_entry.triggered_by.to_owned()];
+        let prev_value = vec![prev_entry.triggered_by.to_owned()];
+        let e = update_index!(
+            claim
+                .triggered_by(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            economic_event.triggers(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_claim::triggered_by index {:?}", e);
+    }
+
+    construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_claim<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // load the record to ensure it is of the correct type
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    let e = update_index!(claim.triggered_by.not(&vec![entry.triggered_by]), economic_event.triggers(&base_address));
+    hdk::prelude::debug!("handle_delete_claim::triggered_by index {:?}", e);
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &ClaimAddress, meta: &SignedActionHashed, e: &EntryData, (
+        settlements,
+    ): (
+        Vec<SettlementAddress>,
+    )
+) -> RecordAPIResult<ResponseData> {
+    let (settled_resource_quantity, settled_effort_quantity) = get_settled_quantities(&settlements)?;
+
+    Ok(ResponseData {
+        claim: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            action: e.action.to_owned(),
+            provider: e.provider.to_owned(),
+            receiver: e.receiver.to_owned(),
+            triggered_by: e.triggered_by.to_owned(),
+            resource_classified_as: e.resource_classified_as.to_owned(),
+            resource_conforms_to: e.resource_conforms_to.to_owned(),
+            resource_quantity: e.resource_quantity.to_owned(),
+            effort_quantity: e.effort_quantity.to_owned(),
+            due: e.due.to_owned(),
+            created: e.created.to_owned(),
+            agreed_in: e.agreed_in.to_owned(),
+            in_scope_of: e.in_scope_of.to_owned(),
+            note: e.note.to_owned(),
+            finished: e.finished.to_owned(),
+            settled_by: settlements.to_owned(),
+            outstanding_resource_quantity: get_outstanding_quantity(&e.resource_quantity, &settled_resource_quantity)?,
+            outstanding_effort_quantity: get_outstanding_quantity(&e.effort_quantity, &settled_effort_quantity)?,
+            settled_resource_quantity,
+            settled_effort_quantity,
+        }
+    })
+}
+
+/// Sum the quantities of all `Settlement`s against a `Claim`.
+/// Settlements are always co-located with the Claims they settle, so can be read directly.
+///
+fn get_settled_quantities(settlements: &Vec<SettlementAddress>) -> RecordAPIResult<(Option<QuantityValue>, Option<QuantityValue>)>
+{
+    settlements.iter()
+        .try_fold((None, None), |(resource_total, effort_total), settlement| {
+            let (_meta, _base_address, entry) = read_record_entry::<SettlementData, SettlementStorage, _>(settlement.as_ref())?;
+            Ok((
+                sum_quantities(resource_total, entry.resource_quantity)?,
+                sum_quantities(effort_total, entry.effort_quantity)?,
+            ))
+        })
+}
+
+fn sum_quantities(total: Option<QuantityValue>, qty: Option<QuantityValue>) -> RecordAPIResult<Option<QuantityValue>>
+{
+    match (total, qty) {
+        (Some(total), Some(qty)) => Ok(Some(add(total, qty)?)),
+        (total, None) => Ok(total),
+        (None, qty) => Ok(qty),
+    }
+}
+
+fn get_outstanding_quantity(claimed: &Option<QuantityValue>, settled: &Option<QuantityValue>) -> RecordAPIResult<Option<QuantityValue>>
+{
+    match (claimed, settled) {
+        (Some(claimed), Some(settled)) => Ok(Some(subtract(claimed.to_owned(), settled.to_owned())?)),
+        (claimed, _) => Ok(claimed.to_owned()),
+    }
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_claim_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.claim.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_economic_event_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.claim.economic_event_index_zome
+}
+
+// @see construct_response
+fn get_link_fields(claim: &ClaimAddress) -> RecordAPIResult<(
+    Vec<SettlementAddress>,
+)> {
+    Ok((
+        read_index!(claim(claim).settled_by)?,
+    ))
+}
@@ -0,0 +1,61 @@
+/**
+ * hREA claim zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_claim_rpc::*;
+use hc_zome_rea_claim_lib::*;
+use hc_zome_rea_claim_storage_consts::*;
+
+#[hdk_extern]
+fn create_claim(CreateParams { claim }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_claim(CLAIM_ENTRY_TYPE, claim)?)
+}
+
+#[hdk_extern]
+fn get_claim(ByAddress { address }: ByAddress<ClaimAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_claim(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ClaimAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn update_claim(UpdateParams { claim }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_claim(claim)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub claim: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_claim_conflict(ResolveConflictParams { conflicting_revisions, claim }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_claim_conflict(conflicting_revisions, claim)?)
+}
+
+#[hdk_extern]
+fn delete_claim(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_claim(CLAIM_ENTRY_TYPE, revision_id)?)
+}
@@ -0,0 +1,14 @@
+/**
+ * Claim query indexes for planning DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_claim_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct Claim {
+    settled_by: Local<settlement, settles>,
+    triggered_by: Local<economic_event, triggers>,
+}
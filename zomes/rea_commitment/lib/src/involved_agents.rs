@@ -0,0 +1,113 @@
+/**
+ * Commitment involved agents
+ *
+ * The agents involved in a `Commitment` are its provider & receiver, along with the
+ * providers & receivers of any `EconomicEvent`s fulfilling it. Fulfilling events live
+ * in the observation DNA and are read via remote calls.
+ *
+ * Results are stored in the `involved_agents` index so that they can be used as a query
+ * filter. `handle_sync_involved_agents` must be run against a `Commitment` whenever its
+ * own agents or any of its `Fulfillment`s change, and whenever a fulfilling event is
+ * corrected- a correction supersedes the event it corrects, including its agents.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::read_record_entry,
+    rpc::call_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+use vf_attributes_hdk::{ByAddress, EconomicEventAddress};
+
+use hc_zome_rea_commitment_storage_consts::*;
+use hc_zome_rea_commitment_storage::*;
+use hc_zome_rea_commitment_rpc::*;
+use hc_zome_rea_fulfillment_storage::{EntryData as FulfillmentData, EntryStorage as FulfillmentStorage};
+use hc_zome_rea_economic_event_rpc::{
+    ResponseData as EconomicEventResponseData,
+};
+
+/// Recompute the `involved_agents` of `commitment` from its own agents and those of its fulfilling events.
+///
+pub fn handle_sync_involved_agents(commitment: &CommitmentAddress) -> RecordAPIResult<()>
+{
+    let (_meta, _base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(commitment.as_ref())?;
+
+    let mut involved_agents = vec![entry.provider, entry.receiver];
+    let fulfillments: Vec<FulfillmentAddress> = read_index!(commitment(commitment).fulfilled_by)?;
+    for fulfillment in fulfillments {
+        let (_meta, _base_address, fulfillment_entry) = read_record_entry::<FulfillmentData, FulfillmentStorage, _>(fulfillment.as_ref())?;
+        let event = read_current_event(&fulfillment_entry.fulfilled_by)?;
+        involved_agents.push(event.economic_event.provider);
+        involved_agents.push(event.economic_event.receiver);
+    }
+    let involved_agents = unique(involved_agents);
+
+    let prev_involved_agents: Vec<AgentAddress> = read_index!(commitment(commitment).involved_agents)?;
+    let results = update_index!(commitment(commitment)
+        .involved_agents(difference(&involved_agents, &prev_involved_agents).as_slice())
+        .not(difference(&prev_involved_agents, &involved_agents).as_slice()))?;
+    for result in results {
+        result?;
+    }
+
+    Ok(())
+}
+
+/// Read an `EconomicEvent` from the observation DNA, following any corrections
+/// made to it through to the event which currently stands in its place.
+///
+fn read_current_event(event: &EconomicEventAddress) -> RecordAPIResult<EconomicEventResponseData>
+{
+    let mut resp = read_economic_event(event)?;
+    while let Some(correction) = resp.economic_event.corrected_by.first().cloned() {
+        resp = read_economic_event(&correction)?;
+    }
+    Ok(resp)
+}
+
+fn read_economic_event(event: &EconomicEventAddress) -> RecordAPIResult<EconomicEventResponseData>
+{
+    Ok(call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+        event,
+        &ECONOMIC_EVENT_READ_API_METHOD,
+        ByAddress { address: event.to_owned() },
+        LinkTypes::AvailableCapability,
+    )?)
+}
+
+/// Remove all `involved_agents` links for `commitment`, prior to its deletion
+///
+pub fn clear_involved_agents(commitment: &CommitmentAddress) -> RecordAPIResult<()>
+{
+    let involved_agents: Vec<AgentAddress> = read_index!(commitment(commitment).involved_agents)?;
+    let results = update_index!(commitment(commitment).involved_agents.not(involved_agents.as_slice()))?;
+    for result in results {
+        result?;
+    }
+
+    Ok(())
+}
+
+fn unique(agents: Vec<AgentAddress>) -> Vec<AgentAddress>
+{
+    let mut unique = vec![];
+    for agent in agents {
+        if !unique.contains(&agent) {
+            unique.push(agent);
+        }
+    }
+    unique
+}
+
+fn difference(a: &Vec<AgentAddress>, b: &Vec<AgentAddress>) -> Vec<AgentAddress>
+{
+    a.iter().filter(|p| !b.contains(p)).cloned().collect()
+}
+
+/// Properties accessor for zome config
+fn read_commitment_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.commitment.index_zome)
+}
@@ -8,21 +8,30 @@
  */
 use paste::paste;
 use hdk_records::{
-    RecordAPIResult, MaybeUndefined, SignedActionHashed,
+    RecordAPIResult, OtherCellResult, MaybeUndefined, SignedActionHashed,
     records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+    rpc::call_zome_method,
 };
 use hdk_semantic_indexes_client_lib::*;
+use vf_attributes_hdk::ByAddress;
 
+use hc_zome_rea_commitment_storage_consts::*;
 use hc_zome_rea_commitment_storage::*;
 use hc_zome_rea_commitment_rpc::*;
 
+mod involved_agents;
+pub use involved_agents::handle_sync_involved_agents;
+use involved_agents::clear_involved_agents;
+
 /// properties accessor for zome config
 fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.commitment.index_zome)
@@ -60,12 +69,22 @@ pub fn handle_create_commitment<S>(entry_def_id: S, commitment: CreateRequest) -
         let e = create_index!(commitment.planned_within(planned_within), plan.non_process_commitments(&base_address));
         hdk::prelude::debug!("handle_create_commitment::planned_within index {:?}", e);
     };
+    if let Some(due) = &entry_resp.due {
+        let e = index_time!(commitment(&base_address).due(due.to_owned()));
+        hdk::prelude::debug!("handle_create_commitment::due time index {:?}", e);
+    };
     // // TODO: because commitment.in_scope_of is a vec of ids rather than one id, make sure this is still handled properly
     // if let CreateRequest { in_scope_of: MaybeUndefined::Some(in_scope_of), .. } = &commitment {
     //     let e = create_index!(commitment.in_scope_of(in_scope_of), agent.commitments(&base_address));
     //     hdk::prelude::debug!("handle_create_commitment::in_scope_of index {:?}", e);
     // };
 
+    let e = handle_sync_involved_agents(&base_address);
+    hdk::prelude::debug!("handle_create_commitment::involved_agents {:?}", e);
+
+    // processes which this flow is an input or output of may now be sequenced differently
+    sync_process_sequence(&flow_processes(&entry_resp));
+
     // :TODO: pass results from link creation rather than re-reading
     construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
 }
@@ -73,19 +92,45 @@ pub fn handle_create_commitment<S>(entry_def_id: S, commitment: CreateRequest) -
 pub fn handle_get_commitment(address: CommitmentAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+    response.commitment.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.commitment.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<CommitmentAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, CommitmentAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.commitment)
+    })
 }
 
 pub fn handle_update_commitment(commitment: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_commitment(None, commitment)
+}
+
+pub fn handle_resolve_commitment_conflict(conflicting_revisions: Vec<ActionHash>, commitment: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_commitment(Some(&conflicting_revisions), commitment)
+}
+
+fn update_or_resolve_commitment(conflicting_revisions: Option<&[ActionHash]>, commitment: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let address = commitment.get_revision_id().to_owned();
-    let (meta, base_address, new_entry, prev_entry): (_, CommitmentAddress, EntryData, EntryData) = update_record(&address, commitment.to_owned())?;
+    let (meta, base_address, new_entry, prev_entry): (_, CommitmentAddress, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, commitment.to_owned())?;
 
     if new_entry.input_of != prev_entry.input_of {
         let new_value = match &new_entry.input_of { Some(val) => vec![val.to_owned()], None => vec![] };
@@ -143,6 +188,13 @@ pub fn handle_update_commitment(commitment: UpdateRequest) -> RecordAPIResult<Re
         );
         hdk::prelude::debug!("handle_update_commitment::planned_within index {:?}", e);
     }
+    if new_entry.due != prev_entry.due {
+        let e = match &new_entry.due {
+            Some(due) => index_time!(commitment(&base_address).due(due.to_owned())),
+            None => unindex_time!(commitment(&base_address).due),
+        };
+        hdk::prelude::debug!("handle_update_commitment::due time index {:?}", e);
+    }
     // // TODO: ensure handling of vec of ids
     // if new_entry.in_scope_of != prev_entry.in_scope_of {
     //     let new_value = match &new_entry.in_scope_of { Some(val) => vec![val.to_owned()], None => vec![] };
@@ -177,14 +229,34 @@ pub fn handle_update_commitment(commitment: UpdateRequest) -> RecordAPIResult<Re
         );
         hdk::prelude::debug!("handle_update_commitment::receiver index {:?}", e);
     }
+    if new_entry.provider != prev_entry.provider || new_entry.receiver != prev_entry.receiver {
+        let e = handle_sync_involved_agents(&base_address);
+        hdk::prelude::debug!("handle_update_commitment::involved_agents {:?}", e);
+    }
+
+    if new_entry.input_of != prev_entry.input_of || new_entry.output_of != prev_entry.output_of
+        || new_entry.resource_inventoried_as != prev_entry.resource_inventoried_as
+        || new_entry.resource_conforms_to != prev_entry.resource_conforms_to
+    {
+        // processes either side of the old & new flow may now be sequenced differently
+        let mut processes = flow_processes(&new_entry);
+        for process in flow_processes(&prev_entry) {
+            if !processes.contains(&process) {
+                processes.push(process);
+            }
+        }
+        sync_process_sequence(&processes);
+    }
 
     construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
 }
 
-pub fn handle_delete_commitment(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_commitment<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     // load the record to ensure it is of the correct type
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let sequenced_processes = flow_processes(&entry);
 
     // handle link fields
     if let Some(process_address) = entry.input_of {
@@ -211,9 +283,31 @@ pub fn handle_delete_commitment(revision_id: ActionHash) -> RecordAPIResult<bool
     hdk::prelude::debug!("handle_delete_commitment::provider index {:?}", e1);
     let e2 = update_index!(commitment.receiver.not(&vec![entry.receiver]), agent.commitments_as_receiver(&base_address));
     hdk::prelude::debug!("handle_delete_commitment::receiver index {:?}", e2);
+    let e = clear_involved_agents(&base_address);
+    hdk::prelude::debug!("handle_delete_commitment::involved_agents {:?}", e);
+
+    sync_process_sequence(&sequenced_processes);
 
     // delete entry last, as it must be present in order for links to be removed
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Processes which a flow is an input or output of
+fn flow_processes(e: &EntryData) -> Vec<ProcessAddress> {
+    e.input_of.iter().chain(e.output_of.iter()).cloned().collect()
+}
+
+/// Ask the observation cell holding each `Process` to recompute its sequencing, after flows into or out of it have changed
+fn sync_process_sequence(processes: &[ProcessAddress]) {
+    for process in processes {
+        let pingback: OtherCellResult<()> = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            process,
+            &PROCESS_SEQUENCE_SYNC_API_METHOD,
+            ByAddress { address: process.to_owned() },
+            LinkTypes::AvailableCapability,
+        );
+        hdk::prelude::debug!("sync_process_sequence::call_zome_method::{:?} {:?}", PROCESS_SEQUENCE_SYNC_API_METHOD, pingback);
+    }
 }
 
 /// Create response from input DHT primitives
@@ -294,11 +388,11 @@ fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
 fn get_link_fields(commitment: &CommitmentAddress) -> RecordAPIResult<(
     Vec<FulfillmentAddress>,
     Vec<SatisfactionAddress>,
-    Vec<AgentAddress>, // is this for `involved_agents` or `in_scope_of` or both?
+    Vec<AgentAddress>,
 )> {
     Ok((
         read_index!(commitment(commitment).fulfilled_by)?,
         read_index!(commitment(commitment).satisfies)?,
-        vec![],   // :TODO:
+        read_index!(commitment(commitment).involved_agents)?,
     ))
 }
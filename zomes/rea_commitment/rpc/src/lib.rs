@@ -12,6 +12,8 @@ use serde_maybe_undefined::{MaybeUndefined, default_false};
 use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     ActionHash, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     ActionId,
     DateTime, FixedOffset,
     ExternalURL,
@@ -105,6 +107,12 @@ pub struct ResponseData {
 
 //---------------- CREATE REQUEST ----------------
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub commitment: CreateRequest,
+}
+
 /// I/O struct to describe the complete input record, including all managed links
 ///
 #[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
@@ -233,4 +241,5 @@ pub struct QueryParams {
     pub receiver: Option<AgentAddress>,
     pub independent_demand_of: Option<PlanAddress>,
     pub planned_within: Option<PlanAddress>,
+    pub involved_agents: Option<AgentAddress>,
 }
@@ -6,3 +6,6 @@
  * @package hREA
  */
 pub const COMMITMENT_ENTRY_TYPE: &str = "commitment";
+
+pub const PROCESS_SEQUENCE_SYNC_API_METHOD: &str = "sync_process_sequence";
+pub const ECONOMIC_EVENT_READ_API_METHOD: &str = "read_economic_event";
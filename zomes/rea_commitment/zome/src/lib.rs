@@ -17,12 +17,6 @@ use hc_zome_rea_commitment_rpc::*;
 use hc_zome_rea_commitment_lib::*;
 use hc_zome_rea_commitment_storage_consts::*;
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct CreateParams {
-    pub commitment: CreateRequest,
-}
-
 #[hdk_extern]
 fn create_commitment(CreateParams { commitment }: CreateParams) -> ExternResult<ResponseData> {
     Ok(handle_create_commitment(COMMITMENT_ENTRY_TYPE, commitment)?)
@@ -38,11 +32,26 @@ fn get_commitment(ByAddress { address }: ByAddress) -> ExternResult<ResponseData
     Ok(handle_get_commitment(address)?)
 }
 
+#[hdk_extern]
+fn sync_commitment_involved_agents(ByAddress { address }: ByAddress) -> ExternResult<()> {
+    Ok(handle_sync_involved_agents(&address)?)
+}
+
 #[hdk_extern]
 fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<CommitmentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -54,7 +63,19 @@ fn update_commitment(UpdateParams { commitment }: UpdateParams) -> ExternResult<
     Ok(handle_update_commitment(commitment)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub commitment: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_commitment_conflict(ResolveConflictParams { conflicting_revisions, commitment }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_commitment_conflict(conflicting_revisions, commitment)?)
+}
+
 #[hdk_extern]
 fn delete_commitment(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_commitment(revision_id)?)
+    Ok(handle_delete_commitment(COMMITMENT_ENTRY_TYPE, revision_id)?)
 }
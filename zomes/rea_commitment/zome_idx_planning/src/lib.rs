@@ -8,6 +8,7 @@ use hdk_semantic_indexes_zome_derive::index_zome;
 use hc_zome_rea_commitment_rpc::*;
 
 #[index_zome]
+#[time_index(due)]
 struct Commitment {
     fulfilled_by: Local<fulfillment, fulfills>,
     satisfies: Local<satisfaction, satisfied_by>,
@@ -20,5 +21,6 @@ struct Commitment {
     receiver: Local<agent, commitments_as_receiver>,
     independent_demand_of: Local<plan, independent_demands>,
     planned_within: Local<plan, non_process_commitments>,
+    involved_agents: Local<agent, commitments_involved_in>,
     // in_scope_of: Local<agent, commitments>,
 }
@@ -8,18 +8,21 @@
  */
 use paste::paste;
 use hdk_records::{
-    RecordAPIResult, OtherCellResult, MaybeUndefined, SignedActionHashed,
+    RecordAPIResult, DataIntegrityError, OtherCellResult, MaybeUndefined, SignedActionHashed,
     rpc::{
+        call_zome_method,
         call_local_zome_method,
     },
     records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -35,10 +38,16 @@ use hc_zome_rea_economic_resource_rpc::{ CreationPayload as ResourceCreationPayl
 
 use hc_zome_rea_economic_resource_storage::{
     EntryData as EconomicResourceData,
+    InventoryUpdate,
+};
+use hc_zome_rea_fulfillment_storage::{
+    EntryData as FulfillmentData,
+    EntryStorage as FulfillmentStorage,
 };
 use hc_zome_rea_economic_resource_lib::{
     construct_response_record as construct_resource_response,
     get_link_fields as get_resource_link_fields,
+    track_trace,
 };
 
 
@@ -59,6 +68,11 @@ fn read_agent_index_zome(conf: DnaConfigSlice) -> Option<String> {
     conf.economic_event.agent_index_zome
 }
 
+/// Properties accessor for zome config.
+fn read_location_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.economic_event.location_index_zome
+}
+
 /// Trait object defining the default ValueFlows EconomicResource zome API.
 /// 'Permissable' denotes the interface as a highly-permissable one, where little
 /// validation on entry contents is performed.
@@ -74,36 +88,58 @@ impl API for EconomicEventZomePermissableDefault {
         let mut resources_affected: Vec<(SignedActionHashed, EconomicResourceAddress, EconomicResourceData, EconomicResourceData)> = vec![];
         let mut resource_created: Option<(SignedActionHashed, EconomicResourceAddress, EconomicResourceData)> = None;
 
-        // if the event observes a new resource, create that resource & return it in the response
+        let corrected_event = match &event.corrects {
+            MaybeUndefined::Some(corrected_event) => Some(read_correctable_event(corrected_event)?),
+            _ => None,
+        };
+        // :TODO: move this assertion to validation callback
+        if corrected_event.is_some() && new_inventoried_resource.is_some() {
+            return Err(DataIntegrityError::RemoteRequestError("cannot create a new EconomicResource in an EconomicEvent correction".to_string()));
+        }
+
+        // Write the event first, so that the resource revisions it causes can reference it.
+        // Note we ignore the revision ID because events can't be edited (only underwritten by subsequent events)
+        let (meta, event_address, event_entry) = handle_create_economic_event_record(&entry_def_id, &event)?;
+        let recorded_event = event.with_recorded_event(&event_address);
+
+        // the correction stands in for the corrected event in any commitments it fulfilled
+        if let (Some(corrected_entry), MaybeUndefined::Some(corrected_address)) = (&corrected_event, &event.corrects) {
+            if corrected_entry.provider != event_entry.provider || corrected_entry.receiver != event_entry.receiver {
+                let e = sync_commitment_involved_agents(corrected_address);
+                hdk::prelude::debug!("create_economic_event::sync_commitment_involved_agents {:?}", e);
+            }
+        }
+
+        // if the event observes a new resource, create that resource & return it in the response.
+        // The resource is linked to the event via the `affects` index, below.
         if let Some(economic_resource) = new_inventoried_resource {
             let new_resource = handle_create_inventory_from_event(
-                &economic_resource, &event,
+                &economic_resource, &recorded_event,
             )?;
             resource_created = Some(new_resource.clone());
             resources_affected.push((new_resource.0, new_resource.1, new_resource.2.clone(), new_resource.2));
         }
 
-        // update any linked resources affected by the event
-        resources_affected.append(&mut handle_update_resource_inventory(&event)?);
-
-        // Now that the resource updates have succeeded, write the event.
-        // Note we ignore the revision ID because events can't be edited (only underwritten by subsequent events)
-        // :TODO: rethinking this, it's probably the event that should be written first, and the resource
-        // validation should eventually depend on an event already having been authored.
-        let (meta, event_address, event_entry) = handle_create_economic_event_record(
-            &entry_def_id,
-            &event, match &resource_created {
-                Some(data) => Some(data.1.to_owned()),
-                None => None,
-            },
-        )?;
+        // update any linked resources affected by the event, reversing the effects of any event it corrects
+        resources_affected.append(&mut handle_update_resource_inventory(InventoryUpdate {
+            event: recorded_event,
+            corrected_event: corrected_event.map(|corrected_entry| corrected_entry.into()),
+        })?);
 
         // Link any affected resources to this event so that we can pull all the events which affect any resource
+        let mut resources_indexed: Vec<&EconomicResourceAddress> = vec![];
         for resource_data in resources_affected.iter() {
+            if resources_indexed.contains(&&resource_data.1) {
+                continue;
+            }
             let e = create_index!(economic_event.affects(resource_data.1), economic_resource.affected_by(&event_address));
             hdk::prelude::debug!("create_economic_event::affects index {:?}", e);
+            resources_indexed.push(&resource_data.1);
         }
 
+        // processes which this event flows into or out of may now be sequenced differently
+        sync_process_sequence(&event_entry.input_of, &event_entry.output_of);
+
         match resource_created {
             Some((resource_meta, resource_addr, resource_entry)) => {
                 construct_response_with_resource(
@@ -118,35 +154,58 @@ impl API for EconomicEventZomePermissableDefault {
         }
     }
 
+    fn correct_economic_event(entry_def_id: Self::S, corrects: EconomicEventAddress, event: EconomicEventCreateRequest) -> RecordAPIResult<ResponseData> {
+        Self::create_economic_event(entry_def_id, EconomicEventCreateRequest {
+            corrects: MaybeUndefined::Some(corrects),
+            ..event
+        }, None)
+    }
+
     fn get_economic_event(address: EconomicEventAddress) -> RecordAPIResult<ResponseData> {
         let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-        construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)
+        let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+        response.economic_event.meta = read_revision_metadata_full(&meta)?;
+        Ok(response)
     }
 
     fn get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
     {
         let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-        construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+        let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+        response.economic_event.meta = read_revision_metadata_full(&meta)?;
+        Ok(response)
+    }
+
+    fn get_revision_history(page: RevisionHistoryParams<EconomicEventAddress>) -> RecordAPIResult<RevisionHistory>
+    {
+        read_record_revision_history(&page)
+    }
+
+    fn diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+    {
+        diff_revisions::<EntryData, EntryStorage, EconomicEventAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+            Ok(construct_response(address, meta, entry, Default::default())?.economic_event)
+        })
     }
 
     fn update_economic_event(event: EconomicEventUpdateRequest) -> RecordAPIResult<ResponseData> {
-        let address = event.get_revision_id().to_owned();
-        let (meta, identity_address, new_entry, _prev_entry): (_, EconomicEventAddress, EntryData, EntryData) = update_record(&address, event)?;
+        update_or_resolve_economic_event(None, event)
+    }
 
-        // :TODO: optimise this- should pass results from `replace_direct_index` instead of retrieving from `get_link_fields` where updates
-        construct_response(&identity_address, &meta, &new_entry, get_link_fields(&identity_address)?)
+    fn resolve_economic_event_conflict(conflicting_revisions: Vec<ActionHash>, event: EconomicEventUpdateRequest) -> RecordAPIResult<ResponseData> {
+        update_or_resolve_economic_event(Some(&conflicting_revisions), event)
     }
 
-    fn delete_economic_event(revision_id: ActionHash) -> RecordAPIResult<bool> {
+    fn delete_economic_event(entry_def_id: Self::S, revision_id: ActionHash) -> RecordAPIResult<bool> {
         // read any referencing indexes
         let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
 
         // handle link fields
-        if let Some(process_address) = entry.input_of {
+        if let Some(process_address) = &entry.input_of {
             let e = update_index!(economic_event.input_of.not(&vec![process_address.to_owned()]), process.observed_inputs(&base_address));
             hdk::prelude::debug!("delete_economic_event::input_of index {:?}", e);
         }
-        if let Some(process_address) = entry.output_of {
+        if let Some(process_address) = &entry.output_of {
             let e = update_index!(economic_event.output_of.not(&vec![process_address.to_owned()]), process.observed_outputs(&base_address));
             hdk::prelude::debug!("delete_economic_event::output_of index {:?}", e);
         }
@@ -154,16 +213,48 @@ impl API for EconomicEventZomePermissableDefault {
             let e = update_index!(economic_event.realization_of.not(&vec![agreement_address.to_owned()]), agreement.economic_events(&base_address));
             hdk::prelude::debug!("delete_economic_event::realization_of index {:?}", e);
         }
+        if let Some(location_address) = entry.at_location {
+            let e = update_index!(economic_event.at_location.not(&vec![location_address.to_owned()]), location.economic_events(&base_address));
+            hdk::prelude::debug!("delete_economic_event::at_location index {:?}", e);
+        }
+        if let Some(corrected_address) = entry.corrects {
+            let e = update_index!(economic_event(&base_address).corrects.not(&vec![corrected_address.to_owned()]));
+            hdk::prelude::debug!("delete_economic_event::corrects index {:?}", e);
+
+            // the corrected event takes its place in time-ordered listings again
+            match read_record_entry::<EntryData, EntryStorage, _>(corrected_address.as_ref()) {
+                Ok((_meta, corrected_address, corrected_entry)) => index_event_times(&corrected_address, &corrected_entry),
+                Err(e) => hdk::prelude::debug!("delete_economic_event::corrects time indexes {:?}", e),
+            }
+        }
         let e = update_index!(economic_event.provider.not(&vec![entry.provider]), agent.economic_events_as_provider(&base_address));
         hdk::prelude::debug!("delete_economic_event::provider index {:?}", e);
         let e = update_index!(economic_event.receiver.not(&vec![entry.receiver]), agent.economic_events_as_receiver(&base_address));
         hdk::prelude::debug!("delete_economic_event::receiver index {:?}", e);
 
+        sync_process_sequence(&entry.input_of, &entry.output_of);
+
         // :TODO: handle cleanup of foreign key fields? (fulfillment, satisfaction)
         // May not be needed due to cross-record deletion validation logic.
 
         // delete entry last as it must be present in order for links to be removed
-        delete_record::<EntryStorage>(&revision_id)
+        delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+    }
+
+    fn trace_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults> {
+        track_trace::trace_economic_event(params)
+    }
+
+    fn track_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults> {
+        track_trace::track_economic_event(params)
+    }
+
+    fn trace_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults> {
+        track_trace::trace_process(params)
+    }
+
+    fn track_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults> {
+        track_trace::track_process(params)
     }
 }
 
@@ -173,6 +264,22 @@ impl API for EconomicEventZomePermissableDefault {
 fn read_economic_event_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.economic_event.index_zome)
 }
+/// Add an event to the time index of each of its timestamps
+fn index_event_times(event_address: &EconomicEventAddress, entry: &EntryData) {
+    if let Some(has_point_in_time) = &entry.has_point_in_time {
+        let e = index_time!(economic_event(event_address).has_point_in_time(has_point_in_time.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_point_in_time time index {:?}", e);
+    };
+    if let Some(has_beginning) = &entry.has_beginning {
+        let e = index_time!(economic_event(event_address).has_beginning(has_beginning.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_beginning time index {:?}", e);
+    };
+    if let Some(has_end) = &entry.has_end {
+        let e = index_time!(economic_event(event_address).has_end(has_end.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_end time index {:?}", e);
+    };
+}
+
 /// Properties accessor for zome config.
 ///
 /// :TODO: should this be configurable as an array, to allow shared process planning spaces to be driven by multiple event logs?
@@ -185,17 +292,22 @@ fn read_agreement_index_zome(conf: DnaConfigSlice) -> Option<String> {
     conf.economic_event.agreement_index_zome
 }
 
-fn handle_create_economic_event_record<S>(entry_def_id: S, event: &EconomicEventCreateRequest, resource_address: Option<EconomicResourceAddress>,
+fn update_or_resolve_economic_event(conflicting_revisions: Option<&[ActionHash]>, event: EconomicEventUpdateRequest) -> RecordAPIResult<ResponseData> {
+    let address = event.get_revision_id().to_owned();
+    let (meta, identity_address, new_entry, _prev_entry): (_, EconomicEventAddress, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, event)?;
+
+    // :TODO: optimise this- should pass results from `replace_direct_index` instead of retrieving from `get_link_fields` where updates
+    construct_response(&identity_address, &meta, &new_entry, get_link_fields(&identity_address)?)
+}
+
+fn handle_create_economic_event_record<S>(entry_def_id: S, event: &EconomicEventCreateRequest,
 ) -> RecordAPIResult<(SignedActionHashed, EconomicEventAddress, EntryData)>
     where S: AsRef<str> + std::fmt::Display,
 {
     let (meta, base_address, entry_resp): (_, EconomicEventAddress, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(
         read_index_zome,
         &entry_def_id,
-        match resource_address {
-            Some(addr) => event.with_inventoried_resource(&addr),
-            None => event.to_owned(),
-        }
+        event.to_owned(),
     )?;
 
     // handle link fields
@@ -217,10 +329,44 @@ fn handle_create_economic_event_record<S>(entry_def_id: S, event: &EconomicEvent
         let e = create_index!(economic_event.realization_of(realization_of), agreement.economic_events(&base_address));
         hdk::prelude::debug!("handle_create_economic_event_record::realization_of index {:?}", e);
     };
+    if let EconomicEventCreateRequest { at_location: MaybeUndefined::Some(at_location), .. } = event {
+        let e = create_index!(economic_event.at_location(at_location), location.economic_events(&base_address));
+        hdk::prelude::debug!("handle_create_economic_event_record::at_location index {:?}", e);
+    };
+    if let EconomicEventCreateRequest { corrects: MaybeUndefined::Some(corrects), .. } = event {
+        let e = create_index!(economic_event(&base_address).corrects(corrects));
+        hdk::prelude::debug!("handle_create_economic_event_record::corrects index {:?}", e);
+    };
+    index_event_times(&base_address, &entry_resp);
+    // a correction takes the place of the event it corrects in any time-ordered listings
+    if let EconomicEventCreateRequest { corrects: MaybeUndefined::Some(corrects), .. } = event {
+        let e = unindex_time!(economic_event(corrects).has_point_in_time);
+        hdk::prelude::debug!("handle_create_economic_event_record::corrects has_point_in_time time index {:?}", e);
+        let e = unindex_time!(economic_event(corrects).has_beginning);
+        hdk::prelude::debug!("handle_create_economic_event_record::corrects has_beginning time index {:?}", e);
+        let e = unindex_time!(economic_event(corrects).has_end);
+        hdk::prelude::debug!("handle_create_economic_event_record::corrects has_end time index {:?}", e);
+    };
 
     Ok((meta, base_address, entry_resp))
 }
 
+/// Add an event to the time index of each of its timestamps
+fn index_event_times(event_address: &EconomicEventAddress, entry: &EntryData) {
+    if let Some(has_point_in_time) = &entry.has_point_in_time {
+        let e = index_time!(economic_event(event_address).has_point_in_time(has_point_in_time.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_point_in_time time index {:?}", e);
+    };
+    if let Some(has_beginning) = &entry.has_beginning {
+        let e = index_time!(economic_event(event_address).has_beginning(has_beginning.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_beginning time index {:?}", e);
+    };
+    if let Some(has_end) = &entry.has_end {
+        let e = index_time!(economic_event(event_address).has_end(has_end.to_owned()));
+        hdk::prelude::debug!("index_event_times::has_end time index {:?}", e);
+    };
+}
+
 /// Properties accessor for zome config.
 ///
 /// :TODO: should this be configurable as an array, to allow multiple inventories to be driven by the same event log?
@@ -229,6 +375,42 @@ fn read_resource_zome(conf: DnaConfigSlice) -> Option<String> {
     conf.economic_event.economic_resource_zome
 }
 
+/// Properties accessor for zome config.
+fn read_process_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.economic_event.process_zome
+}
+
+/// Ask the `Process` zome to recompute sequencing for any processes this event is an input or output of
+///
+fn sync_process_sequence(input_of: &Option<ProcessAddress>, output_of: &Option<ProcessAddress>) {
+    for process in input_of.iter().chain(output_of.iter()) {
+        let result: OtherCellResult<()> = call_local_zome_method(
+            read_process_zome,
+            PROCESS_SEQUENCE_SYNC_API_METHOD.to_string(),
+            ByAddress { address: process.to_owned() },
+        );
+        hdk::prelude::debug!("sync_process_sequence::{:?} {:?}", process, result);
+    }
+}
+
+/// Ask the planning DNA to recompute the agents involved in any `Commitment`s fulfilled by `event`.
+/// Commitments which cannot be reached are skipped, since the planning DNA may not be present.
+///
+fn sync_commitment_involved_agents(event: &EconomicEventAddress) -> RecordAPIResult<()> {
+    let fulfillments: Vec<FulfillmentAddress> = read_index!(economic_event(event).fulfills)?;
+    for fulfillment in fulfillments {
+        let (_meta, _base_address, entry) = read_record_entry::<FulfillmentData, FulfillmentStorage, _>(fulfillment.as_ref())?;
+        let result: OtherCellResult<()> = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            &entry.fulfills,
+            &COMMITMENT_INVOLVED_AGENTS_SYNC_API_METHOD,
+            ByAddress { address: entry.fulfills.to_owned() },
+            LinkTypes::AvailableCapability,
+        );
+        hdk::prelude::debug!("sync_commitment_involved_agents::{:?} {:?}", entry.fulfills, result);
+    }
+    Ok(())
+}
+
 /// Handle creation of new resources via events + resource metadata
 ///
 fn handle_create_inventory_from_event(
@@ -252,16 +434,33 @@ fn resource_creation(event: &CreateRequest, resource: &ResourceCreateRequest) ->
 /// Handle alteration of existing resources via events
 ///
 fn handle_update_resource_inventory(
-    event: &EconomicEventCreateRequest,
+    update: InventoryUpdate,
 ) -> RecordAPIResult<Vec<(SignedActionHashed, EconomicResourceAddress, EconomicResourceData, EconomicResourceData)>>
 {
     Ok(call_local_zome_method(
         read_resource_zome,
         INVENTORY_UPDATE_API_METHOD.to_string(),
-        event,
+        update,
     )?)
 }
 
+/// Read an event which is to be corrected, along with any resource it created.
+///
+/// Events may only be corrected once- any further corrections should be made to the correcting event.
+///
+fn read_correctable_event(corrected_event: &EconomicEventAddress) -> RecordAPIResult<EntryData>
+{
+    let (_meta, base_address, mut entry) = read_record_entry::<EntryData, EntryStorage, _>(corrected_event.as_ref())?;
+
+    let existing_corrections: Vec<EconomicEventAddress> = read_index!(economic_event(&base_address).corrected_by)?;
+    if let Some(correction) = existing_corrections.first() {
+        return Err(DataIntegrityError::RemoteRequestError(format!("EconomicEvent {:?} has already been corrected by {:?}", base_address, correction)));
+    }
+
+    entry.resource_inventoried_as = entry.get_inventoried_resource(&read_index!(economic_event(&base_address).affects)?);
+    Ok(entry)
+}
+
 /**
  * Create response from input DHT primitives
  *
@@ -273,9 +472,21 @@ pub fn construct_response_with_resource<'a>(
     event: &EntryData, (
         fulfillments,
         satisfactions,
+        settlements,
+        triggered_claims,
+        appreciated_by,
+        appreciations,
+        corrected_by,
+        affects,
     ): (
         Vec<FulfillmentAddress>,
         Vec<SatisfactionAddress>,
+        Vec<SettlementAddress>,
+        Vec<ClaimAddress>,
+        Vec<AppreciationAddress>,
+        Vec<AppreciationAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicResourceAddress>,
     ),
     resource_address: Option<EconomicResourceAddress>,
     resource_meta: &SignedActionHashed,
@@ -302,7 +513,7 @@ pub fn construct_response_with_resource<'a>(
             output_of: event.output_of.to_owned(),
             provider: event.provider.to_owned(),
             receiver: event.receiver.to_owned(),
-            resource_inventoried_as: event.resource_inventoried_as.to_owned(),
+            resource_inventoried_as: event.get_inventoried_resource(&affects),
             to_resource_inventoried_as: event.to_resource_inventoried_as.to_owned(),
             resource_classified_as: event.resource_classified_as.to_owned(),
             resource_conforms_to: event.resource_conforms_to.to_owned(),
@@ -316,8 +527,14 @@ pub fn construct_response_with_resource<'a>(
             triggered_by: event.triggered_by.to_owned(),
             realization_of: event.realization_of.to_owned(),
             in_scope_of: event.in_scope_of.to_owned(),
+            corrects: event.corrects.to_owned(),
             fulfills: fulfillments.to_owned(),
             satisfies: satisfactions.to_owned(),
+            settles: settlements.to_owned(),
+            triggers: triggered_claims.to_owned(),
+            appreciated_by: appreciated_by.to_owned(),
+            appreciation: appreciations.to_owned(),
+            corrected_by: corrected_by.to_owned(),
         },
         economic_resource: match resource_address {
             Some(addr) => Some(construct_resource_response(&addr, &resource_meta, &resource, (contained_in, stage, state, contains))?),
@@ -331,9 +548,21 @@ pub fn construct_response<'a>(
     address: &EconomicEventAddress, meta: &SignedActionHashed, e: &EntryData, (
         fulfillments,
         satisfactions,
+        settlements,
+        triggered_claims,
+        appreciated_by,
+        appreciations,
+        corrected_by,
+        affects,
     ): (
         Vec<FulfillmentAddress>,
         Vec<SatisfactionAddress>,
+        Vec<SettlementAddress>,
+        Vec<ClaimAddress>,
+        Vec<AppreciationAddress>,
+        Vec<AppreciationAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicResourceAddress>,
     )
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
@@ -347,7 +576,7 @@ pub fn construct_response<'a>(
             output_of: e.output_of.to_owned(),
             provider: e.provider.to_owned(),
             receiver: e.receiver.to_owned(),
-            resource_inventoried_as: e.resource_inventoried_as.to_owned(),
+            resource_inventoried_as: e.get_inventoried_resource(&affects),
             to_resource_inventoried_as: e.to_resource_inventoried_as.to_owned(),
             resource_classified_as: e.resource_classified_as.to_owned(),
             resource_conforms_to: e.resource_conforms_to.to_owned(),
@@ -361,8 +590,14 @@ pub fn construct_response<'a>(
             triggered_by: e.triggered_by.to_owned(),
             realization_of: e.realization_of.to_owned(),
             in_scope_of: e.in_scope_of.to_owned(),
+            corrects: e.corrects.to_owned(),
             fulfills: fulfillments.to_owned(),
             satisfies: satisfactions.to_owned(),
+            settles: settlements.to_owned(),
+            triggers: triggered_claims.to_owned(),
+            appreciated_by: appreciated_by.to_owned(),
+            appreciation: appreciations.to_owned(),
+            corrected_by: corrected_by.to_owned(),
         },
         economic_resource: None,
     })
@@ -372,10 +607,22 @@ pub fn construct_response<'a>(
 pub fn get_link_fields(event: &EconomicEventAddress) -> RecordAPIResult<(
     Vec<FulfillmentAddress>,
     Vec<SatisfactionAddress>,
+    Vec<SettlementAddress>,
+    Vec<ClaimAddress>,
+    Vec<AppreciationAddress>,
+    Vec<AppreciationAddress>,
+    Vec<EconomicEventAddress>,
+    Vec<EconomicResourceAddress>,
 )> {
     Ok((
         read_index!(economic_event(event).fulfills)?,
         read_index!(economic_event(event).satisfies)?,
+        read_index!(economic_event(event).settles)?,
+        read_index!(economic_event(event).triggers)?,
+        read_index!(economic_event(event).appreciated_by)?,
+        read_index!(economic_event(event).appreciation)?,
+        read_index!(economic_event(event).corrected_by)?,
+        read_index!(economic_event(event).affects)?,
     ))
 }
 
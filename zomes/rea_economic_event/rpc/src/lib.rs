@@ -13,6 +13,8 @@ use vf_measurement::QuantityValue;
 use hdk_relay_pagination::PageInfo;
 pub use vf_attributes_hdk::{
     ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     EconomicEventAddress,
     EconomicResourceAddress,
     ActionId,
@@ -26,6 +28,9 @@ pub use vf_attributes_hdk::{
     CommitmentAddress,
     FulfillmentAddress,
     SatisfactionAddress,
+    SettlementAddress,
+    ClaimAddress,
+    AppreciationAddress,
     AgreementAddress,
     ProductBatchAddress,
     UnitId,
@@ -78,6 +83,8 @@ pub struct Response {
     pub triggered_by: Option<EconomicEventAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub in_scope_of: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corrects: Option<EconomicEventAddress>,
 
     // LINK FIELDS
     #[serde(default)]
@@ -86,6 +93,21 @@ pub struct Response {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub satisfies: Vec<SatisfactionAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub settles: Vec<SettlementAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub triggers: Vec<ClaimAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub appreciated_by: Vec<AppreciationAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub appreciation: Vec<AppreciationAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub corrected_by: Vec<EconomicEventAddress>,
 }
 
 /// I/O struct to describe EconomicResources, including all managed link fields
@@ -127,6 +149,10 @@ pub struct ResourceResponse {
     pub note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub primary_accountable: Option<AgentAddress>,
+    /// only set on historical reconstructions of a resource, with the time it was reconstructed at
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconstructed_as_of: Option<DateTime<FixedOffset>>,
 
     // query edges
     #[serde(default)]
@@ -237,10 +263,15 @@ pub struct CreateRequest {
     pub triggered_by: MaybeUndefined<EconomicEventAddress>,
     #[serde(default)]
     pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    pub corrects: MaybeUndefined<EconomicEventAddress>,
 
     // :SHONK: internal field used in updating linked resource quantities
     #[serde(default)]
     pub target_inventory_type: Option<ResourceInventoryType>,
+    // :SHONK: internal field used to reference the recorded event from the resource revisions it causes
+    #[serde(default)]
+    pub recorded_event: Option<EconomicEventAddress>,
 }
 
 impl<'a> CreateRequest {
@@ -258,6 +289,13 @@ impl<'a> CreateRequest {
         }
     }
 
+    pub fn with_recorded_event(&self, event_address: &EconomicEventAddress) -> Self {
+        CreateRequest {
+            recorded_event: Some(event_address.to_owned()),
+            ..self.to_owned()
+        }
+    }
+
     // accessors for field data
 
     pub fn get_action(&'a self) -> &str {
@@ -308,6 +346,15 @@ pub struct CreateParams {
     pub new_inventoried_resource: Option<ResourceCreateRequest>,
 }
 
+/// Parameters for recording a correction to a previously recorded event.
+/// `event` describes the corrected event in full, and replaces the event being corrected.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CorrectionParams {
+    pub corrects: EconomicEventAddress,
+    pub event: CreateRequest,
+}
+
 //---------------- UPDATE REQUEST ----------------
 
 /// I/O struct to describe the complete input record, including all managed links
@@ -341,6 +388,13 @@ pub struct UpdateParams {
     pub event: UpdateRequest,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub event: UpdateRequest,
+}
+
 //---------------- QUERY FILTER REQUEST ----------------
 
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
@@ -350,8 +404,41 @@ pub struct QueryParams {
     pub output_of: Option<ProcessAddress>,
     pub satisfies: Option<IntentAddress>,
     pub fulfills: Option<CommitmentAddress>,
+    pub settles: Option<SettlementAddress>,
+    pub triggers: Option<ClaimAddress>,
+    pub appreciated_by: Option<AppreciationAddress>,
+    pub appreciation: Option<AppreciationAddress>,
     pub realization_of: Option<AgreementAddress>,
+    pub at_location: Option<LocationAddress>,
     pub affects: Option<EconomicResourceAddress>,
     pub provider: Option<AgentAddress>,
     pub receiver: Option<AgentAddress>,
+    pub corrects: Option<EconomicEventAddress>,
+    pub corrected_by: Option<EconomicEventAddress>,
+}
+
+//---------------- TRACK & TRACE ----------------
+
+/// Parameters for the `trace_*` & `track_*` APIs, which follow economic flows from the record at `address`.
+/// Flows are followed for up to `max_depth` hops. `first` events are returned at a time, nearest first;
+/// pass the last event of a page as `after` to read the next page.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceParams<A> {
+    pub address: A,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    #[serde(default)]
+    pub first: Option<usize>,
+    #[serde(default)]
+    pub after: Option<EconomicEventAddress>,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResults {
+    pub events: Vec<EconomicEventAddress>,
+    // resources passed through on the way to the events in this page
+    pub resources: Vec<EconomicResourceAddress>,
+    pub has_next_page: bool,
 }
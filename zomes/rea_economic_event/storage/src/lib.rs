@@ -50,8 +50,11 @@ pub struct EconomicEventZomeConfig {
     pub economic_resource_zome: Option<String>,
     pub economic_resource_index_zome: Option<String>,
     pub process_index_zome: Option<String>,
+    // zome ID of a `Process` zome which should be notified to recompute process sequencing when flows change.
+    pub process_zome: Option<String>,
     pub agreement_index_zome: Option<String>,
     pub agent_index_zome: Option<String>,
+    pub location_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -78,10 +81,32 @@ pub struct EntryData {
     pub triggered_by: Option<EconomicEventAddress>,
     pub in_scope_of: Option<Vec<String>>,
     pub note: Option<String>,
+    #[serde(default)]
+    pub corrects: Option<EconomicEventAddress>,
     pub _nonce: Bytes,
 }
 
 impl EntryData {
+    /// The time at which this event took effect- its point in time if it has one, otherwise
+    /// the time it ended or else began.
+    pub fn get_event_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.has_point_in_time.or(self.has_end).or(self.has_beginning)
+    }
+
+    /// The resource this event drew upon or created, given the resources it `affects`.
+    ///
+    /// Events which create a resource are written before it, and so only reference the
+    /// new resource via the `affects` index. Corrections never create resources, and
+    /// the resources they affect include those of the event being corrected.
+    pub fn get_inventoried_resource(&self, affected_resources: &[EconomicResourceAddress]) -> Option<EconomicResourceAddress> {
+        if self.resource_inventoried_as.is_some() || self.corrects.is_some() {
+            return self.resource_inventoried_as.to_owned();
+        }
+        affected_resources.iter()
+            .find(|resource| self.to_resource_inventoried_as.as_ref() != Some(*resource))
+            .cloned()
+    }
+
     pub fn validate_action(&self) -> Result<(), String> {
         let result = validate_flow_action(self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned());
         if result.is_ok() && self.action.as_ref() == "move" {
@@ -171,11 +196,46 @@ impl TryFrom<CreateRequest> for EntryData {
             triggered_by: e.triggered_by.into(),
             at_location: e.at_location.into(),
             in_scope_of: e.in_scope_of.into(),
+            corrects: e.corrects.into(),
             _nonce: random_bytes(32)?,
         })
     }
 }
 
+/**
+ * Recover the input record from a stored DHT entry, for replaying the event's effects
+ * against inventoried resources. No inventory type or recorded event is assigned.
+ */
+impl From<EntryData> for CreateRequest {
+    fn from(e: EntryData) -> CreateRequest {
+        CreateRequest {
+            action: e.action,
+            note: e.note.into(),
+            input_of: e.input_of.into(),
+            output_of: e.output_of.into(),
+            provider: e.provider,
+            receiver: e.receiver,
+            resource_inventoried_as: e.resource_inventoried_as.into(),
+            to_resource_inventoried_as: e.to_resource_inventoried_as.into(),
+            resource_classified_as: e.resource_classified_as.into(),
+            resource_conforms_to: e.resource_conforms_to.into(),
+            resource_quantity: e.resource_quantity.into(),
+            effort_quantity: e.effort_quantity.into(),
+            has_beginning: e.has_beginning.into(),
+            has_end: e.has_end.into(),
+            has_point_in_time: e.has_point_in_time.into(),
+            at_location: e.at_location.into(),
+            agreed_in: e.agreed_in.into(),
+            realization_of: e.realization_of.into(),
+            triggered_by: e.triggered_by.into(),
+            in_scope_of: e.in_scope_of.into(),
+            corrects: e.corrects.into(),
+            target_inventory_type: None,
+            recorded_event: None,
+        }
+    }
+}
+
 //---------------- UPDATE ----------------
 
 /// Handles update operations by merging any newly provided fields into
@@ -202,7 +262,9 @@ impl Updateable<UpdateRequest> for EntryData {
             at_location: self.at_location.to_owned(),
             in_scope_of: if e.in_scope_of== MaybeUndefined::Undefined { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
             note: if e.note== MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
+            corrects: self.corrects.to_owned(),
             _nonce: self._nonce.to_owned(),
         })
     }
 }
+
@@ -9,3 +9,5 @@ pub const EVENT_ENTRY_TYPE: &str = "economic_event";
 
 pub const INVENTORY_CREATION_API_METHOD: &str = "_internal_create_inventory";
 pub const INVENTORY_UPDATE_API_METHOD: &str = "_internal_update_inventory";
+pub const PROCESS_SEQUENCE_SYNC_API_METHOD: &str = "sync_process_sequence";
+pub const COMMITMENT_INVOLVED_AGENTS_SYNC_API_METHOD: &str = "sync_commitment_involved_agents";
@@ -8,10 +8,18 @@ pub trait API {
     fn create_economic_event(entry_def_id: Self::S,
         event: CreateRequest, new_inventoried_resource: Option<ResourceCreateRequest>
     ) -> RecordAPIResult<ResponseData>;
+    fn correct_economic_event(entry_def_id: Self::S, corrects: EconomicEventAddress, event: CreateRequest) -> RecordAPIResult<ResponseData>;
     fn get_economic_event(address: EconomicEventAddress) -> RecordAPIResult<ResponseData>;
     fn get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>;
+    fn get_revision_history(page: RevisionHistoryParams<EconomicEventAddress>) -> RecordAPIResult<RevisionHistory>;
+    fn diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>;
     fn update_economic_event(event: UpdateRequest) -> RecordAPIResult<ResponseData>;
-    fn delete_economic_event(revision_id: ActionHash) -> RecordAPIResult<bool>;
+    fn resolve_economic_event_conflict(conflicting_revisions: Vec<ActionHash>, event: UpdateRequest) -> RecordAPIResult<ResponseData>;
+    fn delete_economic_event(entry_def_id: Self::S, revision_id: ActionHash) -> RecordAPIResult<bool>;
+    fn trace_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults>;
+    fn track_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults>;
+    fn trace_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults>;
+    fn track_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults>;
 }
 
 /// Macro to programatically and predictably bind an `API` implementation to a
@@ -31,6 +39,14 @@ macro_rules! declare_economic_event_zome_api {
             )?)
         }
 
+        #[hdk_extern]
+        fn correct_economic_event(CorrectionParams { corrects, event }: CorrectionParams) -> ExternResult<ResponseData> {
+            Ok(<$zome_api>::correct_economic_event(
+                EVENT_ENTRY_TYPE,
+                corrects, event,
+            )?)
+        }
+
         #[hdk_extern]
         fn get_economic_event(ByAddress { address }: ByAddress<EconomicEventAddress>) -> ExternResult<ResponseData> {
             Ok(<$zome_api>::get_economic_event(address)?)
@@ -41,14 +57,50 @@ macro_rules! declare_economic_event_zome_api {
             Ok(<$zome_api>::get_revision(revision_id)?)
         }
 
+        #[hdk_extern]
+        fn get_revision_history(page: RevisionHistoryParams<EconomicEventAddress>) -> ExternResult<RevisionHistory> {
+            Ok(<$zome_api>::get_revision_history(page)?)
+        }
+
+        #[hdk_extern]
+        fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+            Ok(<$zome_api>::diff_revisions(from_revision_id, to_revision_id)?)
+        }
+
         #[hdk_extern]
         fn update_economic_event(UpdateParams { event }: UpdateParams) -> ExternResult<ResponseData> {
             Ok(<$zome_api>::update_economic_event(event)?)
         }
 
+        #[hdk_extern]
+        fn resolve_economic_event_conflict(ResolveConflictParams { conflicting_revisions, event }: ResolveConflictParams) -> ExternResult<ResponseData> {
+            Ok(<$zome_api>::resolve_economic_event_conflict(conflicting_revisions, event)?)
+        }
+
         #[hdk_extern]
         fn delete_economic_event(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-            Ok(<$zome_api>::delete_economic_event(revision_id)?)
+            Ok(<$zome_api>::delete_economic_event(EVENT_ENTRY_TYPE, revision_id)?)
+        }
+
+        #[hdk_extern]
+        fn trace_economic_event(params: TraceParams<EconomicEventAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::trace_economic_event(params)?)
+        }
+
+        #[hdk_extern]
+        fn track_economic_event(params: TraceParams<EconomicEventAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::track_economic_event(params)?)
+        }
+
+        // Processes are traced via the events which flow through them, which are managed by this zome.
+        #[hdk_extern]
+        fn trace_process(params: TraceParams<ProcessAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::trace_process(params)?)
+        }
+
+        #[hdk_extern]
+        fn track_process(params: TraceParams<ProcessAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::track_process(params)?)
         }
     };
 }
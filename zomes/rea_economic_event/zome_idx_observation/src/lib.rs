@@ -8,12 +8,20 @@ use hdk_semantic_indexes_zome_derive::index_zome;
 use hc_zome_rea_economic_event_rpc::*;
 
 #[index_zome]
+#[time_index(has_point_in_time, has_beginning, has_end)]
 struct EconomicEvent {
     input_of: Local<process, observed_inputs>,
     output_of: Local<process, observed_outputs>,
     realization_of: Local<agreement, economic_events>,
     satisfies: Local<satisfaction, satisfied_by>,
     fulfills: Local<fulfillment, fulfilled_by>,
+    settles: Local<settlement, settled_by>,
+    triggers: Remote<claim, triggered_by>,
+    appreciated_by: Local<appreciation, appreciation_of>,
+    appreciation: Local<appreciation, appreciation_with>,
+    at_location: Local<location, economic_events>,
+    corrects: Local<economic_event, corrected_by>,
+    corrected_by: Local<economic_event, corrects>,
 
     // internal indexes (not part of REA spec)
     affects: Local<economic_resource, affected_by>,
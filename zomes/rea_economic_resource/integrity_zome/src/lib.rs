@@ -9,7 +9,9 @@
  */
 use hdi::prelude::*;
 use hc_zome_rea_economic_resource_storage::Identified;
-use hc_zome_rea_economic_resource_storage::{EntryStorage, EntryTypes, EntryTypesUnit, LinkTypes};
+use hc_zome_rea_economic_resource_storage::{EntryData, EntryStorage, EntryTypes, EntryTypesUnit, LinkTypes};
+use hc_zome_rea_economic_event_storage::{EntryData as EventData, EntryStorage as EventStorage};
+use vf_attributes_hdk::EconomicEventAddress;
 
 #[hdk_extern]
 pub fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
@@ -31,10 +33,11 @@ pub fn __num_link_types() -> u8 {
 }
 
 /// Macro to generate a default (permissable) validation function for EconomicResource
-/// records in the local zome (local field checks only).
+/// records in the local zome.
 /// This is the minimum validation required by any zome, and regardless of other
 /// validation rules being implemented it is critical that `record.validate()`
-/// be peformed upon `EntryStorage` creation.
+/// be peformed upon `EntryStorage` creation, and that any change in resource
+/// quantities be checked against the EconomicEvent causing it.
 ///
 /// Use this method as a reference, and always call the below logic before any
 /// application-specific validation.
@@ -45,7 +48,7 @@ macro_rules! declare_economic_resource_zome_validation_defaults {
         fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             match op {
                 Op::StoreRecord { .. } => Ok(ValidateCallbackResult::Valid),
-                Op::StoreEntry(StoreEntry { entry, .. }) => validate_entry(entry),
+                Op::StoreEntry(StoreEntry { action, entry }) => validate_entry(&action.hashed.content, entry),
                 Op::RegisterCreateLink { .. } => Ok(ValidateCallbackResult::Valid),
                 Op::RegisterDeleteLink { .. } => Ok(ValidateCallbackResult::Valid),
                 Op::RegisterUpdate { .. } => Ok(ValidateCallbackResult::Valid),
@@ -54,18 +57,60 @@ macro_rules! declare_economic_resource_zome_validation_defaults {
             }
         }
 
-        fn validate_entry(entry: Entry) -> ExternResult<ValidateCallbackResult> {
+        fn validate_entry(action: &EntryCreationAction, entry: Entry) -> ExternResult<ValidateCallbackResult> {
             match EntryStorage::try_from(&entry) {
                 Ok(resource_storage) => {
                     let record = resource_storage.entry();
-                    record
-                        .validate()
-                        .and_then(|()| Ok(ValidateCallbackResult::Valid))
-                        .or_else(|e| Ok(ValidateCallbackResult::Invalid(e)))
+                    if let Err(e) = record.validate() {
+                        return Ok(ValidateCallbackResult::Invalid(e));
+                    }
+                    validate_event_effects(action, &resource_storage)
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
+
+        /// Resource quantities may only be set by the EconomicEvent referenced in the revision
+        /// which changes them.
+        fn validate_event_effects(action: &EntryCreationAction, resource_storage: &EntryStorage) -> ExternResult<ValidateCallbackResult> {
+            let record = resource_storage.entry();
+            let event = match &record.last_event {
+                Some(event_id) => Some(read_event(event_id)?),
+                None => None,
+            };
+
+            let result = match action {
+                EntryCreationAction::Create(_) => match &event {
+                    Some(event) => record.validate_created_quantities(event),
+                    None => Err("EconomicResource can only be created by an EconomicEvent".to_string()),
+                },
+                EntryCreationAction::Update(Update { original_entry_address, .. }) => {
+                    let resource_id = resource_storage.identity()?;
+                    let original_hash: &EntryHash = resource_id.as_ref();
+                    let original = read_resource(original_hash)?;
+                    let previous = read_resource(original_entry_address)?;
+                    let corrected_event = match event.as_ref().and_then(|e| e.corrects.to_owned()) {
+                        Some(corrected_id) => Some(read_event(&corrected_id)?),
+                        None => None,
+                    };
+                    record.validate_updated_quantities(&resource_id, &original, &previous, event.as_ref(), corrected_event.as_ref())
+                },
+            };
+
+            Ok(match result {
+                Ok(()) => ValidateCallbackResult::Valid,
+                Err(e) => ValidateCallbackResult::Invalid(e),
+            })
+        }
+
+        fn read_resource(entry_hash: &EntryHash) -> ExternResult<EntryData> {
+            Ok(EntryStorage::try_from(&must_get_entry(entry_hash.to_owned())?.content)?.entry())
+        }
+
+        fn read_event(event_id: &EconomicEventAddress) -> ExternResult<EventData> {
+            let event_hash: &EntryHash = event_id.as_ref();
+            Ok(EventStorage::try_from(&must_get_entry(event_hash.to_owned())?.content)?.entry())
+        }
     };
 }
 
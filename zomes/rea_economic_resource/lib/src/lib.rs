@@ -7,26 +7,36 @@
  * @package hREA
  */
 use paste::paste;
+use hdk::prelude::Action;
 use hdk_records::{
     DataIntegrityError, RecordAPIResult, MaybeUndefined,
+    record_interface::Updateable,
     records::{
         get_latest_action_hash,
         create_record,
         read_record_entry,
         read_record_entry_by_action,
+        read_record_entry_original,
+        read_record_revision_history,
+        diff_revisions,
         update_record,
+        update_or_resolve_record,
     },
-    metadata::read_revision_metadata_abbreviated,
-    EntryHash, SignedActionHashed,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+    EntryHash, SignedActionHashed, RevisionMeta,
 };
 use hdk_semantic_indexes_client_lib::*;
 
+pub mod track_trace;
+
 use vf_attributes_hdk::{
     EconomicResourceAddress,
     EconomicEventAddress,
     ActionId,
     ProcessSpecificationAddress,
+    DateTime, FixedOffset,
 };
+use vf_measurement::QuantityValue;
 
 pub use hc_zome_rea_economic_resource_storage_consts::*;
 pub use hc_zome_rea_economic_event_storage_consts::{EVENT_ENTRY_TYPE};
@@ -40,7 +50,11 @@ use hc_zome_rea_process_storage::{
     EntryData as ProcessData,
     EntryStorage as ProcessStorage,
 };
-use hc_zome_rea_economic_event_storage::{EntryData as EventData, EntryStorage as EventStorage};
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData,
+    EntryStorage as EventStorage,
+    DnaConfigSlice as EventDnaConfigSlice,
+};
 use hc_zome_rea_economic_event_rpc::{
     ResourceResponse as Response,
     ResourceResponseData as ResponseData,
@@ -100,6 +114,14 @@ impl API for EconomicResourceZomePermissableDefault {
             let e = create_index!(economic_resource.primary_accountable(&event_params.receiver), agent.inventoried_economic_resources(&base_address));
             hdk::prelude::debug!("create_inventory_from_event::new_inventoried_resource::primary_accountable index {:?}", e);
         }
+        if let Some(current_location) = &entry_resp.current_location {
+            let e = create_index!(economic_resource.current_location(current_location), location.economic_resources(&base_address));
+            hdk::prelude::debug!("create_inventory_from_event::current_location index {:?}", e);
+        }
+        if let Some(lot) = &entry_resp.lot {
+            let e = create_index!(economic_resource.lot(lot), product_batch.economic_resources(&base_address));
+            hdk::prelude::debug!("create_inventory_from_event::lot index {:?}", e);
+        }
 
         Ok((meta, base_address, entry_resp))
     }
@@ -107,72 +129,127 @@ impl API for EconomicResourceZomePermissableDefault {
     fn get_economic_resource(address: EconomicResourceAddress) -> RecordAPIResult<ResponseData>
     {
         let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-        construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)
+        let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+        response.economic_resource.meta = read_revision_metadata_full(&meta)?;
+        Ok(response)
     }
 
     fn get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
     {
         let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-        construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+        let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+        response.economic_resource.meta = read_revision_metadata_full(&meta)?;
+        Ok(response)
+    }
+
+    fn get_revision_history(page: RevisionHistoryParams<EconomicResourceAddress>) -> RecordAPIResult<RevisionHistory>
+    {
+        read_record_revision_history(&page)
+    }
+
+    fn diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+    {
+        diff_revisions::<EntryData, EntryStorage, EconomicResourceAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+            Ok(construct_response(address, meta, entry, Default::default())?.economic_resource)
+        })
     }
 
     /// Handle update of resources by iterative reduction of event records over time.
     ///
+    /// Each affected resource receives a single revision applying all effects of the event,
+    /// including the reversal of any event it corrects.
+    ///
+    /// :WARNING: Should only ever be wired up as the dependency of an EconomicEvent zome.
+    ///
     fn update_inventory_from_event(
-        event: EventCreateRequest,
+        update: InventoryUpdate,
     ) -> RecordAPIResult<Vec<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)>>
     {
-        let mut resources_affected: Vec<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)> = vec![];
-
-        // if the event is a transfer-like event, run the receiver's update first
-        if let MaybeUndefined::Some(receiver_inventory) = &event.to_resource_inventoried_as {
-            let inv_entry_hash: &EntryHash = receiver_inventory.as_ref();
-
-            let (meta, resource_address, new_resource, prev_resource) = handle_update_inventory_resource(
-                &get_latest_action_hash(inv_entry_hash.clone())?,
-                event.with_inventory_type(ResourceInventoryType::ReceivingInventory),
-            )?;
-            resources_affected.push((meta, resource_address.clone(), new_resource.clone(), prev_resource.clone()));
-            if new_resource.primary_accountable != prev_resource.primary_accountable {
-                let new_value = if let Some(val) = &new_resource.primary_accountable { vec![val.to_owned()] } else { vec![] };
-                let prev_value = if let Some(val) = &prev_resource.primary_accountable { vec![val.to_owned()] } else { vec![] };
-                let e = update_index!(
-                    economic_resource
-                        .primary_accountable(new_value.as_slice())
-                        .not(prev_value.as_slice()),
-                    agent.inventoried_economic_resources(&resource_address));
-                hdk::prelude::debug!("update_economic_resource::to_resource_inventoried_as::primary_accountable index {:?}", e);
-            }
-        }
-        // after receiver, run provider. This entry data will be returned in the response.
-        if let MaybeUndefined::Some(provider_inventory) = &event.resource_inventoried_as {
-            let inv_entry_hash: &EntryHash = provider_inventory.as_ref();
-            resources_affected.push(handle_update_inventory_resource(
-                &get_latest_action_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Should error on mismatch and return latest valid ID
-                event.with_inventory_type(ResourceInventoryType::ProvidingInventory),
-            )?);
-        }
-
-        Ok(resources_affected)
+        update.get_affected_resources().iter()
+            .map(|resource_address| handle_update_inventory_resource(resource_address, update.effects_on(resource_address)))
+            .collect()
     }
 
     fn update_economic_resource(resource: UpdateRequest) -> RecordAPIResult<ResponseData>
     {
-        let address = resource.get_revision_id().clone();
-        let (meta, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_record(&address, resource)?;
-
-        // :TODO: issue #192
-        // https://github.com/h-REA/hREA/issues/192
-        if entry.contained_in != prev_entry.contained_in {
-            let now_contained = if let Some(contained) = &entry.contained_in { vec![contained.clone()] } else { vec![] };
-            let prev_contained = if let Some(contained) = &prev_entry.contained_in { vec![contained.clone()] } else { vec![] };
-            let e = update_index!(economic_resource(&identity_address).contained_in(now_contained.as_slice()).not(prev_contained.as_slice()));
-            hdk::prelude::debug!("update_economic_resource::contained_in index {:?}", e);
+        update_or_resolve_economic_resource(None, resource)
+    }
+
+    fn resolve_economic_resource_conflict(conflicting_revisions: Vec<ActionHash>, resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+    {
+        update_or_resolve_economic_resource(Some(&conflicting_revisions), resource)
+    }
+
+    /// Reconstruct the state of a resource at some earlier time, by replaying only those events
+    /// affecting it which took effect before `as_of` over the resource as originally created.
+    ///
+    /// Resources did not exist before the event which created them took effect, and cannot be
+    /// read as of any earlier time.
+    ///
+    fn get_economic_resource_as_of(address: EconomicResourceAddress, as_of: DateTime<FixedOffset>) -> RecordAPIResult<ResponseData>
+    {
+        let (meta, base_address, original) = read_record_entry_original::<EntryData, EntryStorage, _>(address.as_ref())?;
+        let created_at = match &original.last_event {
+            Some(creating_event) => {
+                let (event_meta, _, event) = read_economic_event(creating_event)?;
+                get_effective_time(&event_meta, &event)
+            },
+            None => RevisionMeta::from(&meta).time.into(),
+        };
+        if created_at >= as_of {
+            return Err(DataIntegrityError::EntryNotFound);
         }
 
+        let (_, entry) = replay_affecting_events(&base_address, &original, Some(as_of))?;
+
+        let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+        response.economic_resource.reconstructed_as_of = Some(as_of);
+        Ok(response)
+    }
+
+    /// Recompute the quantities of a resource by replaying all the events which affected it,
+    /// reporting any drift in the stored totals and optionally correcting it.
+    ///
+    /// Quantities can only be changed by EconomicEvents, and `update_inventory_from_event` applies
+    /// each event once. Drift is therefore expected to be caused by affecting events whose effects
+    /// were never applied to the resource, such as those applied to another branch of a conflicted
+    /// history. If `apply` is set, a `QuantityCorrection` revision is written for each of these.
+    ///
+    fn recalculate_economic_resource(address: EconomicResourceAddress, apply: bool) -> RecordAPIResult<RecalculationReport>
+    {
+        let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+        let (events_replayed, replayed) = replay_affecting_events(&base_address, &entry, None)?;
+        let (accounting_quantity, onhand_quantity) = (replayed.accounting_quantity, replayed.onhand_quantity);
+
+        let is_consistent = accounting_quantity == entry.accounting_quantity && onhand_quantity == entry.onhand_quantity;
+
+        let corrected_revision_id = if apply && !is_consistent {
+            apply_missing_events(&base_address, &meta, &entry)?
+        } else {
+            None
+        };
+
+        Ok(RecalculationReport {
+            id: base_address,
+            revision_id: meta.as_hash().to_owned(),
+            events_replayed,
+            stored_accounting_quantity: entry.accounting_quantity,
+            stored_onhand_quantity: entry.onhand_quantity,
+            calculated_accounting_quantity: accounting_quantity,
+            calculated_onhand_quantity: onhand_quantity,
+            is_consistent,
+            corrected_revision_id,
+        })
+    }
+
+    fn trace_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults>
+    {
+        track_trace::trace_economic_resource(params)
+    }
 
-        // :TODO: optimise this- should pass results from `replace_direct_index` instead of retrieving from `get_link_fields` where updates
-        construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
+    fn track_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults>
+    {
+        track_trace::track_economic_resource(params)
     }
 }
 
@@ -186,12 +263,145 @@ fn read_resource_specification_index_zome(conf: DnaConfigSlice) -> Option<String
     conf.economic_resource.resource_specification_index_zome
 }
 
+/// Properties accessor for zome config
+fn read_location_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.economic_resource.location_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_product_batch_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.economic_resource.product_batch_index_zome
+}
+
+fn update_or_resolve_economic_resource(conflicting_revisions: Option<&[ActionHash]>, resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let address = resource.get_revision_id().clone();
+    let (meta, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, resource)?;
+
+    // :TODO: issue #192
+    // https://github.com/h-REA/hREA/issues/192
+    update_container_index(&identity_address, &entry, &prev_entry);
+
+
+    // :TODO: optimise this- should pass results from `replace_direct_index` instead of retrieving from `get_link_fields` where updates
+    construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
+}
+
+/// Move a resource between containers when an update or event has changed its `contained_in`
+fn update_container_index(resource_address: &EconomicResourceAddress, new_resource: &EntryData, prev_resource: &EntryData) {
+    if new_resource.contained_in == prev_resource.contained_in {
+        return;
+    }
+    let now_contained = if let Some(contained) = &new_resource.contained_in { vec![contained.clone()] } else { vec![] };
+    let prev_contained = if let Some(contained) = &prev_resource.contained_in { vec![contained.clone()] } else { vec![] };
+    let e = update_index!(economic_resource(resource_address).contained_in(now_contained.as_slice()).not(prev_contained.as_slice()));
+    hdk::prelude::debug!("update_economic_resource::contained_in index {:?}", e);
+}
+
+/// Move a resource between `Location` indexes when an event has changed its `current_location`
+fn update_location_index(resource_address: &EconomicResourceAddress, new_resource: &EntryData, prev_resource: &EntryData) {
+    if new_resource.current_location == prev_resource.current_location {
+        return;
+    }
+    let new_value = if let Some(val) = &new_resource.current_location { vec![val.to_owned()] } else { vec![] };
+    let prev_value = if let Some(val) = &prev_resource.current_location { vec![val.to_owned()] } else { vec![] };
+    let e = update_index!(
+        economic_resource
+            .current_location(new_value.as_slice())
+            .not(prev_value.as_slice()),
+        location.economic_resources(resource_address));
+    hdk::prelude::debug!("update_economic_resource::current_location index {:?}", e);
+}
+
+/// Apply the effects of an event to the latest revision of a resource, moving the resource
+/// between any indexes affected by the update.
+///
 fn handle_update_inventory_resource(
-    resource_addr: &ActionHash,
-    event: EventCreateRequest,
+    resource_address: &EconomicResourceAddress,
+    effects: EventEffects,
 ) -> RecordAPIResult<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)>
 {
-    Ok(update_record(resource_addr, event)?)
+    let inv_entry_hash: &EntryHash = resource_address.as_ref();
+    let (meta, resource_address, new_resource, prev_resource): (_, EconomicResourceAddress, EntryData, EntryData) = update_record(
+        &get_latest_action_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Should error on mismatch and return latest valid ID
+        effects,
+    )?;
+
+    if new_resource.primary_accountable != prev_resource.primary_accountable {
+        let new_value = if let Some(val) = &new_resource.primary_accountable { vec![val.to_owned()] } else { vec![] };
+        let prev_value = if let Some(val) = &prev_resource.primary_accountable { vec![val.to_owned()] } else { vec![] };
+        let e = update_index!(
+            economic_resource
+                .primary_accountable(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            agent.inventoried_economic_resources(&resource_address));
+        hdk::prelude::debug!("update_economic_resource::primary_accountable index {:?}", e);
+    }
+    update_location_index(&resource_address, &new_resource, &prev_resource);
+    update_container_index(&resource_address, &new_resource, &prev_resource);
+
+    Ok((meta, resource_address, new_resource, prev_resource))
+}
+
+/// Write a `QuantityCorrection` for each event affecting a resource which is not referenced by
+/// any revision in the history leading up to its latest revision. Returns the ID of the last
+/// corrective revision written, if any.
+///
+/// Missing events are applied in the order they took effect, along with the reversal of any
+/// event they correct.
+///
+fn apply_missing_events(
+    resource: &EconomicResourceAddress,
+    latest_meta: &SignedActionHashed,
+    latest: &EntryData,
+) -> RecordAPIResult<Option<ActionHash>>
+{
+    let applied_events = read_applied_events(latest_meta)?;
+    let mut revision_id = latest_meta.as_hash().to_owned();
+    let mut entry = latest.to_owned();
+    let mut corrected_revision_id = None;
+
+    for (event_address, _, event) in read_affecting_events(resource)? {
+        if applied_events.contains(&event_address) {
+            continue;
+        }
+        let corrected_event = match &event.corrects {
+            Some(corrected) => Some(read_economic_event(corrected)?.2.into()),
+            None => None,
+        };
+        let effects = InventoryUpdate {
+            event: EventCreateRequest::from(event).with_recorded_event(&event_address),
+            corrected_event,
+        }.effects_on(resource);
+        let applied = entry.update_with(effects)?;
+
+        let (meta, _, new_entry, _): (_, EconomicResourceAddress, EntryData, EntryData) = update_record(&revision_id, QuantityCorrection {
+            accounting_quantity: applied.accounting_quantity,
+            onhand_quantity: applied.onhand_quantity,
+            event: event_address,
+        })?;
+        revision_id = meta.as_hash().to_owned();
+        entry = new_entry;
+        corrected_revision_id = Some(revision_id.to_owned());
+    }
+
+    Ok(corrected_revision_id)
+}
+
+/// Read the EconomicEvents referenced by the given revision of a resource and all revisions preceding it
+fn read_applied_events(revision: &SignedActionHashed) -> RecordAPIResult<Vec<EconomicEventAddress>>
+{
+    let mut applied_events = vec![];
+    let mut next = Some(revision.as_hash().to_owned());
+    while let Some(revision_id) = next {
+        let (meta, _, entry) = read_record_entry_by_action::<EntryData, EntryStorage, EconomicResourceAddress>(&revision_id)?;
+        applied_events.extend(entry.last_event);
+        next = match meta.action() {
+            Action::Update(update) => Some(update.original_action_address.to_owned()),
+            _ => None,
+        };
+    }
+    Ok(applied_events)
 }
 
 /// Create response from input DHT primitives
@@ -246,6 +456,7 @@ pub fn construct_response_record<'a>(
         current_location: e.current_location.to_owned(),
         note: e.note.to_owned(),
         primary_accountable: e.primary_accountable.to_owned(),
+        reconstructed_as_of: None,
 
         // link fields
         contained_in: contained_in.to_owned(),
@@ -343,3 +554,104 @@ fn get_affecting_events(resource: &EconomicResourceAddress) -> RecordAPIResult<V
 {
     read_index!(economic_resource(resource).affected_by)
 }
+
+/// Replay the EconomicEvents affecting a given EconomicResource over some initial state of the
+/// resource, in the order they took effect. Returns the IDs of the replayed events along with
+/// the resulting resource state.
+///
+/// Each event is applied via the same `Updateable<EventCreateRequest>` logic as
+/// `update_inventory_from_event`, starting from zeroed quantities in the units of the resource.
+///
+/// If `until` is given, only events which took effect before that time are replayed. Corrected
+/// events are then skipped in favour of their corrections, provided the correction also took
+/// effect before `until`; otherwise the event is replayed as originally recorded.
+///
+fn replay_affecting_events(
+    resource: &EconomicResourceAddress,
+    initial: &EntryData,
+    until: Option<DateTime<FixedOffset>>,
+) -> RecordAPIResult<(Vec<EconomicEventAddress>, EntryData)>
+{
+    let mut events = read_affecting_events(resource)?;
+
+    if let Some(cutoff) = until {
+        events.retain(|(_, event_time, _)| *event_time < cutoff);
+    }
+
+    // corrected events are superseded by their corrections, which are replayed in their place
+    let corrected: Vec<EconomicEventAddress> = events.iter()
+        .filter_map(|(_, _, e)| e.corrects.to_owned())
+        .collect();
+    events.retain(|(event_address, _, _)| !corrected.contains(event_address));
+
+    let resource_unit = initial.accounting_quantity.as_ref()
+        .or(initial.onhand_quantity.as_ref())
+        .and_then(|q| q.get_unit());
+    let mut entry = EntryData {
+        accounting_quantity: initial.accounting_quantity.as_ref().map(|q| QuantityValue::new(0.0, q.get_unit())),
+        onhand_quantity: initial.onhand_quantity.as_ref().map(|q| QuantityValue::new(0.0, q.get_unit())),
+        ..initial.to_owned()
+    };
+
+    for (_, _, event) in events.iter() {
+        let mut replayed_event: EventCreateRequest = event.to_owned().into();
+
+        // as on resource creation, event quantities without units are taken to be in the resource's units
+        if let MaybeUndefined::Some(qty) = &replayed_event.resource_quantity {
+            if qty.get_unit().is_none() {
+                replayed_event.resource_quantity = MaybeUndefined::Some(QuantityValue::new(qty.get_numerical_value(), resource_unit.to_owned()));
+            }
+        }
+
+        // sides of the flow are applied in the same order as in `update_inventory_from_event`
+        for inventory_type in get_inventory_types(resource, &replayed_event) {
+            entry = entry.update_with(replayed_event.with_inventory_type(inventory_type))?;
+        }
+    }
+
+    Ok((events.into_iter().map(|(event_address, _, _)| event_address).collect(), entry))
+}
+
+/// Read the EconomicEvents affecting a given EconomicResource, along with the time each took
+/// effect, in that order.
+///
+/// Events take effect at their event time (`hasPointInTime`, else `hasEnd`, else `hasBeginning`).
+/// Events with none of these set take effect when they were recorded, at the timestamp of
+/// the event's original action.
+///
+fn read_affecting_events(resource: &EconomicResourceAddress) -> RecordAPIResult<Vec<(EconomicEventAddress, DateTime<FixedOffset>, EventData)>>
+{
+    let mut events = get_affecting_events(resource)?.iter()
+        .map(|event| {
+            let (original_meta, event_address, event_entry) = read_economic_event(event)?;
+            let event_time = get_effective_time(&original_meta, &event_entry);
+            Ok((event_address, event_time, event_entry))
+        })
+        .collect::<RecordAPIResult<Vec<(EconomicEventAddress, DateTime<FixedOffset>, EventData)>>>()?;
+
+    events.sort_by_key(|(_, event_time, _)| *event_time);
+    Ok(events)
+}
+
+/// Determine the time an EconomicEvent took effect, given the action which originally recorded it
+fn get_effective_time(original_meta: &SignedActionHashed, event: &EventData) -> DateTime<FixedOffset>
+{
+    match event.get_event_time() {
+        Some(time) => time,
+        None => RevisionMeta::from(original_meta).time.into(),
+    }
+}
+
+/// Read an EconomicEvent as originally recorded, including the resource it created (if any)
+/// as its `resource_inventoried_as`
+pub(crate) fn read_economic_event(event: &EconomicEventAddress) -> RecordAPIResult<(SignedActionHashed, EconomicEventAddress, EventData)>
+{
+    let (meta, event_address, mut entry) = read_record_entry_original::<EventData, EventStorage, EconomicEventAddress>(event.as_ref())?;
+    entry.resource_inventoried_as = entry.get_inventoried_resource(&read_index!(economic_event(&event_address).affects)?);
+    Ok((meta, event_address, entry))
+}
+
+/// Properties accessor for zome config
+fn read_economic_event_index_zome(conf: EventDnaConfigSlice) -> Option<String> {
+    Some(conf.economic_event.index_zome)
+}
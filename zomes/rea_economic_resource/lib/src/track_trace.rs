@@ -0,0 +1,241 @@
+/**
+ * hREA track & trace algorithms
+ *
+ * Follows economic flows backwards (`trace`) or forwards (`track`) through the
+ * network of `EconomicResource`s, `EconomicEvent`s and `Process`es recorded in
+ * an observation DNA, as described by the ValueFlows specification.
+ *
+ * Traversal alternates between resources and processes via the events which connect them:
+ *
+ * - `trace` moves from a resource to the events which added to it; from output events
+ *   to the process which produced them; from a process to its observed inputs; and from
+ *   input or transfer events to the resource they drew upon.
+ * - `track` moves from a resource to the events which drew upon it; from input events
+ *   to the process which used them; from a process to its observed outputs; and from
+ *   output or transfer events to the resource they added to.
+ *
+ * Both directions are walked breadth-first, are limited to a maximum number of hops
+ * and skip any node already visited so that cyclic flows (eg. a resource repaired by
+ * a process which it is also an input to) terminate. Results are paginated, and
+ * traversal stops once a page of results has been found.
+ *
+ * @see https://www.valueflo.ws/algorithms/track/
+ * @package hREA
+ */
+use std::collections::{HashSet, VecDeque};
+use paste::paste;
+use hdk_records::RecordAPIResult;
+use hdk_semantic_indexes_client_lib::*;
+use vf_actions::{ActionEffect, get_builtin_action};
+use vf_attributes_hdk::{
+    EconomicResourceAddress,
+    EconomicEventAddress,
+    ProcessAddress,
+};
+
+use hc_zome_rea_economic_event_rpc::{TraceParams, TraceResults};
+use hc_zome_rea_economic_resource_storage::DnaConfigSlice;
+use hc_zome_rea_process_storage::DnaConfigSlice as ProcessDnaConfigSlice;
+use hc_zome_rea_economic_event_storage::EntryData as EventData;
+
+use crate::read_economic_event;
+
+/// Maximum number of hops followed when the caller does not specify a `max_depth`
+pub const DEFAULT_TRACE_DEPTH: usize = 32;
+
+/// Number of events returned per page when the caller does not specify `first`
+pub const DEFAULT_TRACE_PAGE_SIZE: usize = 30;
+
+/// Nodes in the flow graph which are visited during traversal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum FlowNode {
+    Resource(EconomicResourceAddress),
+    Event(EconomicEventAddress),
+    Process(ProcessAddress),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlowDirection {
+    Trace,
+    Track,
+}
+
+/// Return a page of the `EconomicEvent`s leading up to the current state of a resource, nearest first
+pub fn trace_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Resource(params.address.to_owned()), FlowDirection::Trace, &params)
+}
+
+/// Return a page of the `EconomicEvent`s flowing onward from a resource, nearest first
+pub fn track_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Resource(params.address.to_owned()), FlowDirection::Track, &params)
+}
+
+/// Return a page of the `EconomicEvent`s leading up to an event, nearest first
+pub fn trace_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Event(params.address.to_owned()), FlowDirection::Trace, &params)
+}
+
+/// Return a page of the `EconomicEvent`s flowing onward from an event, nearest first
+pub fn track_economic_event(params: TraceParams<EconomicEventAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Event(params.address.to_owned()), FlowDirection::Track, &params)
+}
+
+/// Return a page of the `EconomicEvent`s leading into a process, nearest first
+pub fn trace_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Process(params.address.to_owned()), FlowDirection::Trace, &params)
+}
+
+/// Return a page of the `EconomicEvent`s flowing onward from a process, nearest first
+pub fn track_process(params: TraceParams<ProcessAddress>) -> RecordAPIResult<TraceResults> {
+    walk_flows(FlowNode::Process(params.address.to_owned()), FlowDirection::Track, &params)
+}
+
+/// Breadth-first traversal of the flow graph from `start`, collecting events (and the resources
+/// between them) encountered within `max_depth` hops. The starting node itself is never included
+/// in the output.
+///
+/// Traversal stops as soon as a page of `first` events following the `after` cursor has been
+/// collected, so that the number of records read is bounded by the requested page.
+///
+fn walk_flows<A>(start: FlowNode, direction: FlowDirection, params: &TraceParams<A>) -> RecordAPIResult<TraceResults> {
+    let max_depth = params.max_depth.unwrap_or(DEFAULT_TRACE_DEPTH);
+    let page_size = params.first.unwrap_or(DEFAULT_TRACE_PAGE_SIZE);
+
+    let mut visited: HashSet<FlowNode> = HashSet::new();
+    let mut pending: VecDeque<(FlowNode, usize)> = VecDeque::new();
+    let mut events = vec![];
+    let mut resources = vec![];
+    // collect from the start of the traversal, or from the event following the cursor
+    let mut collecting = params.after.is_none();
+
+    visited.insert(start.to_owned());
+    pending.push_back((start, 0));
+
+    while let Some((node, depth)) = pending.pop_front() {
+        if depth >= max_depth {
+            continue;
+        }
+
+        for next in adjacent_nodes(&node, direction)? {
+            // already reached via some other path; don't loop around cyclic flows
+            if !visited.insert(next.to_owned()) {
+                continue;
+            }
+            match &next {
+                FlowNode::Event(event) => {
+                    if collecting {
+                        // one more event than requested indicates that another page exists
+                        if events.len() == page_size {
+                            return Ok(TraceResults { events, resources, has_next_page: true });
+                        }
+                        events.push(event.to_owned());
+                    } else if params.after.as_ref() == Some(event) {
+                        collecting = true;
+                    }
+                },
+                FlowNode::Resource(resource) if collecting => resources.push(resource.to_owned()),
+                _ => (),
+            }
+            pending.push_back((next, depth + 1));
+        }
+    }
+
+    Ok(TraceResults { events, resources, has_next_page: false })
+}
+
+/// Determine the set of nodes one hop away from `node` in the given `direction`
+///
+fn adjacent_nodes(node: &FlowNode, direction: FlowDirection) -> RecordAPIResult<Vec<FlowNode>> {
+    match node {
+        FlowNode::Resource(resource) => {
+            let affecting_events: Vec<EconomicEventAddress> = read_index!(economic_resource(resource).affected_by)?;
+            let mut nodes = vec![];
+            for event in affecting_events {
+                let (_meta, _base_address, entry) = read_economic_event(&event)?;
+                let is_adjacent = match direction {
+                    FlowDirection::Trace => is_inflow(resource, &entry),
+                    FlowDirection::Track => is_outflow(resource, &entry),
+                };
+                if is_adjacent {
+                    nodes.push(FlowNode::Event(event));
+                }
+            }
+            Ok(nodes)
+        },
+        FlowNode::Event(event) => {
+            let (_meta, _base_address, entry) = read_economic_event(event)?;
+            Ok(event_flows_to(&entry, direction).into_iter().collect())
+        },
+        FlowNode::Process(process) => {
+            let events: Vec<EconomicEventAddress> = match direction {
+                FlowDirection::Trace => read_index!(process(process).observed_inputs)?,
+                FlowDirection::Track => read_index!(process(process).observed_outputs)?,
+            };
+            Ok(events.into_iter().map(FlowNode::Event).collect())
+        },
+    }
+}
+
+/// Determine the node which an `event` leads to when moving in the given `direction`
+///
+fn event_flows_to(event: &EventData, direction: FlowDirection) -> Option<FlowNode> {
+    match direction {
+        FlowDirection::Trace => match (&event.output_of, &event.resource_inventoried_as) {
+            (Some(process), _) => Some(FlowNode::Process(process.to_owned())),
+            (None, Some(resource)) if event.input_of.is_some() || event.to_resource_inventoried_as.is_some() =>
+                Some(FlowNode::Resource(resource.to_owned())),
+            _ => None,  // events which create value from nothing (eg. `raise`) are the end of the line
+        },
+        FlowDirection::Track => match (&event.input_of, &event.to_resource_inventoried_as, &event.resource_inventoried_as) {
+            (Some(process), _, _) => Some(FlowNode::Process(process.to_owned())),
+            (None, Some(to_resource), _) => Some(FlowNode::Resource(to_resource.to_owned())),
+            (None, None, Some(resource)) if event.output_of.is_some() => Some(FlowNode::Resource(resource.to_owned())),
+            _ => None,  // events which remove value from the system (eg. `lower`) are the end of the line
+        },
+    }
+}
+
+/// Determine whether `event` added to `resource`, ie. is a step backwards from it in a `trace`
+///
+fn is_inflow(resource: &EconomicResourceAddress, event: &EventData) -> bool {
+    if event.to_resource_inventoried_as.as_ref() == Some(resource) {
+        return true;
+    }
+    event.resource_inventoried_as.as_ref() == Some(resource) && (
+        event.output_of.is_some() || (
+            event.input_of.is_none() &&
+            event.to_resource_inventoried_as.is_none() &&
+            get_accounting_effect(event) == Some(ActionEffect::Increment)
+        )
+    )
+}
+
+/// Determine whether `event` drew upon `resource`, ie. is a step forwards from it in a `track`
+///
+fn is_outflow(resource: &EconomicResourceAddress, event: &EventData) -> bool {
+    if event.resource_inventoried_as.as_ref() != Some(resource) || event.to_resource_inventoried_as.as_ref() == Some(resource) {
+        return false;
+    }
+    event.input_of.is_some() ||
+    event.to_resource_inventoried_as.is_some() || (
+        event.output_of.is_none() &&
+        match get_accounting_effect(event) {
+            Some(ActionEffect::Decrement) | Some(ActionEffect::DecrementIncrement) => true,
+            _ => false,
+        }
+    )
+}
+
+fn get_accounting_effect(event: &EventData) -> Option<ActionEffect> {
+    get_builtin_action(event.action.as_ref()).map(|action| action.accounting_effect)
+}
+
+/// Properties accessor for zome config
+fn read_economic_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.economic_resource.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_process_index_zome(conf: ProcessDnaConfigSlice) -> Option<String> {
+    Some(conf.process.index_zome)
+}
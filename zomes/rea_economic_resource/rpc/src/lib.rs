@@ -9,8 +9,11 @@
 use holochain_serialized_bytes::prelude::*;
 
 use serde_maybe_undefined::MaybeUndefined;
+use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     ActionHash, ByAddress, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     EconomicResourceAddress,
     EconomicEventAddress,
     ExternalURL,
@@ -19,6 +22,7 @@ pub use vf_attributes_hdk::{
     UnitId,
     ProductBatchAddress,
     AgentAddress,
+    DateTime, FixedOffset,
 };
 
 use hc_zome_rea_economic_event_rpc::{
@@ -93,6 +97,52 @@ pub struct UpdateParams {
     pub resource: UpdateRequest,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub resource: UpdateRequest,
+}
+
+//---------------- HISTORICAL READ REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AsOfParams {
+    pub address: EconomicResourceAddress,
+    pub as_of: DateTime<FixedOffset>,
+}
+
+//---------------- RECALCULATION REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecalculateParams {
+    pub address: EconomicResourceAddress,
+    /// if set, revisions applying the quantities of any events missing from the resource's history are written
+    #[serde(default)]
+    pub apply: bool,
+}
+
+/// Outcome of replaying the events affecting an EconomicResource and comparing the
+/// resulting quantities to those currently stored.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecalculationReport {
+    pub id: EconomicResourceAddress,
+    /// the revision whose stored quantities were checked
+    pub revision_id: ActionHash,
+    /// affecting events, in the order they were replayed
+    pub events_replayed: Vec<EconomicEventAddress>,
+    pub stored_accounting_quantity: Option<QuantityValue>,
+    pub stored_onhand_quantity: Option<QuantityValue>,
+    pub calculated_accounting_quantity: Option<QuantityValue>,
+    pub calculated_onhand_quantity: Option<QuantityValue>,
+    pub is_consistent: bool,
+    /// the last corrective revision, if `apply` was requested and the quantities mismatched
+    pub corrected_revision_id: Option<ActionHash>,
+}
+
 //---------------- QUERY FILTER REQUEST ----------------
 
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
@@ -101,6 +151,8 @@ pub struct QueryParams {
     pub contains: Option<EconomicResourceAddress>,
     pub contained_in: Option<EconomicResourceAddress>,
     pub conforms_to: Option<ResourceSpecificationAddress>,
+    pub current_location: Option<LocationAddress>,
+    pub lot: Option<ProductBatchAddress>,
     pub affected_by: Option<EconomicEventAddress>,
     pub primary_accountable: Option<AgentAddress>,
 }
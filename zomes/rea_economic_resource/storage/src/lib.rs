@@ -20,6 +20,7 @@ use hdk_records::{
 use vf_measurement::*;
 use vf_attributes_hdk::{
     EconomicResourceAddress,
+    EconomicEventAddress,
     ExternalURL,
     LocationAddress,
     ResourceSpecificationAddress,
@@ -28,7 +29,7 @@ use vf_attributes_hdk::{
     ActionId,
     AgentAddress,
 };
-use vf_actions::{ ActionEffect, ActionInventoryEffect};
+use vf_actions::{ ActionEffect, ActionInventoryEffect, ContainedEffect, LocationEffect };
 pub use vf_actions::get_builtin_action;
 use hc_zome_rea_resource_specification_rpc::{ResponseData as ResourceSpecificationResponseData, Response as ResourceSpecificationResponse};
 
@@ -37,6 +38,7 @@ use hc_zome_rea_economic_event_rpc::{
     CreateRequest as EventCreateRequest,
     ResourceInventoryType,
 };
+use hc_zome_rea_economic_event_storage::EntryData as EventData;
 
 // :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
 pub use hdk_records::record_interface::Identified;
@@ -54,6 +56,8 @@ pub struct EconomicResourceZomeConfig {
     pub index_zome: String,
     pub resource_specification_index_zome: Option<String>,
     pub agent_index_zome: Option<String>,
+    pub location_index_zome: Option<String>,
+    pub product_batch_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -73,6 +77,9 @@ pub struct EntryData {
     pub contained_in: Option<EconomicResourceAddress>,
     pub note: Option<String>,
     pub primary_accountable: Option<AgentAddress>,
+    /// the EconomicEvent whose effects were most recently applied to the resource
+    #[serde(default)]
+    pub last_event: Option<EconomicEventAddress>,
     pub _nonce: Bytes,
 }
 
@@ -83,6 +90,96 @@ impl EntryData {
         }
         Ok(())
     }
+
+    /// Validates that the quantities of a newly created resource are exactly those produced by
+    /// the `event` which created it.
+    pub fn validate_created_quantities(&self, event: &EventData) -> Result<(), String> {
+        let initial = EntryData {
+            accounting_quantity: self.accounting_quantity.as_ref().map(|q| QuantityValue::new(0.0, q.get_unit())),
+            onhand_quantity: self.onhand_quantity.as_ref().map(|q| QuantityValue::new(0.0, q.get_unit())),
+            ..self.to_owned()
+        };
+        // inventories can only be inited by their owners, ie. on the providing side of the event
+        let expected = event_quantities(&initial, &event.action, event.resource_quantity.to_owned(), ResourceInventoryType::ProvidingInventory)
+            .map_err(|e| e.to_string())?;
+
+        if expected != (self.accounting_quantity.to_owned(), self.onhand_quantity.to_owned()) {
+            return Err("EconomicResource quantities do not match those of the EconomicEvent which created it".into());
+        }
+        Ok(())
+    }
+
+    /// Validates that any change in quantities from the `previous` revision of a resource is exactly
+    /// the effect of the `event` referenced by this revision, including the reversal of any
+    /// `corrected_event` which it corrects.
+    ///
+    /// Each event may only change the quantities of a resource once. So that validation does not
+    /// grow with the length of the resource's history, this is checked against the events
+    /// referenced by the `previous` and `original` revisions only.
+    pub fn validate_updated_quantities(
+        &self,
+        resource_id: &EconomicResourceAddress,
+        original: &EntryData,
+        previous: &EntryData,
+        event: Option<&EventData>,
+        corrected_event: Option<&EventData>,
+    ) -> Result<(), String> {
+        let quantities = (self.accounting_quantity.to_owned(), self.onhand_quantity.to_owned());
+        if quantities == (previous.accounting_quantity.to_owned(), previous.onhand_quantity.to_owned()) {
+            return Ok(());
+        }
+        let (event_id, event) = match (&self.last_event, event) {
+            (Some(event_id), Some(event)) => (event_id, event),
+            _ => return Err("EconomicResource quantities can only be changed by an EconomicEvent".into()),
+        };
+        if previous.last_event.as_ref() == Some(event_id) || original.last_event.as_ref() == Some(event_id) {
+            return Err("EconomicResource quantities have already been changed by this EconomicEvent".into());
+        }
+
+        let event_request = EventCreateRequest::from(event.to_owned()).with_recorded_event(event_id);
+        let corrected_request = corrected_event.map(|corrected| EventCreateRequest::from(corrected.to_owned()));
+        let mut corrected_inventory_types = match &corrected_request {
+            Some(corrected) => get_inventory_types(resource_id, corrected),
+            None => vec![],
+        };
+        // events which create a resource are recorded before it, and so cannot reference it
+        if let (Some(corrected), Some(corrected_id)) = (corrected_event, &event.corrects) {
+            let created_by_corrected = original.last_event.as_ref() == Some(corrected_id) || previous.last_event.as_ref() == Some(corrected_id);
+            if corrected.resource_inventoried_as.is_none() && created_by_corrected {
+                corrected_inventory_types.push(ResourceInventoryType::ProvidingInventory);
+            }
+        }
+
+        let expected = previous.update_with(EventEffects {
+            inventory_types: get_inventory_types(resource_id, &event_request),
+            event: event_request,
+            corrected_event: corrected_request,
+            corrected_inventory_types,
+        }).map_err(|e| e.to_string())?;
+
+        if (expected.accounting_quantity, expected.onhand_quantity) != quantities {
+            return Err("EconomicResource quantities do not match the effect of the EconomicEvent which updated them".into());
+        }
+        Ok(())
+    }
+}
+
+/// Determines which sides of an event's flow a resource is on, in the order they are applied.
+///
+/// Events which place a resource in a container reference the container as their
+/// `to_resource_inventoried_as`, which is not a side of the flow.
+///
+pub fn get_inventory_types(resource_id: &EconomicResourceAddress, event: &EventCreateRequest) -> Vec<ResourceInventoryType> {
+    let mut inventory_types = vec![];
+    if event.to_resource_inventoried_as == MaybeUndefined::Some(resource_id.to_owned())
+        && get_contained_effect(&event.action) != ContainedEffect::Update
+    {
+        inventory_types.push(ResourceInventoryType::ReceivingInventory);
+    }
+    if event.resource_inventoried_as == MaybeUndefined::Some(resource_id.to_owned()) {
+        inventory_types.push(ResourceInventoryType::ProvidingInventory);
+    }
+    inventory_types
 }
 
 generate_record_entry!(EntryData, EconomicResourceAddress, EntryStorage);
@@ -186,10 +283,18 @@ impl TryFrom<CreationPayload> for EntryData {
                 None => None,
             },
             unit_of_effort,
-            current_location: if r.current_location == MaybeUndefined::Undefined { None } else { r.current_location.to_owned().to_option() },
+            current_location: match r.current_location.to_owned() {
+                // resources created by actions with a 'new' location effect are located where they were created, if not otherwise specified
+                MaybeUndefined::Undefined => match get_location_effect(&e.action) {
+                    LocationEffect::New => e.get_location().to_option(),
+                    _ => None,
+                },
+                current_location => current_location.to_option(),
+            },
             contained_in: if r.contained_in == MaybeUndefined::Undefined { None } else { r.contained_in.to_owned().to_option() },
             note: if r.note == MaybeUndefined::Undefined { None } else { r.note.clone().into() },
             primary_accountable: if action_id == produce_action.id || action_id == raise_action.id || action_id == lower_action.id { Some(e.receiver) } else { None },
+            last_event: e.recorded_event,
             _nonce: random_bytes(32)?,
         })
     }
@@ -275,6 +380,7 @@ impl Updateable<UpdateRequest> for EntryData {
             contained_in: if e.contained_in == MaybeUndefined::Undefined { self.contained_in.to_owned() } else { e.contained_in.to_owned().to_option() },
             note: if e.note == MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().to_option() },
             primary_accountable: self.primary_accountable.to_owned(),
+            last_event: self.last_event.to_owned(),
             _nonce: self._nonce.to_owned(),
         })
     }
@@ -285,8 +391,8 @@ impl Updateable<UpdateRequest> for EntryData {
 /// :WARNING: we presume the event has already been determined to relate to the
 /// resource, and this method will panic if that is not the case.
 ///
-/// Currently it is only called within `hdk_records::record_helpers::update_record`,
-/// where this check is already implicitly performed.
+/// Currently it is only applied via `EventEffects`, for the sides of the event's flow which
+/// the resource was determined to be on.
 ///
 impl Updateable<EventCreateRequest> for EntryData {
     fn update_with(&self, e: EventCreateRequest) -> RecordAPIResult<EntryData> {
@@ -331,14 +437,23 @@ impl Updateable<EventCreateRequest> for EntryData {
                 },
             )?,
             unit_of_effort: self.unit_of_effort.to_owned(), // :TODO: pull from e.resource_conforms_to.unit_of_effort
-            current_location: if e.get_action() == "move" {
-                if let MaybeUndefined::Some(at_location) = e.get_location() {
-                    Some(at_location)
-                } else {
-                    self.current_location.to_owned()
-                }
-            } else { self.current_location.to_owned() },
-            contained_in: self.contained_in.to_owned(),
+            current_location: match get_location_effect(&e.action) {
+                LocationEffect::Update => match e.get_location() {
+                    MaybeUndefined::Some(at_location) => Some(at_location),
+                    _ => self.current_location.to_owned(),
+                },
+                LocationEffect::Remove => None,
+                LocationEffect::New | LocationEffect::NoEffect => self.current_location.to_owned(),
+            },
+            contained_in: match get_contained_effect(&e.action) {
+                // the container is referenced as the event's `to_resource_inventoried_as`
+                ContainedEffect::Update => match &e.to_resource_inventoried_as {
+                    MaybeUndefined::Some(container) => Some(container.to_owned()),
+                    _ => self.contained_in.to_owned(),
+                },
+                ContainedEffect::Remove => None,
+                ContainedEffect::NoEffect => self.contained_in.to_owned(),
+            },
             note: self.note.to_owned(),
             // NOTE: this could be "dangerous" in the sense that if not validated properly, this ability to update via events could be abused by third party agents transferring rights and 'ownership' to themselves, from resources currently controlled/owned/stewarded by other agents
             // relates to transfer all rights but not custody
@@ -347,11 +462,157 @@ impl Updateable<EventCreateRequest> for EntryData {
             } else {
                 self.primary_accountable.to_owned()
             },
+            last_event: e.recorded_event.to_owned(),
             _nonce: self._nonce.to_owned(),
         })
     }
 }
 
+/// Payload for applying a newly recorded `event` to the inventories it affects. If the event
+/// corrects an earlier one, the `corrected_event` must also be provided so that its effects
+/// can be reversed, with any resource it created assigned as its `resource_inventoried_as`.
+///
+/// :WARNING: not for direct use- only to be constructed by an EconomicEvent zome.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct InventoryUpdate {
+    pub event: EventCreateRequest,
+    pub corrected_event: Option<EventCreateRequest>,
+}
+
+impl InventoryUpdate {
+    /// The resources affected by the event and any event it corrects, in the order they are to be updated
+    pub fn get_affected_resources(&self) -> Vec<EconomicResourceAddress> {
+        let mut resources: Vec<EconomicResourceAddress> = vec![];
+        for event in std::iter::once(&self.event).chain(self.corrected_event.iter()) {
+            for resource in vec![&event.to_resource_inventoried_as, &event.resource_inventoried_as] {
+                if let MaybeUndefined::Some(resource) = resource {
+                    if !resources.contains(resource) {
+                        resources.push(resource.to_owned());
+                    }
+                }
+            }
+        }
+        resources
+    }
+
+    /// The effects of the update upon one of the resources it affects
+    pub fn effects_on(&self, resource_id: &EconomicResourceAddress) -> EventEffects {
+        EventEffects {
+            event: self.event.to_owned(),
+            inventory_types: get_inventory_types(resource_id, &self.event),
+            corrected_event: self.corrected_event.to_owned(),
+            corrected_inventory_types: match &self.corrected_event {
+                Some(corrected) => get_inventory_types(resource_id, corrected),
+                None => vec![],
+            },
+        }
+    }
+}
+
+/// Handle update operations by observed events, applying all effects of an event upon a single
+/// resource in one revision.
+///
+/// The quantities of any corrected event are reversed first. Other effects of the corrected event
+/// (such as relocating or transferring the resource) are left to be overwritten by the effects of
+/// the correction. The event is then applied to each side of its flow which the resource is on,
+/// receiving side first.
+///
+#[derive(Clone, Debug)]
+pub struct EventEffects {
+    pub event: EventCreateRequest,
+    pub inventory_types: Vec<ResourceInventoryType>,
+    pub corrected_event: Option<EventCreateRequest>,
+    pub corrected_inventory_types: Vec<ResourceInventoryType>,
+}
+
+impl Updateable<EventEffects> for EntryData {
+    fn update_with(&self, e: EventEffects) -> RecordAPIResult<EntryData> {
+        let mut entry = self.to_owned();
+
+        if let Some(corrected) = &e.corrected_event {
+            for inventory_type in e.corrected_inventory_types.iter() {
+                let (accounting_quantity, onhand_quantity) = event_quantities(
+                    &entry, &corrected.action, corrected.resource_quantity.to_owned().to_option(),
+                    opposing_inventory_type(inventory_type),
+                )?;
+                entry = EntryData { accounting_quantity, onhand_quantity, ..entry };
+            }
+        }
+        for inventory_type in e.inventory_types {
+            entry = entry.update_with(e.event.with_inventory_type(inventory_type))?;
+        }
+
+        Ok(EntryData {
+            last_event: e.event.recorded_event.to_owned(),
+            ..entry
+        })
+    }
+}
+
+/// Corrective update applied when the quantities of a resource are found to have drifted
+/// from the totals implied by its event history, because the `event` which should have
+/// produced them was never applied to the resource.
+///
+/// Only quantities are corrected. The other effects of the event have since been
+/// superseded by those of later events.
+///
+/// :WARNING: not for direct use- only to be constructed when reconciling a resource
+/// against its affecting events.
+///
+#[derive(Clone, Debug)]
+pub struct QuantityCorrection {
+    pub accounting_quantity: Option<QuantityValue>,
+    pub onhand_quantity: Option<QuantityValue>,
+    pub event: EconomicEventAddress,
+}
+
+impl Updateable<QuantityCorrection> for EntryData {
+    fn update_with(&self, e: QuantityCorrection) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            accounting_quantity: e.accounting_quantity,
+            onhand_quantity: e.onhand_quantity,
+            last_event: Some(e.event),
+            ..self.to_owned()
+        })
+    }
+}
+
+/// Applying an event to the opposing side of its flow has the inverse effect
+fn opposing_inventory_type(which_inventory_type: &ResourceInventoryType) -> ResourceInventoryType {
+    match which_inventory_type {
+        ResourceInventoryType::ProvidingInventory => ResourceInventoryType::ReceivingInventory,
+        ResourceInventoryType::ReceivingInventory => ResourceInventoryType::ProvidingInventory,
+    }
+}
+
+/// Determines the (accounting, onhand) quantities of a resource after applying an event quantity
+/// to one side of the event's flow.
+///
+/// As on resource creation, event quantities without units are taken to be in the resource's units.
+///
+fn event_quantities(
+    resource: &EntryData,
+    action: &ActionId,
+    event_quantity: Option<QuantityValue>,
+    which_inventory_type: ResourceInventoryType,
+) -> RecordAPIResult<(Option<QuantityValue>, Option<QuantityValue>)> {
+    let resource_unit = resource.accounting_quantity.as_ref()
+        .or(resource.onhand_quantity.as_ref())
+        .and_then(|q| q.get_unit());
+    let event_val: MaybeUndefined<QuantityValue> = event_quantity
+        .map(|qty| match qty.get_unit() {
+            Some(_) => qty,
+            None => QuantityValue::new(qty.get_numerical_value(), resource_unit),
+        })
+        .into();
+
+    Ok((
+        update_quantity(resource.accounting_quantity.to_owned(), event_val.to_owned(), action, ResourceValueType::AccountingValue, which_inventory_type.to_owned())?,
+        update_quantity(resource.onhand_quantity.to_owned(), event_val, action, ResourceValueType::OnhandValue, which_inventory_type)?,
+    ))
+}
+
 /// Encapsulates the logic for updating EconomicResource quantities in response to event triggers
 fn update_quantity(
     current_val: Option<QuantityValue>,
@@ -378,6 +639,22 @@ fn update_quantity(
     }
 }
 
+/// Determines the `LocationEffect` of an event action upon the resources it affects
+fn get_location_effect(action: &ActionId) -> LocationEffect {
+    match get_builtin_action(action.as_ref()) {
+        Some(action_obj) => action_obj.location_effect,
+        None => LocationEffect::NoEffect,
+    }
+}
+
+/// Determines the `ContainedEffect` of an event action upon the resources it affects
+fn get_contained_effect(action: &ActionId) -> ContainedEffect {
+    match get_builtin_action(action.as_ref()) {
+        Some(action_obj) => action_obj.contained_effect,
+        None => ContainedEffect::NoEffect,
+    }
+}
+
 enum ResourceValueType {
     AccountingValue,
     OnhandValue,
@@ -4,8 +4,9 @@ pub use hc_zome_rea_economic_event_rpc::{
     CreateRequest as EventCreateRequest,
     ResourceResponseData as ResponseData,
     ResourceResponseCollection as Collection,
+    TraceParams, TraceResults,
 };
-use hc_zome_rea_economic_resource_storage::{EntryData};
+use hc_zome_rea_economic_resource_storage::{EntryData, InventoryUpdate};
 
 /// API interface for the external zome gateway
 pub trait API {
@@ -13,11 +14,18 @@ pub trait API {
 
     fn create_inventory_from_event(resource_entry_def_id: Self::S, params: CreationPayload) -> RecordAPIResult<(SignedActionHashed, EconomicResourceAddress, EntryData)>;
     fn update_inventory_from_event(
-        event: EventCreateRequest,
+        update: InventoryUpdate,
     ) -> RecordAPIResult<Vec<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)>>;
     fn get_economic_resource(address: EconomicResourceAddress) -> RecordAPIResult<ResponseData>;
     fn get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>;
+    fn get_economic_resource_as_of(address: EconomicResourceAddress, as_of: DateTime<FixedOffset>) -> RecordAPIResult<ResponseData>;
+    fn get_revision_history(page: RevisionHistoryParams<EconomicResourceAddress>) -> RecordAPIResult<RevisionHistory>;
+    fn diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>;
     fn update_economic_resource(resource: UpdateRequest) -> RecordAPIResult<ResponseData>;
+    fn resolve_economic_resource_conflict(conflicting_revisions: Vec<ActionHash>, resource: UpdateRequest) -> RecordAPIResult<ResponseData>;
+    fn recalculate_economic_resource(address: EconomicResourceAddress, apply: bool) -> RecordAPIResult<RecalculationReport>;
+    fn trace_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults>;
+    fn track_economic_resource(params: TraceParams<EconomicResourceAddress>) -> RecordAPIResult<TraceResults>;
 }
 
 /// Macro to programatically and predictably bind an `API` implementation to a
@@ -41,9 +49,9 @@ macro_rules! declare_economic_resource_zome_api {
         }
 
         #[hdk_extern]
-        fn _internal_update_inventory(event: EventCreateRequest) -> ExternResult<Vec<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)>>
+        fn _internal_update_inventory(update: InventoryUpdate) -> ExternResult<Vec<(SignedActionHashed, EconomicResourceAddress, EntryData, EntryData)>>
         {
-            Ok(<$zome_api>::update_inventory_from_event(event)?)
+            Ok(<$zome_api>::update_inventory_from_event(update)?)
         }
 
         #[hdk_extern]
@@ -51,14 +59,49 @@ macro_rules! declare_economic_resource_zome_api {
             Ok(<$zome_api>::get_economic_resource(address)?)
         }
 
+        #[hdk_extern]
+        fn get_economic_resource_as_of(AsOfParams { address, as_of }: AsOfParams) -> ExternResult<$crate::ResponseData> {
+            Ok(<$zome_api>::get_economic_resource_as_of(address, as_of)?)
+        }
+
         #[hdk_extern]
         fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<$crate::ResponseData> {
             Ok(<$zome_api>::get_revision(revision_id)?)
         }
 
+        #[hdk_extern]
+        fn get_revision_history(page: RevisionHistoryParams<EconomicResourceAddress>) -> ExternResult<RevisionHistory> {
+            Ok(<$zome_api>::get_revision_history(page)?)
+        }
+
+        #[hdk_extern]
+        fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+            Ok(<$zome_api>::diff_revisions(from_revision_id, to_revision_id)?)
+        }
+
         #[hdk_extern]
         fn update_economic_resource(UpdateParams { resource }: UpdateParams) -> ExternResult<$crate::ResponseData> {
             Ok(<$zome_api>::update_economic_resource(resource)?)
         }
+
+        #[hdk_extern]
+        fn resolve_economic_resource_conflict(ResolveConflictParams { conflicting_revisions, resource }: ResolveConflictParams) -> ExternResult<$crate::ResponseData> {
+            Ok(<$zome_api>::resolve_economic_resource_conflict(conflicting_revisions, resource)?)
+        }
+
+        #[hdk_extern]
+        fn recalculate_economic_resource(RecalculateParams { address, apply }: RecalculateParams) -> ExternResult<RecalculationReport> {
+            Ok(<$zome_api>::recalculate_economic_resource(address, apply)?)
+        }
+
+        #[hdk_extern]
+        fn trace_economic_resource(params: TraceParams<EconomicResourceAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::trace_economic_resource(params)?)
+        }
+
+        #[hdk_extern]
+        fn track_economic_resource(params: TraceParams<EconomicResourceAddress>) -> ExternResult<TraceResults> {
+            Ok(<$zome_api>::track_economic_resource(params)?)
+        }
     };
 }
@@ -17,10 +17,16 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    rpc::call_zome_method,
+    rpc::{
+        call_zome_method,
+        call_local_zome_method,
+    },
+    metadata::read_revision_metadata_full,
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -63,31 +69,59 @@ pub fn handle_create_fulfillment<S>(entry_def_id: S, fulfillment: CreateRequest)
     );
     hdk::prelude::debug!("handle_create_fulfillment::call_zome_method::{:?} {:?}", REPLICATE_CREATE_API_METHOD, pingback);
 
+    sync_involved_agents(&[entry_resp.fulfills.to_owned()]);
+
     construct_response(&fulfillment_address, &meta, &entry_resp)
 }
 
 pub fn handle_get_fulfillment(address: FulfillmentAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.fulfillment.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.fulfillment.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<FulfillmentAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, FulfillmentAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry)?.fulfillment)
+    })
 }
 
 pub fn handle_update_fulfillment(fulfillment: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
-    let (meta, base_address, new_entry, prev_entry): (_, FulfillmentAddress, EntryData, EntryData) = update_record(&fulfillment.get_revision_id(), fulfillment.to_owned())?;
+    update_or_resolve_fulfillment(None, fulfillment)
+}
+
+pub fn handle_resolve_fulfillment_conflict(conflicting_revisions: Vec<ActionHash>, fulfillment: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_fulfillment(Some(&conflicting_revisions), fulfillment)
+}
+
+fn update_or_resolve_fulfillment(conflicting_revisions: Option<&[ActionHash]>, fulfillment: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, new_entry, prev_entry): (_, FulfillmentAddress, EntryData, EntryData) = update_or_resolve_record(&fulfillment.get_revision_id(), conflicting_revisions, fulfillment.to_owned())?;
 
     // update commitment indexes in local DNA
     if new_entry.fulfills != prev_entry.fulfills {
         let e = update_index!(
             fulfillment
                 .fulfills(&vec![new_entry.fulfills.clone()])
-                .not(&vec![prev_entry.fulfills]),
+                .not(&vec![prev_entry.fulfills.clone()]),
             commitment.fulfilled_by(&base_address)
         );
         hdk::prelude::debug!("handle_update_fulfillment::fulfills index (origin) {:?}", e);
@@ -106,17 +140,27 @@ pub fn handle_update_fulfillment(fulfillment: UpdateRequest) -> RecordAPIResult<
         hdk::prelude::debug!("handle_update_fulfillment::call_zome_method::{:?} {:?}", REPLICATE_UPDATE_API_METHOD, pingback);
     }
 
+    // agents of the fulfilling event are involved in the new commitment, and may no longer be in the old one
+    if new_entry.fulfills != prev_entry.fulfills {
+        sync_involved_agents(&[new_entry.fulfills.to_owned(), prev_entry.fulfills.to_owned()]);
+    } else if new_entry.fulfilled_by != prev_entry.fulfilled_by {
+        sync_involved_agents(&[new_entry.fulfills.to_owned()]);
+    }
+
     construct_response(&base_address, &meta, &new_entry)
 }
 
-pub fn handle_delete_fulfillment(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_fulfillment<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
 
     // update commitment indexes in local DNA
-    let e = update_index!(fulfillment.fulfills.not(&vec![entry.fulfills]), commitment.fulfilled_by(&base_address));
+    let e = update_index!(fulfillment.fulfills.not(&vec![entry.fulfills.to_owned()]), commitment.fulfilled_by(&base_address));
     hdk::prelude::debug!("handle_delete_fulfillment::fulfills index (origin) {:?}", e);
 
+    sync_involved_agents(&[entry.fulfills]);
+
     // update fulfillment records in remote DNA (and by proxy, event indexes in remote DNA)
     let pingback: OtherCellResult<ResponseData> = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
         &entry.fulfilled_by,
@@ -127,7 +171,25 @@ pub fn handle_delete_fulfillment(revision_id: ActionHash) -> RecordAPIResult<boo
     // :TODO: report any error
     hdk::prelude::debug!("handle_delete_fulfillment::call_zome_method::{:?} {:?}", REPLICATE_DELETE_API_METHOD, pingback);
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Ask the `Commitment` zome to recompute the agents involved in each of `commitments`,
+/// after the events fulfilling them have changed
+fn sync_involved_agents(commitments: &[CommitmentAddress]) {
+    for commitment in commitments {
+        let result: OtherCellResult<()> = call_local_zome_method(
+            read_commitment_zome,
+            COMMITMENT_INVOLVED_AGENTS_SYNC_API_METHOD.to_string(),
+            ByAddress { address: commitment.to_owned() },
+        );
+        hdk::prelude::debug!("sync_involved_agents::{:?} {:?}", commitment, result);
+    }
+}
+
+/// Properties accessor for zome config.
+fn read_commitment_zome(conf: DnaConfigSlicePlanning) -> Option<String> {
+    conf.fulfillment.commitment_zome
 }
 
 /// Properties accessor for zome config.
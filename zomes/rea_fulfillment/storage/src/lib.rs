@@ -42,6 +42,8 @@ pub struct DnaConfigSliceObservation {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
 pub struct FulfillmentZomeConfigPlanning {
     pub commitment_index_zome: String,
+    // zome ID of a `Commitment` zome which should be notified to recompute involved agents when fulfillments change.
+    pub commitment_zome: Option<String>,
     pub index_zome: String,
 }
 
@@ -10,3 +10,5 @@ pub const FULFILLMENT_ENTRY_TYPE: &str = "fulfillment";
 pub const REPLICATE_CREATE_API_METHOD: &str = "create_fulfillment";
 pub const REPLICATE_UPDATE_API_METHOD: &str = "update_fulfillment";
 pub const REPLICATE_DELETE_API_METHOD: &str = "delete_fulfillment";
+
+pub const COMMITMENT_INVOLVED_AGENTS_SYNC_API_METHOD: &str = "sync_commitment_involved_agents";
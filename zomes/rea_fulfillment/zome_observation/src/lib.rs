@@ -30,6 +30,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<FulfillmentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn fulfillment_updated(UpdateParams { fulfillment }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_fulfillment(fulfillment)?)
@@ -37,5 +47,5 @@ fn fulfillment_updated(UpdateParams { fulfillment }: UpdateParams) -> ExternResu
 
 #[hdk_extern]
 fn fulfillment_deleted(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_fulfillment(revision_id)?)
+    Ok(handle_delete_fulfillment(FULFILLMENT_ENTRY_TYPE, revision_id)?)
 }
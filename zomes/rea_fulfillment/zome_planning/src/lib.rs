@@ -28,12 +28,34 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<FulfillmentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn update_fulfillment(UpdateParams { fulfillment }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_fulfillment(fulfillment)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub fulfillment: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_fulfillment_conflict(ResolveConflictParams { conflicting_revisions, fulfillment }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_fulfillment_conflict(conflicting_revisions, fulfillment)?)
+}
+
 #[hdk_extern]
 fn delete_fulfillment(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_fulfillment(revision_id)?)
+    Ok(handle_delete_fulfillment(FULFILLMENT_ENTRY_TYPE, revision_id)?)
 }
@@ -8,18 +8,23 @@
  */
 use paste::paste;
 use hdk_records::{
-    RecordAPIResult, MaybeUndefined, SignedActionHashed,
+    RecordAPIResult, OtherCellResult, MaybeUndefined, SignedActionHashed,
     records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+    rpc::call_zome_method,
 };
 use hdk_semantic_indexes_client_lib::*;
+use vf_attributes_hdk::ByAddress;
 
+use hc_zome_rea_intent_storage_consts::*;
 use hc_zome_rea_intent_storage::*;
 use hc_zome_rea_intent_rpc::*;
 
@@ -50,6 +55,13 @@ pub fn handle_create_intent<S>(entry_def_id: S, intent: CreateRequest) -> Record
         let e = create_index!(intent.output_of(output_of), process.intended_outputs(&base_address));
         hdk::prelude::debug!("handle_create_intent::output_of index {:?}", e);
     };
+    if let Some(due) = &entry_resp.due {
+        let e = index_time!(intent(&base_address).due(due.to_owned()));
+        hdk::prelude::debug!("handle_create_intent::due time index {:?}", e);
+    };
+
+    // processes which this flow is an input or output of may now be sequenced differently
+    sync_process_sequence(&flow_processes(&entry_resp));
 
     // return entire record structure
     construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
@@ -58,19 +70,45 @@ pub fn handle_create_intent<S>(entry_def_id: S, intent: CreateRequest) -> Record
 pub fn handle_get_intent(address: IntentAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+    response.intent.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.intent.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<IntentAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, IntentAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.intent)
+    })
 }
 
 pub fn handle_update_intent(intent: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_intent(None, intent)
+}
+
+pub fn handle_resolve_intent_conflict(conflicting_revisions: Vec<ActionHash>, intent: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_intent(Some(&conflicting_revisions), intent)
+}
+
+fn update_or_resolve_intent(conflicting_revisions: Option<&[ActionHash]>, intent: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let address = intent.get_revision_id().to_owned();
-    let (meta, base_address, new_entry, prev_entry): (_, IntentAddress, EntryData, EntryData) = update_record(&address, intent.to_owned())?;
+    let (meta, base_address, new_entry, prev_entry): (_, IntentAddress, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, intent.to_owned())?;
 
     // handle link fields
     if new_entry.provider != prev_entry.provider {
@@ -115,14 +153,37 @@ pub fn handle_update_intent(intent: UpdateRequest) -> RecordAPIResult<ResponseDa
         );
         hdk::prelude::debug!("handle_update_intent::output_of index {:?}", e);
     }
+    if new_entry.due != prev_entry.due {
+        let e = match &new_entry.due {
+            Some(due) => index_time!(intent(&base_address).due(due.to_owned())),
+            None => unindex_time!(intent(&base_address).due),
+        };
+        hdk::prelude::debug!("handle_update_intent::due time index {:?}", e);
+    }
+
+    if new_entry.input_of != prev_entry.input_of || new_entry.output_of != prev_entry.output_of
+        || new_entry.resource_inventoried_as != prev_entry.resource_inventoried_as
+        || new_entry.resource_conforms_to != prev_entry.resource_conforms_to
+    {
+        // processes either side of the old & new flow may now be sequenced differently
+        let mut processes = flow_processes(&new_entry);
+        for process in flow_processes(&prev_entry) {
+            if !processes.contains(&process) {
+                processes.push(process);
+            }
+        }
+        sync_process_sequence(&processes);
+    }
 
     construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
 }
 
-pub fn handle_delete_intent(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_intent<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     // load the record to ensure it is of the correct type
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let sequenced_processes = flow_processes(&entry);
 
     // handle link fields
     if let Some(process_address) = entry.input_of {
@@ -142,8 +203,28 @@ pub fn handle_delete_intent(revision_id: ActionHash) -> RecordAPIResult<bool>
         hdk::prelude::debug!("handle_delete_intent::receiver index {:?}", e);
     }
 
+    sync_process_sequence(&sequenced_processes);
+
     // delete entry last, as it must be present in order for links to be removed
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Processes which a flow is an input or output of
+fn flow_processes(e: &EntryData) -> Vec<ProcessAddress> {
+    e.input_of.iter().chain(e.output_of.iter()).cloned().collect()
+}
+
+/// Ask the observation cell holding each `Process` to recompute its sequencing, after flows into or out of it have changed
+fn sync_process_sequence(processes: &[ProcessAddress]) {
+    for process in processes {
+        let pingback: OtherCellResult<()> = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            process,
+            &PROCESS_SEQUENCE_SYNC_API_METHOD,
+            ByAddress { address: process.to_owned() },
+            LinkTypes::AvailableCapability,
+        );
+        hdk::prelude::debug!("sync_process_sequence::call_zome_method::{:?} {:?}", PROCESS_SEQUENCE_SYNC_API_METHOD, pingback);
+    }
 }
 
 /// Create response from input DHT primitives
@@ -22,6 +22,8 @@ pub use vf_attributes_hdk::{
     LocationAddress,
     ProposedIntentAddress,
     ActionHash, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
 };
 
 //---------------- EXTERNAL RECORD STRUCTURE ----------------
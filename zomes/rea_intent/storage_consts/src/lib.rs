@@ -6,3 +6,5 @@
  * @package hREA
  */
 pub const INTENT_ENTRY_TYPE: &str = "intent";
+
+pub const PROCESS_SEQUENCE_SYNC_API_METHOD: &str = "sync_process_sequence";
@@ -42,6 +42,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<IntentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -53,7 +63,19 @@ fn update_intent(UpdateParams { intent }: UpdateParams) -> ExternResult<Response
     Ok(handle_update_intent(intent)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub intent: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_intent_conflict(ResolveConflictParams { conflicting_revisions, intent }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_intent_conflict(conflicting_revisions, intent)?)
+}
+
 #[hdk_extern]
 fn delete_intent(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_intent(revision_id)?)
+    Ok(handle_delete_intent(INTENT_ENTRY_TYPE, revision_id)?)
 }
@@ -8,6 +8,7 @@ use hdk_semantic_indexes_zome_derive::index_zome;
 use hc_zome_rea_intent_rpc::*;
 
 #[index_zome]
+#[time_index(due)]
 struct Intent {
     satisfied_by: Local<satisfaction, satisfies>,
     input_of: Local<process, intended_inputs>,
@@ -0,0 +1,165 @@
+/**
+ * hREA location zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Location` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_location_storage::*;
+use hc_zome_rea_location_rpc::*;
+
+pub use hc_zome_rea_location_storage::LOCATION_ENTRY_TYPE;
+
+pub fn handle_create_location<S>(entry_def_id: S, location: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_location_index_zome, &entry_def_id, location)?;
+
+    // index against the geohash cells containing the location, if it has coordinates
+    let cells = geohash_cells(&entry_resp);
+    if !cells.is_empty() {
+        update_string_index!(location(&base_address).geohash(cells)<GeohashId>)?;
+    }
+
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_location(address: LocationAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.location.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.location.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<LocationAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, LocationAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.location)
+    })
+}
+
+pub fn handle_update_location(location: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_location(None, location)
+}
+
+pub fn handle_resolve_location_conflict(conflicting_revisions: Vec<ActionHash>, location: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_location(Some(&conflicting_revisions), location)
+}
+
+fn update_or_resolve_location(conflicting_revisions: Option<&[ActionHash]>, location: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let revision_hash = location.get_revision_id().clone();
+    let (meta, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, location)?;
+
+    // move the location between geohash cells if its coordinates changed
+    let new_cells = geohash_cells(&entry);
+    let prev_cells = geohash_cells(&prev_entry);
+    let added_cells: Vec<String> = new_cells.iter().filter(|c| !prev_cells.contains(c)).cloned().collect();
+    let removed_cells: Vec<String> = prev_cells.iter().filter(|c| !new_cells.contains(c)).cloned().collect();
+    if !added_cells.is_empty() || !removed_cells.is_empty() {
+        update_string_index!(location(&identity_address).geohash(added_cells).not(removed_cells)<GeohashId>)?;
+    }
+
+    construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
+}
+
+pub fn handle_delete_location<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // read any referencing indexes
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    let cells = geohash_cells(&entry);
+    if !cells.is_empty() {
+        update_string_index!(location(&base_address).geohash.not(cells)<GeohashId>)?;
+    }
+
+    delete_record::<EntryStorage,_,_,_,_,_>(read_location_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &LocationAddress, meta: &SignedActionHashed, e: &EntryData, (
+        economic_events,
+        economic_resources,
+    ): (
+        Vec<EconomicEventAddress>,
+        Vec<EconomicResourceAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        location: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            name: e.name.to_owned(),
+            lat: e.lat.to_owned(),
+            long: e.long.to_owned(),
+            alt: e.alt.to_owned(),
+            mappable_address: e.mappable_address.to_owned(),
+            note: e.note.to_owned(),
+            economic_events: economic_events.to_owned(),
+            economic_resources: economic_resources.to_owned(),
+        }
+    })
+}
+
+/// Geohash strings to index a location against, empty if it has no coordinates
+fn geohash_cells(e: &EntryData) -> Vec<String> {
+    match e.coordinates() {
+        Some((lat, long)) => geohash::index_cells(lat, long),
+        None => vec![],
+    }
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_location_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.location.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &LocationAddress) -> RecordAPIResult<(
+    Vec<EconomicEventAddress>,
+    Vec<EconomicResourceAddress>,
+)> {
+    Ok((
+        read_index!(location(base_address).economic_events)?,
+        read_index!(location(base_address).economic_resources)?,
+    ))
+}
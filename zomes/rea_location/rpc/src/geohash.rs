@@ -0,0 +1,252 @@
+/**
+ * Geohash helpers for spatial indexing of `Location` records
+ *
+ * Locations with coordinates are indexed against the geohash cells containing them
+ * at several levels of precision. Spatial queries are answered by determining the
+ * set of cells at a single precision which cover the requested area, reading the
+ * records indexed against each, and discarding any which fall outside of the area.
+ *
+ * All functions in this module are pure, so that they may be shared by the record
+ * storage zome (to write the index) and the index zome (to query it).
+ *
+ * @see     https://en.wikipedia.org/wiki/Geohash
+ * @package hREA
+ */
+use std::collections::BTreeSet;
+use serde::{Serialize, Deserialize};
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Geohash lengths which `Location`s are indexed against, least precise first
+pub const INDEXED_PRECISIONS: [usize; 5] = [1, 2, 4, 6, 8];
+
+/// Maximum number of cells to read when answering a spatial query. Queries over larger
+/// areas fall back to coarser cells, and rely on post-filtering to trim the results.
+pub const MAX_QUERY_CELLS: usize = 16;
+
+/// Mean radius of the Earth in metres, as used for distance calculations
+pub const EARTH_RADIUS_METRES: f64 = 6_371_008.8;
+
+/// Approximate length of one degree of latitude in metres
+const METRES_PER_DEGREE: f64 = 111_320.0;
+
+/// A rectangular area described by its south-western and north-eastern corners.
+/// Boxes crossing the antimeridian are described with `min_long` greater than `max_long`.
+///
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_long: f64,
+    pub max_lat: f64,
+    pub max_long: f64,
+}
+
+impl BoundingBox {
+    /// Determine whether the given coordinates lie within (or upon the edges of) this box
+    pub fn contains(&self, lat: f64, long: f64) -> bool {
+        if lat < self.min_lat || lat > self.max_lat {
+            return false;
+        }
+        if self.min_long <= self.max_long {
+            long >= self.min_long && long <= self.max_long
+        } else {
+            long >= self.min_long || long <= self.max_long
+        }
+    }
+
+    /// Compute a box enclosing the circle of `radius` metres around the given coordinates
+    pub fn around(lat: f64, long: f64, radius: f64) -> Self {
+        let lat_delta = radius / METRES_PER_DEGREE;
+        let min_lat = (lat - lat_delta).max(-90.0);
+        let max_lat = (lat + lat_delta).min(90.0);
+
+        let long_scale = lat.to_radians().cos();
+        let long_delta = if long_scale > f64::EPSILON { radius / (METRES_PER_DEGREE * long_scale) } else { 180.0 };
+
+        // circles touching a pole or spanning the globe cover all longitudes
+        if long_delta >= 180.0 || min_lat <= -90.0 || max_lat >= 90.0 {
+            return Self { min_lat, min_long: -180.0, max_lat, max_long: 180.0 };
+        }
+
+        Self {
+            min_lat,
+            min_long: wrap_longitude(long - long_delta),
+            max_lat,
+            max_long: wrap_longitude(long + long_delta),
+        }
+    }
+
+    /// Split into boxes which do not cross the antimeridian
+    fn split(&self) -> Vec<Self> {
+        if self.min_long <= self.max_long {
+            return vec![self.to_owned()];
+        }
+        vec![
+            Self { min_lat: self.min_lat, min_long: self.min_long, max_lat: self.max_lat, max_long: 180.0 },
+            Self { min_lat: self.min_lat, min_long: -180.0, max_lat: self.max_lat, max_long: self.max_long },
+        ]
+    }
+}
+
+/// Encode the given coordinates as a geohash string of `precision` characters
+pub fn encode(lat: f64, long: f64, precision: usize) -> String {
+    let mut lat_range = (-90.0, 90.0);
+    let mut long_range = (-180.0, 180.0);
+    let mut hash = String::with_capacity(precision);
+    let mut is_long_bit = true;
+    let mut bit = 0;
+    let mut ch = 0usize;
+
+    while hash.len() < precision {
+        let (range, value) = if is_long_bit { (&mut long_range, long) } else { (&mut lat_range, lat) };
+        let mid = (range.0 + range.1) / 2.0;
+        if value >= mid {
+            ch = (ch << 1) | 1;
+            range.0 = mid;
+        } else {
+            ch <<= 1;
+            range.1 = mid;
+        }
+        is_long_bit = !is_long_bit;
+
+        bit += 1;
+        if bit == 5 {
+            hash.push(BASE32[ch] as char);
+            bit = 0;
+            ch = 0;
+        }
+    }
+
+    hash
+}
+
+/// Geohash strings for every cell containing the given coordinates which is used for indexing
+pub fn index_cells(lat: f64, long: f64) -> Vec<String> {
+    let finest = encode(lat, long, INDEXED_PRECISIONS[INDEXED_PRECISIONS.len() - 1]);
+    INDEXED_PRECISIONS.iter()
+        .map(|precision| finest[..*precision].to_string())
+        .collect()
+}
+
+/// Height and width (in degrees) of geohash cells of the given `precision`
+pub fn cell_size(precision: usize) -> (f64, f64) {
+    let bits = 5 * precision as i32;
+    let long_bits = (bits + 1) / 2;
+    let lat_bits = bits / 2;
+    (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(long_bits))
+}
+
+/// Determine the geohash cells of the given `precision` which together cover `bounds`
+pub fn covering_cells(bounds: &BoundingBox, precision: usize) -> Vec<String> {
+    let (lat_size, long_size) = cell_size(precision);
+    let mut cells = BTreeSet::new();
+
+    for area in bounds.split() {
+        let (lat_from, lat_to) = cell_span(area.min_lat, area.max_lat, -90.0, lat_size);
+        let (long_from, long_to) = cell_span(area.min_long, area.max_long, -180.0, long_size);
+        for lat_idx in lat_from..=lat_to {
+            for long_idx in long_from..=long_to {
+                cells.insert(encode(
+                    -90.0 + (lat_idx as f64 + 0.5) * lat_size,
+                    -180.0 + (long_idx as f64 + 0.5) * long_size,
+                    precision,
+                ));
+            }
+        }
+    }
+
+    cells.into_iter().collect()
+}
+
+/// Select the most precise indexed geohash length which can cover `bounds` using no
+/// more than `MAX_QUERY_CELLS` cells, falling back to the coarsest available.
+///
+pub fn precision_for_bounds(bounds: &BoundingBox) -> usize {
+    INDEXED_PRECISIONS.iter().rev()
+        .find(|precision| count_covering_cells(bounds, **precision) <= MAX_QUERY_CELLS)
+        .copied()
+        .unwrap_or(INDEXED_PRECISIONS[0])
+}
+
+/// Great-circle distance in metres between two coordinates, using the haversine formula
+pub fn distance_metres(lat_a: f64, long_a: f64, lat_b: f64, long_b: f64) -> f64 {
+    let d_lat = (lat_b - lat_a).to_radians();
+    let d_long = (long_b - long_a).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat_a.to_radians().cos() * lat_b.to_radians().cos() * (d_long / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METRES * a.sqrt().atan2((1.0 - a).sqrt())
+}
+
+fn count_covering_cells(bounds: &BoundingBox, precision: usize) -> usize {
+    let (lat_size, long_size) = cell_size(precision);
+    bounds.split().iter()
+        .map(|area| {
+            let (lat_from, lat_to) = cell_span(area.min_lat, area.max_lat, -90.0, lat_size);
+            let (long_from, long_to) = cell_span(area.min_long, area.max_long, -180.0, long_size);
+            (lat_to - lat_from + 1) * (long_to - long_from + 1)
+        })
+        .sum()
+}
+
+/// Indexes of the first and last cells along an axis starting at `origin` which overlap `min`..`max`
+fn cell_span(min: f64, max: f64, origin: f64, size: f64) -> (usize, usize) {
+    let last_cell = ((-2.0 * origin) / size) as usize - 1;
+    let to_cell = |value: f64| (((value - origin) / size).floor().max(0.0) as usize).min(last_cell);
+    (to_cell(min), to_cell(max))
+}
+
+fn wrap_longitude(long: f64) -> f64 {
+    if long < -180.0 {
+        long + 360.0
+    } else if long > 180.0 {
+        long - 360.0
+    } else {
+        long
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(57.64911, 10.40744, 11), "u4pruydqqvj");
+        assert_eq!(encode(42.6, -5.6, 5), "ezs42");
+    }
+
+    #[test]
+    fn test_index_cells() {
+        assert_eq!(index_cells(42.6, -5.6), vec!["e", "ez", "ezs4", "ezs42e", "ezs42e44"]);
+    }
+
+    #[test]
+    fn test_covering_cells_include_contained_points() {
+        let bounds = BoundingBox { min_lat: 42.5, min_long: -5.7, max_lat: 42.7, max_long: -5.5 };
+        let precision = precision_for_bounds(&bounds);
+        let cells = covering_cells(&bounds, precision);
+        assert!(cells.len() <= MAX_QUERY_CELLS);
+        assert!(cells.contains(&encode(42.6, -5.6, precision)));
+        assert!(cells.contains(&encode(42.5, -5.7, precision)));
+        assert!(cells.contains(&encode(42.7, -5.5, precision)));
+    }
+
+    #[test]
+    fn test_antimeridian() {
+        let bounds = BoundingBox::around(0.0, 179.99, 5000.0);
+        assert!(bounds.min_long > bounds.max_long);
+        assert!(bounds.contains(0.0, -179.99));
+        assert!(!bounds.contains(0.0, 0.0));
+
+        let cells = covering_cells(&bounds, 2);
+        assert!(cells.contains(&encode(0.0, 179.99, 2)));
+        assert!(cells.contains(&encode(0.0, -179.99, 2)));
+    }
+
+    #[test]
+    fn test_distance() {
+        let d = distance_metres(51.5007, 0.1246, 40.6892, 74.0445);
+        assert!((d - 5_574_840.0).abs() < 1_000.0);
+    }
+}
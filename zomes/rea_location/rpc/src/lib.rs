@@ -0,0 +1,178 @@
+/**
+ * hREA location zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use serde_maybe_undefined::MaybeUndefined;
+use hdk_uuid_types::{ DnaHash, addressable_identifier };
+pub use hdk::prelude::*;
+pub use vf_attributes_hdk::{
+    LocationAddress,
+    EconomicEventAddress,
+    EconomicResourceAddress,
+    ActionHash,
+    ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+};
+
+pub mod geohash;
+pub use geohash::BoundingBox;
+
+// internal type for indexing against geohash cell strings
+addressable_identifier!(GeohashId => EntryHash);
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields.
+/// Corresponds to a ValueFlows `SpatialThing`.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: LocationAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mappable_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub economic_events: Vec<EconomicEventAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub economic_resources: Vec<EconomicResourceAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub location: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub lat: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub long: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub alt: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub mappable_address: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub lat: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub long: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub alt: MaybeUndefined<f64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub mappable_address: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&self) -> ActionHash {
+        self.revision_id.to_owned().into()
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub economic_events: Option<EconomicEventAddress>,
+    pub economic_resources: Option<EconomicResourceAddress>,
+    pub geohash: Option<String>,
+    pub geohash_internal: Option<GeohashId>,
+}
+
+/// Parameters for locating all `Location`s within a rectangular area
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WithinBoundsParams {
+    pub bounds: BoundingBox,
+    // pagination, as per index zome `query_*` APIs
+    #[serde(default)]
+    pub first: Option<usize>,
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub last: Option<usize>,
+    #[serde(default)]
+    pub before: Option<String>,
+}
+
+/// Parameters for locating all `Location`s within `radius` metres of a point
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WithinRadiusParams {
+    pub lat: f64,
+    pub long: f64,
+    pub radius: f64,
+    // pagination, as per index zome `query_*` APIs
+    #[serde(default)]
+    pub first: Option<usize>,
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub last: Option<usize>,
+    #[serde(default)]
+    pub before: Option<String>,
+}
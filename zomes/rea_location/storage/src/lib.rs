@@ -0,0 +1,148 @@
+/**
+ * hREA location zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    generate_record_entry,
+    record_interface::{Updateable},
+};
+
+use hc_zome_rea_location_rpc::{ CreateRequest, UpdateRequest };
+
+pub use vf_attributes_hdk::LocationAddress;
+pub use hc_zome_rea_location_storage_consts::LOCATION_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub location: LocationZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct LocationZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct EntryData {
+    pub name: String,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+    pub alt: Option<f64>,
+    pub mappable_address: Option<String>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.lat.is_some() != self.long.is_some() {
+            return Err("Location must specify both 'lat' and 'long', or neither".into());
+        }
+        if let Some(lat) = self.lat {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err("Location 'lat' must be between -90 and 90 degrees".into());
+            }
+        }
+        if let Some(long) = self.long {
+            if !(-180.0..=180.0).contains(&long) {
+                return Err("Location 'long' must be between -180 and 180 degrees".into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Coordinates of this location, if it has been placed on the globe
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        match (self.lat, self.long) {
+            (Some(lat), Some(long)) => Some((lat, long)),
+            _ => None,
+        }
+    }
+}
+
+generate_record_entry!(EntryData, LocationAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    Location(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::Location(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: e.name.into(),
+            lat: e.lat.into(),
+            long: e.long.into(),
+            alt: e.alt.into(),
+            mappable_address: e.mappable_address.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            lat: if e.lat.is_undefined() { self.lat.to_owned() } else { e.lat.to_owned().into() },
+            long: if e.long.is_undefined() { self.long.to_owned() } else { e.long.to_owned().into() },
+            alt: if e.alt.is_undefined() { self.alt.to_owned() } else { e.alt.to_owned().into() },
+            mappable_address: if e.mappable_address.is_undefined() { self.mappable_address.to_owned() } else { e.mappable_address.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
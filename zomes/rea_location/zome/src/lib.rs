@@ -0,0 +1,77 @@
+/**
+ * hREA location zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_location_rpc::*;
+use hc_zome_rea_location_lib::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateParams {
+    pub location: CreateRequest,
+}
+
+#[hdk_extern]
+fn create_location(CreateParams { location }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_location(LOCATION_ENTRY_TYPE, location)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadParams {
+    pub address: LocationAddress,
+}
+
+#[hdk_extern]
+fn get_location(ReadParams { address }: ReadParams) -> ExternResult<ResponseData> {
+    Ok(handle_get_location(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<LocationAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateParams {
+    pub location: UpdateRequest,
+}
+
+#[hdk_extern]
+fn update_location(UpdateParams { location }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_location(location)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub location: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_location_conflict(ResolveConflictParams { conflicting_revisions, location }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_location_conflict(conflicting_revisions, location)?)
+}
+
+#[hdk_extern]
+fn delete_location(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_location(LOCATION_ENTRY_TYPE, revision_id)?)
+}
@@ -0,0 +1,113 @@
+/**
+ * Location query indexes for observation DNA
+ *
+ * In addition to the standard relationship indexes, `Location`s with coordinates are
+ * indexed against the geohash cells containing them in order to support spatial queries.
+ *
+ * @package hREA
+ */
+use std::collections::HashSet;
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_location_rpc::*;
+
+#[index_zome]
+struct Location {
+    economic_events: Local<economic_event, at_location>,
+    economic_resources: Local<economic_resource, current_location>,
+
+    // query locations by the geohash cells they fall within
+    geohash: Local<location, geohash_internal>::String,
+    // :SHONK: redundant loopback index, required for internals of bidirectional index link management.
+    geohash_internal: Local<location, geohash>,
+}
+
+/// Locate all `Location`s falling within the given rectangular area
+#[hdk_extern]
+fn query_locations_within_bounds(WithinBoundsParams { bounds, first, after, last, before }: WithinBoundsParams) -> ExternResult<QueryResults> {
+    let (entries, errors) = read_locations_within(&bounds, |_lat, _long| true)?;
+    Ok(paginate_locations(entries, errors, &PageRequest::new(first, after, last, before)?)?)
+}
+
+/// Locate all `Location`s within `radius` metres of the given point
+#[hdk_extern]
+fn query_locations_within_radius(WithinRadiusParams { lat, long, radius, first, after, last, before }: WithinRadiusParams) -> ExternResult<QueryResults> {
+    let bounds = BoundingBox::around(lat, long, radius);
+    let (entries, errors) = read_locations_within(&bounds, |loc_lat, loc_long| {
+        geohash::distance_metres(lat, long, loc_lat, loc_long) <= radius
+    })?;
+    Ok(paginate_locations(entries, errors, &PageRequest::new(first, after, last, before)?)?)
+}
+
+/// Select the requested page from the full set of matching `Location`s. Spatial matches must
+/// be read in full to be filtered by coordinates, so pagination is applied after the fact.
+/// Records which could not be read have no position in the ordering and are reported with
+/// the first page only.
+///
+fn paginate_locations(
+    entries: Vec<ResponseData>,
+    errors: Vec<RecordAPIResult<ResponseData>>,
+    request: &PageRequest,
+) -> RecordAPIResult<QueryResults>
+{
+    let identities = entries.iter()
+        .map(|data| { let id: &EntryHash = data.location.id.as_ref(); id.to_owned() })
+        .collect();
+    let page = paginate_identities(identities, request, PAGE_SIZE)?;
+
+    let mut page_entries: Vec<RecordAPIResult<ResponseData>> = page.identities.iter()
+        .filter_map(|id| entries.iter().find(|data| { let loc_id: &EntryHash = data.location.id.as_ref(); loc_id == id }))
+        .cloned()
+        .map(Ok)
+        .collect();
+    if !page.has_previous_page {
+        page_entries.extend(errors);
+    }
+
+    handle_list_output(page_entries.as_slice(), &page)
+}
+
+/// Read all records indexed against the geohash cells covering `bounds`, discarding any
+/// which lie outside of it or which do not satisfy `filter`. Any records which failed to load
+/// are returned separately.
+///
+fn read_locations_within<F>(bounds: &BoundingBox, filter: F) -> RecordAPIResult<(Vec<ResponseData>, Vec<RecordAPIResult<ResponseData>>)>
+    where F: Fn(f64, f64) -> bool,
+{
+    let precision = geohash::precision_for_bounds(bounds);
+    let mut seen: HashSet<LocationAddress> = HashSet::new();
+    let mut results = vec![];
+    let mut errors = vec![];
+
+    for cell in geohash::covering_cells(bounds, precision) {
+        // adapt the geohash string to an EntryHash for indexing engine
+        let index_anchor_path = Path::from(&cell);
+        let index_anchor_id: LocationAddress = DnaAddressable::new(dna_info()?.hash, index_anchor_path.path_entry_hash()?);
+
+        let entries = query_index::<ResponseData, LocationAddress, _,_,_,_,_,_>(
+            &index_anchor_id,
+            &"location_geohash_internal",
+            &LOCAL_TIME_INDEX_ID,
+            &read_index_target_zome,
+            &QUERY_FN_NAME,
+        )?;
+
+        for entry in entries {
+            match entry {
+                Ok(data) => {
+                    // guard against records linked more than once from the same cell
+                    if !seen.insert(data.location.id.to_owned()) {
+                        continue;
+                    }
+                    if let (Some(lat), Some(long)) = (data.location.lat, data.location.long) {
+                        if bounds.contains(lat, long) && filter(lat, long) {
+                            results.push(data);
+                        }
+                    }
+                },
+                Err(e) => errors.push(Err(e)),
+            }
+        }
+    }
+
+    Ok((results, errors))
+}
@@ -0,0 +1,228 @@
+/**
+ * Plan templating
+ *
+ * Creates a new `Plan` by deep-copying the `Process`es and `Commitment`s of an existing one.
+ * Processes live in the observation DNA and commitments in the planning DNA, so all copies
+ * are made via remote calls to the cells which hold the original records. Those cells then
+ * index the copies back against the new `Plan` in the usual way.
+ *
+ * Copied quantities are multiplied by a scaling factor, and all dates are shifted by the
+ * same offset as is required to move the original `Plan`'s due date to the new one.
+ *
+ * Remote writes cannot be rolled back, so if any copy fails the records created up to that
+ * point are reported to the caller along with the error.
+ *
+ * @package hREA
+ */
+use std::collections::HashMap;
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    MaybeUndefined,
+    records::read_record_entry,
+    rpc::call_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+use vf_measurement::{QuantityValue, scale};
+use vf_attributes_hdk::ByAddress;
+
+use hc_zome_rea_plan_storage_consts::*;
+use hc_zome_rea_plan_storage::*;
+use hc_zome_rea_plan_rpc::*;
+use hc_zome_rea_process_rpc::{
+    CreateParams as ProcessCreateParams,
+    CreateRequest as ProcessCreateRequest,
+    ResponseData as ProcessResponseData,
+    Response as ProcessResponse,
+};
+use hc_zome_rea_commitment_rpc::{
+    CreateParams as CommitmentCreateParams,
+    CreateRequest as CommitmentCreateRequest,
+    ResponseData as CommitmentResponseData,
+    Response as CommitmentResponse,
+};
+
+use crate::{handle_create_plan, handle_get_plan};
+
+pub fn handle_instantiate_plan_from(
+    plan: PlanAddress,
+    scale_factor: f64,
+    new_due: DateTime<FixedOffset>,
+) -> RecordAPIResult<InstantiateResponse> {
+    if !scale_factor.is_finite() || scale_factor <= 0.0 {
+        return Err(DataIntegrityError::RemoteRequestError(format!("scale factor must be a finite positive number, got {}", scale_factor)));
+    }
+
+    let (_meta, _base_address, template) = read_record_entry::<EntryData, EntryStorage, _>(plan.as_ref())?;
+
+    // load everything to be copied before writing anything
+    let process_ids: Vec<ProcessAddress> = read_index!(plan(&plan).processes)?;
+    let processes = process_ids.iter()
+        .map(read_process)
+        .collect::<RecordAPIResult<Vec<ProcessResponse>>>()?;
+
+    let mut commitment_ids: Vec<CommitmentAddress> = read_index!(plan(&plan).independent_demands)?;
+    let non_process_commitments: Vec<CommitmentAddress> = read_index!(plan(&plan).non_process_commitments)?;
+    commitment_ids.extend(non_process_commitments);
+    for process in processes.iter() {
+        commitment_ids.extend(process.committed_inputs.iter().cloned());
+        commitment_ids.extend(process.committed_outputs.iter().cloned());
+    }
+    dedupe(&mut commitment_ids);
+    let commitments = commitment_ids.iter()
+        .map(read_commitment)
+        .collect::<RecordAPIResult<Vec<CommitmentResponse>>>()?;
+
+    // all dates move relative to the template's due date, or its latest commitment deadline if it has none
+    let anchor = template.due.or_else(|| commitments.iter().filter_map(|c| c.due).max())
+        .ok_or_else(|| DataIntegrityError::RemoteRequestError(format!("Plan {:?} has no due date to shift from", plan)))?;
+    let shift = |date: &Option<DateTime<FixedOffset>>| -> Option<DateTime<FixedOffset>> {
+        date.as_ref().map(|date| new_due + date.signed_duration_since(anchor))
+    };
+    let scale_qty = |qty: &Option<QuantityValue>| qty.to_owned().map(|q| scale(q, scale_factor));
+
+    let created_plan = handle_create_plan(PLAN_ENTRY_TYPE, CreateRequest {
+        name: template.name.to_owned().into(),
+        created: MaybeUndefined::Undefined,
+        due: MaybeUndefined::Some(new_due),
+        note: template.note.to_owned().into(),
+        deletable: template.deletable.to_owned().into(),
+        in_scope_of: template.in_scope_of.to_owned().into(),
+    })?;
+    let new_plan = created_plan.plan.id.to_owned();
+
+    let mut result = InstantiateResponse {
+        plan: created_plan.plan,
+        processes: vec![],
+        commitments: vec![],
+        error: None,
+    };
+    if let Err(e) = copy_plan_contents(&plan, &new_plan, processes, commitments, shift, scale_qty, &mut result) {
+        result.error = Some(e.to_string());
+        return Ok(result);
+    }
+
+    // re-read to pick up links indexed by the remote cells
+    result.plan = handle_get_plan(new_plan)?.plan;
+    Ok(result)
+}
+
+/// Copy template records into `new_plan`, recording the ID of each copy in `result` as it is made
+///
+fn copy_plan_contents<S, Q>(
+    plan: &PlanAddress,
+    new_plan: &PlanAddress,
+    processes: Vec<ProcessResponse>,
+    commitments: Vec<CommitmentResponse>,
+    shift: S,
+    scale_qty: Q,
+    result: &mut InstantiateResponse,
+) -> RecordAPIResult<()>
+    where S: Fn(&Option<DateTime<FixedOffset>>) -> Option<DateTime<FixedOffset>>,
+        Q: Fn(&Option<QuantityValue>) -> Option<QuantityValue>,
+{
+    let mut process_mapping: HashMap<ProcessAddress, ProcessAddress> = HashMap::new();
+    for process in processes {
+        let created: ProcessResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            &process.id,
+            &PROCESS_CREATE_API_METHOD,
+            ProcessCreateParams { process: ProcessCreateRequest {
+                name: process.name.to_owned(),
+                has_beginning: shift(&process.has_beginning).into(),
+                has_end: shift(&process.has_end).into(),
+                before: shift(&process.before).into(),
+                after: shift(&process.after).into(),
+                classified_as: process.classified_as.to_owned().into(),
+                based_on: process.based_on.to_owned().into(),
+                planned_within: MaybeUndefined::Some(new_plan.to_owned()),
+                finished: MaybeUndefined::Some(false),
+                in_scope_of: process.in_scope_of.to_owned().into(),
+                note: process.note.to_owned().into(),
+            } },
+            LinkTypes::AvailableCapability,
+        )?;
+        result.processes.push(created.process.id.to_owned());
+        process_mapping.insert(process.id, created.process.id);
+    }
+
+    // commitments referencing processes outside of the template keep pointing at the originals
+    let remap_process = |process: &Option<ProcessAddress>| -> Option<ProcessAddress> {
+        process.as_ref().map(|p| process_mapping.get(p).unwrap_or(p).to_owned())
+    };
+    let remap_plan = |p: &Option<PlanAddress>| -> Option<PlanAddress> {
+        p.as_ref().map(|p| if p == plan { new_plan.to_owned() } else { p.to_owned() })
+    };
+
+    for commitment in commitments {
+        let created: CommitmentResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            &commitment.id,
+            &COMMITMENT_CREATE_API_METHOD,
+            CommitmentCreateParams { commitment: CommitmentCreateRequest {
+                action: commitment.action.to_owned(),
+                note: commitment.note.to_owned().into(),
+                input_of: remap_process(&commitment.input_of).into(),
+                output_of: remap_process(&commitment.output_of).into(),
+                provider: commitment.provider.to_owned(),
+                receiver: commitment.receiver.to_owned(),
+                resource_inventoried_as: commitment.resource_inventoried_as.to_owned().into(),
+                resource_classified_as: commitment.resource_classified_as.to_owned().into(),
+                resource_conforms_to: commitment.resource_conforms_to.to_owned().into(),
+                resource_quantity: scale_qty(&commitment.resource_quantity).into(),
+                effort_quantity: scale_qty(&commitment.effort_quantity).into(),
+                has_beginning: shift(&commitment.has_beginning).into(),
+                has_end: shift(&commitment.has_end).into(),
+                has_point_in_time: shift(&commitment.has_point_in_time).into(),
+                due: shift(&commitment.due).into(),
+                at_location: commitment.at_location.to_owned().into(),
+                agreed_in: commitment.agreed_in.to_owned().into(),
+                clause_of: commitment.clause_of.to_owned().into(),
+                planned_within: remap_plan(&commitment.planned_within).into(),
+                independent_demand_of: remap_plan(&commitment.independent_demand_of).into(),
+                finished: MaybeUndefined::Some(false),
+                in_scope_of: commitment.in_scope_of.to_owned().into(),
+            } },
+            LinkTypes::AvailableCapability,
+        )?;
+        result.commitments.push(created.commitment.id);
+    }
+
+    Ok(())
+}
+
+fn read_process(address: &ProcessAddress) -> RecordAPIResult<ProcessResponse> {
+    let resp: ProcessResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+        address,
+        &PROCESS_READ_API_METHOD,
+        ByAddress { address: address.to_owned() },
+        LinkTypes::AvailableCapability,
+    )?;
+    Ok(resp.process)
+}
+
+fn read_commitment(address: &CommitmentAddress) -> RecordAPIResult<CommitmentResponse> {
+    let resp: CommitmentResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+        address,
+        &COMMITMENT_READ_API_METHOD,
+        ByAddress { address: address.to_owned() },
+        LinkTypes::AvailableCapability,
+    )?;
+    Ok(resp.commitment)
+}
+
+/// Remove repeated IDs (eg. an independent demand which is also a process output), keeping the first
+fn dedupe<T: PartialEq + Clone>(ids: &mut Vec<T>) {
+    let mut seen: Vec<T> = vec![];
+    ids.retain(|id| {
+        if seen.contains(id) {
+            false
+        } else {
+            seen.push(id.to_owned());
+            true
+        }
+    });
+}
+
+/// Properties accessor for zome config
+fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.plan.index_zome)
+}
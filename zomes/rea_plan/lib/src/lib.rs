@@ -8,21 +8,25 @@
  */
 use paste::paste;
 use hdk_records::{
-    RecordAPIResult, SignedActionHashed,
+    RecordAPIResult, MaybeUndefined, SignedActionHashed,
     records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
 use hc_zome_rea_plan_storage::*;
 use hc_zome_rea_plan_rpc::*;
 
+mod instantiate;
+pub use instantiate::handle_instantiate_plan_from;
 
 pub use hc_zome_rea_plan_storage::PLAN_ENTRY_TYPE;
 
@@ -34,31 +38,90 @@ fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
 pub fn handle_create_plan<S>(entry_def_id: S, plan: CreateRequest) -> RecordAPIResult<ResponseData>
     where S: AsRef<str> + std::fmt::Display
 {
-    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, plan)?;
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, plan.to_owned())?;
+
+    // handle link fields
+    if let CreateRequest { in_scope_of: MaybeUndefined::Some(in_scope_of), .. } = &plan {
+        let e = create_index!(plan.in_scope_of(in_scope_of), scenario.plans(&base_address));
+        hdk::prelude::debug!("handle_create_plan::in_scope_of index {:?}", e);
+    };
+
     construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
 }
 
 pub fn handle_get_plan(address: PlanAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.plan.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.plan.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<PlanAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, PlanAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.plan)
+    })
 }
 
 pub fn handle_update_plan(plan: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_plan(None, plan)
+}
+
+pub fn handle_resolve_plan_conflict(conflicting_revisions: Vec<ActionHash>, plan: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_plan(Some(&conflicting_revisions), plan)
+}
+
+fn update_or_resolve_plan(conflicting_revisions: Option<&[ActionHash]>, plan: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let revision_hash = plan.get_revision_id().clone();
-    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&revision_hash, plan)?;
+    let (meta, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, plan)?;
+
+    // handle link fields
+    if entry.in_scope_of != prev_entry.in_scope_of {
+        let new_value = match &entry.in_scope_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.in_scope_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let e = update_index!(
+            plan
+                .in_scope_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            scenario.plans(&identity_address)
+        );
+        hdk::prelude::debug!("handle_update_plan::in_scope_of index {:?}", e);
+    }
+
     construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
 }
 
-pub fn handle_delete_plan(address: ActionHash) -> RecordAPIResult<bool> {
-    delete_record::<EntryStorage>(&address)
+pub fn handle_delete_plan<S>(entry_def_id: S, address: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // load the record to ensure it is of the correct type
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&address)?;
+
+    // handle link fields
+    if let Some(scenario_address) = entry.in_scope_of {
+        let e = update_index!(plan.in_scope_of.not(&vec![scenario_address]), scenario.plans(&base_address));
+        hdk::prelude::debug!("handle_delete_plan::in_scope_of index {:?}", e);
+    }
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &address)
 }
 
 /// Create response from input DHT primitives
@@ -81,6 +144,7 @@ fn construct_response<'a>(
             due: e.due.to_owned(),
             note: e.note.to_owned(),
             deletable: e.deletable.to_owned(),
+            in_scope_of: e.in_scope_of.to_owned(),
             processes: processes.to_owned(),
             independent_demands: independent_demands.to_owned(),
         }
@@ -94,6 +158,11 @@ fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.plan.index_zome)
 }
 
+/// Properties accessor for zome config
+fn read_scenario_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.plan.scenario_index_zome
+}
+
 // @see construct_response
 fn get_link_fields(base_address: &PlanAddress) -> RecordAPIResult<(
     Vec<ProcessAddress>,
@@ -11,12 +11,15 @@ use holochain_serialized_bytes::prelude::*;
 use serde_maybe_undefined::MaybeUndefined;
 pub use vf_attributes_hdk::{
     PlanAddress,
+    ScenarioAddress,
     CommitmentAddress,
     ProcessAddress,
     EconomicEventAddress,
     DateTime,
     FixedOffset,
     ByAction, ActionHash, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
 };
 
 //---------------- EXTERNAL RECORD STRUCTURE ----------------
@@ -39,6 +42,8 @@ pub struct Response {
     pub note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deletable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_scope_of: Option<ScenarioAddress>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub processes: Vec<ProcessAddress>,
@@ -79,7 +84,10 @@ pub struct CreateRequest {
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub deletable: MaybeUndefined<bool>,
-    // exclude `refinementOf` because it relates to Scenario, which is out of MMR scope
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub in_scope_of: MaybeUndefined<ScenarioAddress>,
+    // :TODO: `refinementOf`, the Scenario which this Plan refines
     // #[serde(default)]
     // #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     // pub refinementOf: MaybeUndefined<ScenarioAddress>,
@@ -112,7 +120,10 @@ pub struct UpdateRequest {
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub deletable: MaybeUndefined<bool>,
-    // exclude `refinementOf` because it relates to Scenario, which is out of MMR scope
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub in_scope_of: MaybeUndefined<ScenarioAddress>,
+    // :TODO: `refinementOf`, the Scenario which this Plan refines
     // #[serde(default)]
     // #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     // pub refinementOf: MaybeUndefined<ScenarioAddress>,
@@ -126,6 +137,33 @@ impl<'a> UpdateRequest {
     // :TODO: accessors for other field data
 }
 
+//---------------- TEMPLATING REQUEST ----------------
+
+/// Parameters for creating a new `Plan` as a scaled copy of an existing one
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InstantiateParams {
+    pub plan: PlanAddress,
+    pub scale_factor: f64,
+    pub new_due: DateTime<FixedOffset>,
+}
+
+/// Result of instantiating a `Plan` from a template.
+///
+/// If copying fails partway through, `error` describes the failure and the IDs of all records
+/// created so far are returned, so that the caller can complete or remove the partial copy.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InstantiateResponse {
+    pub plan: Response,
+    pub processes: Vec<ProcessAddress>,
+    pub commitments: Vec<CommitmentAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 //---------------- QUERY FILTER REQUEST ----------------
 
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
@@ -134,4 +172,5 @@ pub struct QueryParams {
     pub processes: Option<ProcessAddress>,
     pub non_process_commitments: Option<CommitmentAddress>,
     pub independent_demands: Option<CommitmentAddress>,
+    pub in_scope_of: Option<ScenarioAddress>,
 }
@@ -6,3 +6,8 @@
  * @package hREA
  */
 pub const PLAN_ENTRY_TYPE: &str = "plan";
+
+pub const PROCESS_READ_API_METHOD: &str = "read_process";
+pub const PROCESS_CREATE_API_METHOD: &str = "create_process";
+pub const COMMITMENT_READ_API_METHOD: &str = "read_commitment";
+pub const COMMITMENT_CREATE_API_METHOD: &str = "create_commitment";
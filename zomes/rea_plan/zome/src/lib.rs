@@ -38,6 +38,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<PlanAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -49,7 +59,24 @@ fn update_plan(UpdateParams { plan }: UpdateParams) -> ExternResult<ResponseData
     Ok(handle_update_plan(plan)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub plan: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_plan_conflict(ResolveConflictParams { conflicting_revisions, plan }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_plan_conflict(conflicting_revisions, plan)?)
+}
+
 #[hdk_extern]
 fn delete_plan(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_plan(revision_id)?)
+    Ok(handle_delete_plan(PLAN_ENTRY_TYPE, revision_id)?)
+}
+
+#[hdk_extern]
+fn instantiate_plan_from(InstantiateParams { plan, scale_factor, new_due }: InstantiateParams) -> ExternResult<InstantiateResponse> {
+    Ok(handle_instantiate_plan_from(plan, scale_factor, new_due)?)
 }
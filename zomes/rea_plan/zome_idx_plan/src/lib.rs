@@ -12,4 +12,5 @@ struct Plan {
     processes: Remote<process, planned_within>,
     non_process_commitments: Remote<commitment, planned_within>,
     independent_demands: Remote<commitment, independent_demand_of>,
+    in_scope_of: Local<scenario, plans>,
 }
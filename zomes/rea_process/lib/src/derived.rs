@@ -0,0 +1,147 @@
+/**
+ * Derived process fields
+ *
+ * Computes `unplanned_economic_events` & `working_agents` from the flows recorded
+ * against a `Process`. Neither is stored, and both involve reading every related
+ * flow- so rather than being recalculated on every `Process` read they are served
+ * by their own zome API methods, to be requested only when needed.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::read_record_entry,
+    rpc::call_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+use vf_attributes_hdk::{
+    ByAddress,
+    FulfillmentAddress,
+};
+
+use hc_zome_rea_process_storage_consts::*;
+use hc_zome_rea_process_storage::*;
+use hc_zome_rea_process_rpc::*;
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData, EntryStorage as EventStorage,
+    DnaConfigSlice as EventDnaConfigSlice,
+};
+use hc_zome_rea_fulfillment_storage::{EntryData as FulfillmentData, EntryStorage as FulfillmentStorage};
+use hc_zome_rea_commitment_rpc::{
+    ResponseData as CommitmentResponseData,
+};
+
+/// Read the observed flows of a `Process` which do not fulfill any of its `Commitment`s,
+/// split into inputs & outputs.
+///
+pub fn handle_get_unplanned_economic_events(process: &ProcessAddress) -> RecordAPIResult<UnplannedEconomicEvents>
+{
+    let observed_inputs: Vec<EconomicEventAddress> = read_index!(process(process).observed_inputs)?;
+    let observed_outputs: Vec<EconomicEventAddress> = read_index!(process(process).observed_outputs)?;
+    let committed = read_commitments(process)?;
+
+    Ok(UnplannedEconomicEvents {
+        unplanned_inputs: read_unplanned_economic_events(&observed_inputs, &committed)?,
+        unplanned_outputs: read_unplanned_economic_events(&observed_outputs, &committed)?,
+    })
+}
+
+/// Read the distinct agents involved in a `Process`.
+///
+/// `Commitment`s are held in the planning DNA. If any of them cannot be read the agents
+/// which could be determined are still returned, with the failures listed in `errors`.
+///
+pub fn handle_get_working_agents(process: &ProcessAddress) -> RecordAPIResult<WorkingAgents>
+{
+    let observed_inputs: Vec<EconomicEventAddress> = read_index!(process(process).observed_inputs)?;
+    let committed = read_commitments(process)?;
+
+    let (working_agents, errors) = read_working_agents(&observed_inputs, &committed)?;
+    Ok(WorkingAgents { working_agents, errors })
+}
+
+fn read_commitments(process: &ProcessAddress) -> RecordAPIResult<Vec<CommitmentAddress>>
+{
+    let committed_inputs: Vec<CommitmentAddress> = read_index!(process(process).committed_inputs)?;
+    let committed_outputs: Vec<CommitmentAddress> = read_index!(process(process).committed_outputs)?;
+    Ok([committed_inputs.as_slice(), committed_outputs.as_slice()].concat())
+}
+
+/// Observed flows which do not fulfill any of the given `Commitment`s- i.e. activity
+/// against a `Process` which happened outside of its plan.
+///
+fn read_unplanned_economic_events(observed: &[EconomicEventAddress], committed: &[CommitmentAddress]) -> RecordAPIResult<Vec<EconomicEventAddress>>
+{
+    let mut unplanned = vec![];
+    for event in observed {
+        let fulfillments: Vec<FulfillmentAddress> = read_index!(economic_event(event).fulfills)?;
+        let mut planned = false;
+        for fulfillment in fulfillments {
+            let (_meta, _base_address, entry) = read_record_entry::<FulfillmentData, FulfillmentStorage, _>(fulfillment.as_ref())?;
+            if committed.contains(&entry.fulfills) {
+                planned = true;
+                break;
+            }
+        }
+        if !planned && !unplanned.contains(event) {
+            unplanned.push(event.to_owned());
+        }
+    }
+    Ok(unplanned)
+}
+
+/// Distinct agents involved in a `Process`- the providers of any `work` it received,
+/// and both parties to each of its `Commitment`s.
+///
+/// Returns the agents found, along with a description of any `Commitment` which could not be read.
+///
+fn read_working_agents(observed_inputs: &[EconomicEventAddress], committed: &[CommitmentAddress]) -> RecordAPIResult<(Vec<AgentAddress>, Vec<String>)>
+{
+    let mut agents = vec![];
+    let mut errors = vec![];
+    for event in observed_inputs {
+        let (_meta, _base_address, entry) = read_record_entry::<EventData, EventStorage, _>(event.as_ref())?;
+        if entry.action.as_ref() == "work" {
+            agents.push(entry.provider);
+        }
+    }
+    for commitment in committed {
+        match read_commitment(commitment) {
+            Ok(resp) => {
+                agents.push(resp.commitment.provider);
+                agents.push(resp.commitment.receiver);
+            },
+            Err(e) => errors.push(format!("Unable to read Commitment {:?}: {}", commitment, e)),
+        }
+    }
+
+    let mut unique = vec![];
+    for agent in agents {
+        if !unique.contains(&agent) {
+            unique.push(agent);
+        }
+    }
+    Ok((unique, errors))
+}
+
+/// Read a `Commitment` from the planning DNA
+pub (crate) fn read_commitment(commitment: &CommitmentAddress) -> RecordAPIResult<CommitmentResponseData>
+{
+    Ok(call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+        commitment,
+        &COMMITMENT_READ_API_METHOD,
+        ByAddress { address: commitment.to_owned() },
+        LinkTypes::AvailableCapability,
+    )?)
+}
+
+/// Properties accessor for zome config
+fn read_economic_event_index_zome(conf: EventDnaConfigSlice) -> Option<String> {
+    Some(conf.economic_event.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.process.index_zome)
+}
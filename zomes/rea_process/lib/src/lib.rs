@@ -13,10 +13,12 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
     MaybeUndefined, SignedActionHashed,
 };
 use hdk_semantic_indexes_client_lib::*;
@@ -24,6 +26,12 @@ use hdk_semantic_indexes_client_lib::*;
 use hc_zome_rea_process_storage::*;
 use hc_zome_rea_process_rpc::*;
 
+mod sequencing;
+pub use sequencing::handle_sync_process_sequence;
+use sequencing::clear_process_sequence;
+mod derived;
+pub use derived::{handle_get_unplanned_economic_events, handle_get_working_agents};
+
 
 /// properties accessor for zome config
 fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
@@ -41,6 +49,10 @@ pub fn handle_create_process<S>(entry_def_id: S, process: CreateRequest) -> Reco
         let e = create_index!(process.planned_within(planned_within), plan.processes(&base_address));
         hdk::prelude::debug!("handle_create_process::planned_within index {:?}", e);
     };
+    if let CreateRequest { in_scope_of: MaybeUndefined::Some(in_scope_of), .. } = &process {
+        let e = create_index!(process.in_scope_of(in_scope_of), scenario.processes(&base_address));
+        hdk::prelude::debug!("handle_create_process::in_scope_of index {:?}", e);
+    };
 
     // :TODO: pass results from link creation rather than re-reading
     construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
@@ -49,19 +61,45 @@ pub fn handle_create_process<S>(entry_def_id: S, process: CreateRequest) -> Reco
 pub fn handle_get_process(address: ProcessAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+    response.process.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.process.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ProcessAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ProcessAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.process)
+    })
 }
 
 pub fn handle_update_process(process: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_process(None, process)
+}
+
+pub fn handle_resolve_process_conflict(conflicting_revisions: Vec<ActionHash>, process: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_process(Some(&conflicting_revisions), process)
+}
+
+fn update_or_resolve_process(conflicting_revisions: Option<&[ActionHash]>, process: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let address = process.get_revision_id().clone();
-    let (meta, base_address, new_entry, prev_entry): (_,_, EntryData, EntryData) = update_record(&address, process)?;
+    let (meta, base_address, new_entry, prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, process)?;
 
     // handle link fields
     if new_entry.planned_within != prev_entry.planned_within {
@@ -75,10 +113,27 @@ pub fn handle_update_process(process: UpdateRequest) -> RecordAPIResult<Response
         );
         hdk::prelude::debug!("handle_update_process::planned_within index {:?}", e);
     }
+    if new_entry.in_scope_of != prev_entry.in_scope_of {
+        let new_value = match &new_entry.in_scope_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.in_scope_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let e = update_index!(
+            process
+                .in_scope_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            scenario.processes(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_process::in_scope_of index {:?}", e);
+    }
+    // moving between plans changes which processes this one may be sequenced with
+    if new_entry.planned_within != prev_entry.planned_within {
+        let e = handle_sync_process_sequence(&base_address);
+        hdk::prelude::debug!("handle_update_process::sequencing {:?}", e);
+    }
     construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
 }
 
-pub fn handle_delete_process(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_process<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     // load the record to ensure it is of the correct type
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
@@ -88,28 +143,28 @@ pub fn handle_delete_process(revision_id: ActionHash) -> RecordAPIResult<bool>
         let e = update_index!(process.planned_within.not(&vec![plan_address]), plan.processes(&base_address));
         hdk::prelude::debug!("handle_delete_process::planned_within index {:?}", e);
     }
+    if let Some(scenario_address) = entry.in_scope_of {
+        let e = update_index!(process.in_scope_of.not(&vec![scenario_address]), scenario.processes(&base_address));
+        hdk::prelude::debug!("handle_delete_process::in_scope_of index {:?}", e);
+    }
+    let e = clear_process_sequence(&base_address);
+    hdk::prelude::debug!("handle_delete_process::sequencing {:?}", e);
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 /// Create response from input DHT primitives
 fn construct_response<'a>(
     address: &ProcessAddress, meta: &SignedActionHashed, e: &EntryData, (
         observed_inputs, observed_outputs,
-        unplanned_economic_events,
         committed_inputs, committed_outputs,
         intended_inputs, intended_outputs,
         next_processes, previous_processes,
-        working_agents,
-        trace, track,
      ): (
         Vec<EconomicEventAddress>, Vec<EconomicEventAddress>,
-        Vec<EconomicEventAddress>,
         Vec<CommitmentAddress>, Vec<CommitmentAddress>,
         Vec<IntentAddress>, Vec<IntentAddress>,
         Vec<ProcessAddress>, Vec<ProcessAddress>,
-        Vec<AgentAddress>,
-        Vec<EconomicEventAddress>, Vec<EconomicEventAddress>,
     ),
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
@@ -134,16 +189,12 @@ fn construct_response<'a>(
             // link fields
             observed_inputs: observed_inputs.to_owned(),
             observed_outputs: observed_outputs.to_owned(),
-            unplanned_economic_events: unplanned_economic_events.to_owned(),
             committed_inputs: committed_inputs.to_owned(),
             committed_outputs: committed_outputs.to_owned(),
             intended_inputs: intended_inputs.to_owned(),
             intended_outputs: intended_outputs.to_owned(),
             next_processes: next_processes.to_owned(),
             previous_processes: previous_processes.to_owned(),
-            working_agents: working_agents.to_owned(),
-            trace: trace.to_owned(),
-            track: track.to_owned(),
         }
     })
 }
@@ -158,10 +209,13 @@ fn read_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
 fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
     conf.process.plan_index_zome
 }
+/// Properties accessor for zome config
+fn read_scenario_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.process.scenario_index_zome
+}
 
 // @see construct_response
 fn get_link_fields(process: &ProcessAddress) -> RecordAPIResult<(
-    Vec<EconomicEventAddress>,
     Vec<EconomicEventAddress>,
     Vec<EconomicEventAddress>,
     Vec<CommitmentAddress>,
@@ -170,22 +224,15 @@ fn get_link_fields(process: &ProcessAddress) -> RecordAPIResult<(
     Vec<IntentAddress>,
     Vec<ProcessAddress>,
     Vec<ProcessAddress>,
-    Vec<AgentAddress>,
-    Vec<EconomicEventAddress>,
-    Vec<EconomicEventAddress>,
 )> {
     Ok((
         read_index!(process(process).observed_inputs)?,
         read_index!(process(process).observed_outputs)?,
-        vec![],  // :TODO: unplanned_economic_events
         read_index!(process(process).committed_inputs)?,
         read_index!(process(process).committed_outputs)?,
         read_index!(process(process).intended_inputs)?,
         read_index!(process(process).intended_outputs)?,
-        vec![], // :TODO: next_processes
-        vec![], // :TODO: previous_processes
-        vec![], // :TODO: working_agents
-        vec![], // :TODO: trace
-        vec![], // :TODO: track
+        read_index!(process(process).next_processes)?,
+        read_index!(process(process).previous_processes)?,
     ))
 }
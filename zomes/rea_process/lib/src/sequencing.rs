@@ -0,0 +1,231 @@
+/**
+ * Process sequencing
+ *
+ * Determines which `Process`es feed into one another. Process A precedes process B
+ * when an output flow of A (an `EconomicEvent`, `Commitment` or `Intent`) refers to the
+ * same resource as an input flow of B- either the same `EconomicResource`, or the same
+ * `ResourceSpecification` where no specific resource is given.
+ *
+ * Candidate processes are discovered via the `EconomicResource`s affected by observed
+ * flows, and amongst the other `Process`es planned within the same `Plan`. Commitments,
+ * intents and plans live in other DNAs and are read via remote calls.
+ *
+ * Results are stored in the self-referential `next_processes` & `previous_processes`
+ * indexes so that they can be used as query filters. `handle_sync_process_sequence`
+ * must be run against a `Process` whenever any flow into or out of it changes.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::read_record_entry,
+    rpc::call_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+use vf_attributes_hdk::{
+    ByAddress,
+    EconomicResourceAddress,
+    ResourceSpecificationAddress,
+};
+
+use hc_zome_rea_process_storage_consts::*;
+use hc_zome_rea_process_storage::*;
+use hc_zome_rea_process_rpc::*;
+use crate::derived::read_commitment;
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData,
+    EntryStorage as EventStorage,
+    DnaConfigSlice as EventDnaConfigSlice,
+};
+use hc_zome_rea_economic_resource_storage::DnaConfigSlice as ResourceDnaConfigSlice;
+use hc_zome_rea_intent_rpc::{
+    ResponseData as IntentResponseData,
+};
+use hc_zome_rea_plan_rpc::{
+    ResponseData as PlanResponseData,
+};
+
+/// The resource moved by a flow, by which outputs of one process are matched to inputs of another
+#[derive(Debug, Clone, PartialEq)]
+enum FlowResource {
+    Inventoried(EconomicResourceAddress),
+    Conforming(ResourceSpecificationAddress),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlowDirection {
+    Input,
+    Output,
+}
+
+/// Recompute the `next_processes` & `previous_processes` of `process` from its current flows.
+/// Processes on the other side of each relationship are updated reciprocally.
+///
+pub fn handle_sync_process_sequence(process: &ProcessAddress) -> RecordAPIResult<()>
+{
+    let (_meta, _base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(process.as_ref())?;
+
+    let outputs = read_flow_resources(process, FlowDirection::Output)?;
+    let inputs = read_flow_resources(process, FlowDirection::Input)?;
+
+    let mut next_processes = vec![];
+    let mut previous_processes = vec![];
+    for other in find_candidate_processes(&entry, &outputs, &inputs)? {
+        if other == *process {
+            continue;
+        }
+        if is_fed_by(&read_flow_resources(&other, FlowDirection::Input)?, &outputs) {
+            next_processes.push(other.to_owned());
+        }
+        if is_fed_by(&inputs, &read_flow_resources(&other, FlowDirection::Output)?) {
+            previous_processes.push(other);
+        }
+    }
+
+    let prev_next_processes: Vec<ProcessAddress> = read_index!(process(process).next_processes)?;
+    let e = update_index!(process(process)
+        .next_processes(difference(&next_processes, &prev_next_processes).as_slice())
+        .not(difference(&prev_next_processes, &next_processes).as_slice()));
+    hdk::prelude::debug!("handle_sync_process_sequence::next_processes index {:?}", e);
+
+    let prev_previous_processes: Vec<ProcessAddress> = read_index!(process(process).previous_processes)?;
+    let e = update_index!(process(process)
+        .previous_processes(difference(&previous_processes, &prev_previous_processes).as_slice())
+        .not(difference(&prev_previous_processes, &previous_processes).as_slice()));
+    hdk::prelude::debug!("handle_sync_process_sequence::previous_processes index {:?}", e);
+
+    Ok(())
+}
+
+/// Remove all sequencing links for `process`, prior to its deletion
+///
+pub fn clear_process_sequence(process: &ProcessAddress) -> RecordAPIResult<()>
+{
+    let next_processes: Vec<ProcessAddress> = read_index!(process(process).next_processes)?;
+    let e = update_index!(process(process).next_processes.not(next_processes.as_slice()));
+    hdk::prelude::debug!("clear_process_sequence::next_processes index {:?}", e);
+
+    let previous_processes: Vec<ProcessAddress> = read_index!(process(process).previous_processes)?;
+    let e = update_index!(process(process).previous_processes.not(previous_processes.as_slice()));
+    hdk::prelude::debug!("clear_process_sequence::previous_processes index {:?}", e);
+
+    Ok(())
+}
+
+/// Processes which may be connected to the given one- those planned within the same `Plan`,
+/// and those with observed flows affecting the same `EconomicResource`s.
+///
+fn find_candidate_processes(process: &EntryData, outputs: &Vec<FlowResource>, inputs: &Vec<FlowResource>) -> RecordAPIResult<Vec<ProcessAddress>>
+{
+    let mut candidates = match &process.planned_within {
+        Some(plan) => read_plan_processes(plan)?,
+        None => vec![],
+    };
+
+    for flow in outputs.iter().chain(inputs.iter()) {
+        if let FlowResource::Inventoried(resource) = flow {
+            let events: Vec<EconomicEventAddress> = read_index!(economic_resource(resource).affected_by)?;
+            for event in events {
+                let (_meta, _base_address, entry) = read_record_entry::<EventData, EventStorage, _>(event.as_ref())?;
+                candidates.extend(entry.input_of);
+                candidates.extend(entry.output_of);
+            }
+        }
+    }
+
+    let mut unique = vec![];
+    for candidate in candidates {
+        if !unique.contains(&candidate) {
+            unique.push(candidate);
+        }
+    }
+    Ok(unique)
+}
+
+/// Collect the resources referenced by all input or output flows of `process`
+///
+fn read_flow_resources(process: &ProcessAddress, direction: FlowDirection) -> RecordAPIResult<Vec<FlowResource>>
+{
+    let (events, commitments, intents): (Vec<EconomicEventAddress>, Vec<CommitmentAddress>, Vec<IntentAddress>) = match direction {
+        FlowDirection::Input => (
+            read_index!(process(process).observed_inputs)?,
+            read_index!(process(process).committed_inputs)?,
+            read_index!(process(process).intended_inputs)?,
+        ),
+        FlowDirection::Output => (
+            read_index!(process(process).observed_outputs)?,
+            read_index!(process(process).committed_outputs)?,
+            read_index!(process(process).intended_outputs)?,
+        ),
+    };
+
+    let mut resources = vec![];
+    for event in events {
+        let (_meta, base_address, entry) = read_record_entry::<EventData, EventStorage, _>(event.as_ref())?;
+        // events which created a resource only reference it via the `affects` index
+        let resource = entry.get_inventoried_resource(&read_index!(economic_event(&base_address).affects)?);
+        resources.extend(flow_resource(resource, entry.resource_conforms_to));
+    }
+    for commitment in commitments {
+        let resp = read_commitment(&commitment)?;
+        resources.extend(flow_resource(resp.commitment.resource_inventoried_as, resp.commitment.resource_conforms_to));
+    }
+    for intent in intents {
+        let resp: IntentResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+            &intent,
+            &INTENT_READ_API_METHOD,
+            ByAddress { address: intent.to_owned() },
+            LinkTypes::AvailableCapability,
+        )?;
+        resources.extend(flow_resource(resp.intent.resource_inventoried_as, resp.intent.resource_conforms_to));
+    }
+
+    Ok(resources)
+}
+
+/// A specific resource takes precedence over its specification when matching flows
+fn flow_resource(inventoried_as: Option<EconomicResourceAddress>, conforms_to: Option<ResourceSpecificationAddress>) -> Option<FlowResource>
+{
+    match (inventoried_as, conforms_to) {
+        (Some(resource), _) => Some(FlowResource::Inventoried(resource)),
+        (None, Some(spec)) => Some(FlowResource::Conforming(spec)),
+        (None, None) => None,
+    }
+}
+
+fn is_fed_by(inputs: &Vec<FlowResource>, outputs: &Vec<FlowResource>) -> bool
+{
+    inputs.iter().any(|input| outputs.contains(input))
+}
+
+fn difference(a: &Vec<ProcessAddress>, b: &Vec<ProcessAddress>) -> Vec<ProcessAddress>
+{
+    a.iter().filter(|p| !b.contains(p)).cloned().collect()
+}
+
+fn read_plan_processes(plan: &PlanAddress) -> RecordAPIResult<Vec<ProcessAddress>>
+{
+    let resp: PlanResponseData = call_zome_method::<EntryTypes, _, _, _, _, _, _, _>(
+        plan,
+        &PLAN_READ_API_METHOD,
+        ByAddress { address: plan.to_owned() },
+        LinkTypes::AvailableCapability,
+    )?;
+    Ok(resp.plan.processes)
+}
+
+/// Properties accessor for zome config
+fn read_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.process.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_economic_resource_index_zome(conf: ResourceDnaConfigSlice) -> Option<String> {
+    Some(conf.economic_resource.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_economic_event_index_zome(conf: EventDnaConfigSlice) -> Option<String> {
+    Some(conf.economic_event.index_zome)
+}
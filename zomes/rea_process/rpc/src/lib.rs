@@ -9,11 +9,14 @@ use serde_maybe_undefined::{
 };
 pub use vf_attributes_hdk::{
     ActionHash, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     ProcessAddress,
     DateTime, FixedOffset,
     ExternalURL,
     ProcessSpecificationAddress,
     PlanAddress,
+    ScenarioAddress,
     EconomicEventAddress,
     CommitmentAddress,
     IntentAddress,
@@ -47,7 +50,7 @@ pub struct Response {
     pub finished: bool,
     pub deletable: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub in_scope_of: Option<Vec<String>>,
+    pub in_scope_of: Option<ScenarioAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
 
@@ -60,9 +63,6 @@ pub struct Response {
     pub observed_outputs: Vec<EconomicEventAddress>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub unplanned_economic_events: Vec<EconomicEventAddress>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub committed_inputs: Vec<CommitmentAddress>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -79,15 +79,9 @@ pub struct Response {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub previous_processes: Vec<ProcessAddress>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub working_agents: Vec<AgentAddress>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub trace: Vec<EconomicEventAddress>,
-    #[serde(default)]
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub track: Vec<EconomicEventAddress>,
+    // :NOTE: `unplanned_economic_events` & `working_agents` are derived from every related flow, and so served
+    // by `get_unplanned_economic_events` & `get_working_agents` rather than computed on each read.
+    // `trace` & `track` are paginated, and so served by `trace_process` & `track_process` in the EconomicEvent zome.
 }
 
 /// I/O struct to describe what is returned outside the gateway
@@ -97,8 +91,38 @@ pub struct ResponseData {
     pub process: Response,
 }
 
+//---------------- DERIVED FIELDS ----------------
+
+/// Observed flows of a `Process` which do not fulfill any of its `Commitment`s
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnplannedEconomicEvents {
+    #[serde(default)]
+    pub unplanned_inputs: Vec<EconomicEventAddress>,
+    #[serde(default)]
+    pub unplanned_outputs: Vec<EconomicEventAddress>,
+}
+
+/// Distinct agents involved in a `Process`, along with any `Commitment`s
+/// which could not be read from the planning DNA whilst determining them
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkingAgents {
+    #[serde(default)]
+    pub working_agents: Vec<AgentAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
 //---------------- CREATE REQUEST ----------------
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub process: CreateRequest,
+}
+
 /// I/O struct to describe the complete input record, including all managed links
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -121,7 +145,7 @@ pub struct CreateRequest {
     #[serde(default = "default_false")]
     pub finished: MaybeUndefined<bool>,
     #[serde(default)]
-    pub in_scope_of: MaybeUndefined<Vec<String>>,
+    pub in_scope_of: MaybeUndefined<ScenarioAddress>,
     #[serde(default)]
     pub note: MaybeUndefined<String>,
 }
@@ -156,7 +180,7 @@ pub struct UpdateRequest {
     #[serde(default)]
     pub finished: MaybeUndefined<bool>,
     #[serde(default)]
-    pub in_scope_of: MaybeUndefined<Vec<String>>,
+    pub in_scope_of: MaybeUndefined<ScenarioAddress>,
     #[serde(default)]
     pub note: MaybeUndefined<String>,
 }
@@ -183,4 +207,7 @@ pub struct QueryParams {
     pub intended_outputs: Option<IntentAddress>,
     pub working_agents: Option<AgentAddress>,
     pub planned_within: Option<PlanAddress>,
+    pub in_scope_of: Option<ScenarioAddress>,
+    pub next_processes: Option<ProcessAddress>,
+    pub previous_processes: Option<ProcessAddress>,
 }
@@ -22,6 +22,7 @@ use vf_attributes_hdk::{
     ExternalURL,
     ProcessSpecificationAddress,
     PlanAddress,
+    ScenarioAddress,
 };
 
 use hc_zome_rea_process_rpc::{ CreateRequest, UpdateRequest };
@@ -38,6 +39,7 @@ pub struct DnaConfigSlice {
 pub struct ProcessZomeConfig {
     pub index_zome: String,
     pub plan_index_zome: Option<String>,
+    pub scenario_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -53,7 +55,7 @@ pub struct EntryData {
     pub based_on: Option<ProcessSpecificationAddress>,
     pub planned_within: Option<PlanAddress>,
     pub finished: bool,
-    pub in_scope_of: Option<Vec<String>>,
+    pub in_scope_of: Option<ScenarioAddress>,
     pub note: Option<String>,
     pub _nonce: Bytes,
 }
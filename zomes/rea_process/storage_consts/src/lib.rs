@@ -6,3 +6,7 @@
  * @package hREA
  */
 pub const PROCESS_ENTRY_TYPE: &str = "process";
+
+pub const PLAN_READ_API_METHOD: &str = "read_plan";
+pub const COMMITMENT_READ_API_METHOD: &str = "read_commitment";
+pub const INTENT_READ_API_METHOD: &str = "read_intent";
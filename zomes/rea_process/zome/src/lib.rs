@@ -13,12 +13,6 @@ use hc_zome_rea_process_storage_consts::*;
 use hc_zome_rea_process_lib::*;
 use hc_zome_rea_process_rpc::*;
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct CreateParams {
-    pub process: CreateRequest,
-}
-
 #[hdk_extern]
 fn create_process(CreateParams { process }: CreateParams) -> ExternResult<ResponseData> {
     Ok(handle_create_process(PROCESS_ENTRY_TYPE, process)?)
@@ -34,11 +28,36 @@ fn get_process(ReadParams { address }: ReadParams) -> ExternResult<ResponseData>
     Ok(handle_get_process(address)?)
 }
 
+#[hdk_extern]
+fn get_unplanned_economic_events(ReadParams { address }: ReadParams) -> ExternResult<UnplannedEconomicEvents> {
+    Ok(handle_get_unplanned_economic_events(&address)?)
+}
+
+#[hdk_extern]
+fn get_working_agents(ReadParams { address }: ReadParams) -> ExternResult<WorkingAgents> {
+    Ok(handle_get_working_agents(&address)?)
+}
+
+#[hdk_extern]
+fn sync_process_sequence(ReadParams { address }: ReadParams) -> ExternResult<()> {
+    Ok(handle_sync_process_sequence(&address)?)
+}
+
 #[hdk_extern]
 fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ProcessAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -50,7 +69,19 @@ fn update_process(UpdateParams { process }: UpdateParams) -> ExternResult<Respon
     Ok(handle_update_process(process)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub process: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_process_conflict(ResolveConflictParams { conflicting_revisions, process }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_process_conflict(conflicting_revisions, process)?)
+}
+
 #[hdk_extern]
 fn delete_process(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_process(revision_id)?)
+    Ok(handle_delete_process(PROCESS_ENTRY_TYPE, revision_id)?)
 }
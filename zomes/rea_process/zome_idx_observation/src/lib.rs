@@ -16,4 +16,7 @@ struct Process {
     intended_inputs: Remote<intent, input_of>,
     intended_outputs: Remote<intent, output_of>,
     planned_within: Local<plan, processes>,
+    in_scope_of: Local<scenario, processes>,
+    next_processes: Local<process, previous_processes>,
+    previous_processes: Local<process, next_processes>,
 }
@@ -28,12 +28,34 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ProcessSpecificationAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn update_process_specification(UpdateParams { process_specification }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_process_specification(process_specification)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub process_specification: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_process_specification_conflict(ResolveConflictParams { conflicting_revisions, process_specification }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_process_specification_conflict(conflicting_revisions, process_specification)?)
+}
+
 #[hdk_extern]
 fn delete_process_specification(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_process_specification(revision_id)?)
+    Ok(handle_delete_process_specification(PROCESS_SPECIFICATION_ENTRY_TYPE, revision_id)?)
 }
@@ -6,8 +6,9 @@
  */
 use hdk_semantic_indexes_zome_derive::index_zome;
 use hc_zome_rea_process_specification_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
 
 #[index_zome]
 struct ProcessSpecification {
-    // :NOTE: blank means only the `read_all_` and `register_new_` APIs will be generated
+    recipe_processes: Local<recipe_process, process_conforms_to>,
 }
@@ -0,0 +1,176 @@
+/**
+ * hREA product batch zome library API
+ *
+ * Contains helper methods that can be used to manipulate `ProductBatch` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+    rpc::call_local_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_product_batch_storage::*;
+use hc_zome_rea_product_batch_rpc::*;
+use hc_zome_rea_economic_event_rpc::{TraceParams, TraceResults};
+
+pub use hc_zome_rea_product_batch_storage::PRODUCT_BATCH_ENTRY_TYPE;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.product_batch.index_zome)
+}
+
+pub fn handle_create_product_batch<S>(entry_def_id: S, product_batch: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, product_batch)?;
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_product_batch(address: ProductBatchAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.product_batch.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.product_batch.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ProductBatchAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ProductBatchAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.product_batch)
+    })
+}
+
+pub fn handle_update_product_batch(product_batch: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_product_batch(None, product_batch)
+}
+
+pub fn handle_resolve_product_batch_conflict(conflicting_revisions: Vec<ActionHash>, product_batch: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_product_batch(Some(&conflicting_revisions), product_batch)
+}
+
+fn update_or_resolve_product_batch(conflicting_revisions: Option<&[ActionHash]>, product_batch: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let revision_hash = product_batch.get_revision_id().clone();
+    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, product_batch)?;
+    construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
+}
+
+pub fn handle_delete_product_batch<S>(entry_def_id: S, address: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &address)
+}
+
+/// Find every `EconomicResource` in a batch, along with all resources derived from them,
+/// by tracking flows onward from each resource in the lot.
+///
+pub fn handle_recall_product_batch(params: RecallParams) -> RecordAPIResult<RecallResults>
+{
+    let lot_resources: Vec<EconomicResourceAddress> = read_index!(product_batch(&params.address).economic_resources)?;
+    let mut economic_resources: Vec<EconomicResourceAddress> = vec![];
+
+    for resource in lot_resources {
+        if !economic_resources.contains(&resource) {
+            economic_resources.push(resource.to_owned());
+        }
+
+        // read all pages of downstream flows from the resource
+        let mut after = None;
+        loop {
+            let page: TraceResults = call_local_zome_method(
+                read_economic_resource_zome,
+                RESOURCE_TRACK_API_METHOD,
+                TraceParams {
+                    address: resource.to_owned(),
+                    max_depth: params.max_depth,
+                    first: None,
+                    after,
+                },
+            )?;
+            for derived in page.resources {
+                if !economic_resources.contains(&derived) {
+                    economic_resources.push(derived);
+                }
+            }
+            if !page.has_next_page {
+                break;
+            }
+            after = page.events.last().cloned();
+        }
+    }
+
+    Ok(RecallResults { economic_resources })
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &ProductBatchAddress, meta: &SignedActionHashed, e: &EntryData, (
+        economic_resources,
+    ): (
+        Vec<EconomicResourceAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        product_batch: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            batch_number: e.batch_number.to_owned(),
+            expiry_date: e.expiry_date.to_owned(),
+            production_date: e.production_date.to_owned(),
+            economic_resources: economic_resources.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_product_batch_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.product_batch.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_economic_resource_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.product_batch.economic_resource_zome
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &ProductBatchAddress) -> RecordAPIResult<(
+    Vec<EconomicResourceAddress>,
+)> {
+    Ok((
+        read_index!(product_batch(base_address).economic_resources)?,
+    ))
+}
@@ -0,0 +1,125 @@
+/**
+ * hREA product batch zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    ProductBatchAddress,
+    EconomicResourceAddress,
+    DateTime,
+    FixedOffset,
+    ActionHash,
+    ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: ProductBatchAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub batch_number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_date: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production_date: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub economic_resources: Vec<EconomicResourceAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub product_batch: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub batch_number: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub expiry_date: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub production_date: MaybeUndefined<DateTime<FixedOffset>>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub batch_number: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub expiry_date: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub production_date: MaybeUndefined<DateTime<FixedOffset>>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&self) -> ActionHash {
+        self.revision_id.to_owned().into()
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub economic_resources: Option<EconomicResourceAddress>,
+}
+
+//---------------- RECALL ----------------
+
+/// Parameters for finding all resources derived from a batch.
+/// Flows are tracked for up to `max_depth` hops from each resource in the lot.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallParams {
+    pub address: ProductBatchAddress,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallResults {
+    pub economic_resources: Vec<EconomicResourceAddress>,
+}
@@ -0,0 +1,133 @@
+/**
+ * hREA product batch zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    generate_record_entry,
+    record_interface::{Updateable},
+};
+
+use vf_attributes_hdk::{
+    DateTime,
+    FixedOffset,
+};
+
+use hc_zome_rea_product_batch_rpc::{ CreateRequest, UpdateRequest };
+
+pub use vf_attributes_hdk::ProductBatchAddress;
+pub use hc_zome_rea_product_batch_storage_consts::*;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub product_batch: ProductBatchZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct ProductBatchZomeConfig {
+    pub index_zome: String,
+    // zome ID of the `EconomicResource` zome used to track resources derived from each batch
+    pub economic_resource_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct EntryData {
+    pub batch_number: String,
+    pub expiry_date: Option<DateTime<FixedOffset>>,
+    pub production_date: Option<DateTime<FixedOffset>>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.batch_number.trim().is_empty() {
+            return Err("ProductBatch must have a batch number".into());
+        }
+        if let (Some(produced), Some(expires)) = (&self.production_date, &self.expiry_date) {
+            if expires < produced {
+                return Err("ProductBatch cannot expire before it was produced".into());
+            }
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, ProductBatchAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    ProductBatch(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::ProductBatch(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            batch_number: e.batch_number.into(),
+            expiry_date: e.expiry_date.into(),
+            production_date: e.production_date.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            batch_number: if !e.batch_number.is_some() { self.batch_number.to_owned() } else { e.batch_number.to_owned().unwrap() },
+            expiry_date: if e.expiry_date.is_undefined() { self.expiry_date.to_owned() } else { e.expiry_date.to_owned().into() },
+            production_date: if e.production_date.is_undefined() { self.production_date.to_owned() } else { e.production_date.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
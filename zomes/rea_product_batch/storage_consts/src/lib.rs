@@ -0,0 +1,10 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package hREA
+ */
+pub const PRODUCT_BATCH_ENTRY_TYPE: &str = "product_batch";
+
+pub const RESOURCE_TRACK_API_METHOD: &str = "track_economic_resource";
@@ -0,0 +1,12 @@
+/**
+ * Product batch query indexes for observation DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_product_batch_rpc::*;
+
+#[index_zome(query_fn_name="query_product_batches",read_all_fn_name="read_all_product_batches")]
+struct ProductBatch {
+    economic_resources: Local<economic_resource, lot>,
+}
@@ -14,9 +14,11 @@ use hdk_records::{
         delete_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -39,24 +41,52 @@ pub fn handle_create_proposal<S>(entry_def_id: S, proposal: CreateRequest) -> Re
 pub fn handle_get_proposal(address: ProposalAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.proposal.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.proposal.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ProposalAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ProposalAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.proposal)
+    })
 }
 
 pub fn handle_update_proposal(proposal: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_proposal(None, proposal)
+}
+
+pub fn handle_resolve_proposal_conflict(conflicting_revisions: Vec<ActionHash>, proposal: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_proposal(Some(&conflicting_revisions), proposal)
+}
+
+fn update_or_resolve_proposal(conflicting_revisions: Option<&[ActionHash]>, proposal: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let old_revision = proposal.get_revision_id().to_owned();
-    let (meta, base_address, new_entry, _prev_entry): (_, ProposalAddress, EntryData, EntryData) = update_record(&old_revision, proposal)?;
+    let (meta, base_address, new_entry, _prev_entry): (_, ProposalAddress, EntryData, EntryData) = update_or_resolve_record(&old_revision, conflicting_revisions, proposal)?;
     construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
 }
 
-pub fn handle_delete_proposal(address: ActionHash) -> RecordAPIResult<bool> {
-    delete_record::<EntryStorage>(&address)
+pub fn handle_delete_proposal<S>(entry_def_id: S, address: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &address)
 }
 
 /// Create response from input DHT primitives
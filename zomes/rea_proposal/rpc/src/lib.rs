@@ -10,6 +10,8 @@ use holochain_serialized_bytes::prelude::*;
 use serde_maybe_undefined::MaybeUndefined;
 pub use vf_attributes_hdk::{
     ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     ProposalAddress, ProposedIntentAddress, ProposedToAddress,
     DateTime, FixedOffset,
 };
@@ -28,12 +28,34 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ProposalAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn update_proposal(UpdateParams { proposal }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_proposal(proposal)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub proposal: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_proposal_conflict(ResolveConflictParams { conflicting_revisions, proposal }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_proposal_conflict(conflicting_revisions, proposal)?)
+}
+
 #[hdk_extern]
 fn delete_proposal(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_proposal(revision_id)?)
+    Ok(handle_delete_proposal(PROPOSAL_ENTRY_TYPE, revision_id)?)
 }
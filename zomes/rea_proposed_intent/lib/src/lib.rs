@@ -14,8 +14,9 @@ use hdk_records::{
         delete_record,
         read_record_entry,
         read_record_entry_by_action,
+        read_record_revision_history,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -44,10 +45,18 @@ pub fn handle_create_proposed_intent<S>(entry_def_id: S, proposed_intent: Create
 pub fn handle_get_proposed_intent(address: ProposedIntentAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.proposed_intent.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
-pub fn handle_delete_proposed_intent(revision_id: &ActionHash) -> RecordAPIResult<bool>
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ProposedIntentAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_delete_proposed_intent<S>(entry_def_id: S, revision_id: &ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
 
@@ -57,7 +66,7 @@ pub fn handle_delete_proposed_intent(revision_id: &ActionHash) -> RecordAPIResul
     hdk::prelude::debug!("handle_delete_proposed_intent::published_in index {:?}", r1);
 
     // manage record deletion
-    let res = delete_record::<EntryStorage>(&revision_id);
+    let res = delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id);
 
     // Update in associated foreign DNAs as well.
     // :TODO: If we caught errors here, foreign cells can also intervene in record deletion, and cause rollback.
@@ -9,6 +9,8 @@
 use holochain_serialized_bytes::prelude::*;
 pub use vf_attributes_hdk::{
     ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     ProposedIntentAddress, IntentAddress, ProposalAddress,
 };
 
@@ -23,7 +23,12 @@ fn get_proposed_intent(ByAddress { address }: ByAddress<ProposedIntentAddress>)
     Ok(handle_get_proposed_intent(address)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ProposedIntentAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
 #[hdk_extern]
 fn delete_proposed_intent(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_proposed_intent(&revision_id)?)
+    Ok(handle_delete_proposed_intent(PROPOSED_INTENT_ENTRY_TYPE, &revision_id)?)
 }
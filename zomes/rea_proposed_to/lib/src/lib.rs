@@ -14,8 +14,9 @@ use hdk_records::{
         delete_record,
         read_record_entry,
         read_record_entry_by_action,
+        read_record_revision_history,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -45,17 +46,25 @@ pub fn handle_create_proposed_to<S>(entry_def_id: S, proposed_to: CreateRequest)
 pub fn handle_get_proposed_to(address: ProposedToAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.proposed_to.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
-pub fn handle_delete_proposed_to(revision_id: &ActionHash) -> RecordAPIResult<bool>
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ProposedToAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_delete_proposed_to<S>(entry_def_id: S, revision_id: &ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
 
     let e = update_index!(proposed_to.proposed.not(&vec![entry.proposed]), proposal.published_to(&base_address));
     hdk::prelude::debug!("handle_delete_proposed_to::proposed index {:?}", e);
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 /// Create response from input DHT primitives
@@ -23,7 +23,12 @@ fn get_proposed_to(ByAddress { address }: ByAddress<ProposedToAddress>) -> Exter
     Ok(handle_get_proposed_to(address)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ProposedToAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
 #[hdk_extern]
 fn delete_proposed_to(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_proposed_to(&revision_id)?)
+    Ok(handle_delete_proposed_to(PROPOSED_TO_ENTRY_TYPE, &revision_id)?)
 }
@@ -0,0 +1,125 @@
+/**
+ * hREA recipe exchange zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeExchange` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_exchange_storage::*;
+use hc_zome_rea_recipe_exchange_rpc::*;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_exchange.index_zome)
+}
+
+pub fn handle_create_recipe_exchange<S>(entry_def_id: S, recipe_exchange: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, recipe_exchange)?;
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_recipe_exchange(address: RecipeExchangeAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.recipe_exchange.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.recipe_exchange.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<RecipeExchangeAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, RecipeExchangeAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.recipe_exchange)
+    })
+}
+
+pub fn handle_update_recipe_exchange(recipe_exchange: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_recipe_exchange(None, recipe_exchange)
+}
+
+pub fn handle_resolve_recipe_exchange_conflict(conflicting_revisions: Vec<ActionHash>, recipe_exchange: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_recipe_exchange(Some(&conflicting_revisions), recipe_exchange)
+}
+
+fn update_or_resolve_recipe_exchange(conflicting_revisions: Option<&[ActionHash]>, recipe_exchange: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let old_revision = recipe_exchange.get_revision_id();
+    let (meta, base_address, new_entry, _prev_entry): (_, RecipeExchangeAddress, EntryData, EntryData) = update_or_resolve_record(old_revision, conflicting_revisions, recipe_exchange.to_owned())?;
+    construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_recipe_exchange<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeExchangeAddress, meta: &SignedActionHashed, e: &EntryData, (
+        recipe_clauses,
+    ): (
+        Vec<RecipeFlowAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_exchange: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            name: e.name.to_owned(),
+            note: e.note.to_owned(),
+            recipe_clauses: recipe_clauses.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_exchange_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_exchange.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &RecipeExchangeAddress) -> RecordAPIResult<(
+    Vec<RecipeFlowAddress>,
+)> {
+    Ok((
+        read_index!(recipe_exchange(base_address).recipe_clauses)?,
+    ))
+}
@@ -0,0 +1,151 @@
+/**
+ * hREA recipe flow zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+use serde_maybe_undefined::MaybeUndefined;
+use vf_measurement::QuantityValue;
+pub use vf_attributes_hdk::{
+    ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+    ActionId,
+    RecipeFlowAddress,
+    RecipeResourceAddress,
+    RecipeProcessAddress,
+    RecipeExchangeAddress,
+    ProcessSpecificationAddress,
+};
+
+// toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub recipe_flow: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    pub recipe_flow: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete output record, including all managed link fields
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: RecipeFlowAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub action: ActionId,
+    pub recipe_flow_resource: RecipeResourceAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ProcessSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// I/O struct to describe what is returned outside the gateway
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub recipe_flow: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub action: ActionId,
+    pub recipe_flow_resource: RecipeResourceAddress,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub recipe_input_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_output_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_clause_of: MaybeUndefined<RecipeExchangeAddress>,
+    #[serde(default)]
+    pub stage: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub state: MaybeUndefined<String>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    pub action: MaybeUndefined<ActionId>,
+    #[serde(default)]
+    pub recipe_flow_resource: MaybeUndefined<RecipeResourceAddress>,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub recipe_input_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_output_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_clause_of: MaybeUndefined<RecipeExchangeAddress>,
+    #[serde(default)]
+    pub stage: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub state: MaybeUndefined<String>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &ActionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub recipe_flow_resource: Option<RecipeResourceAddress>,
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+}
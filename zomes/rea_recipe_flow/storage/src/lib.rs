@@ -0,0 +1,170 @@
+/**
+ * hREA recipe flow zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+use vf_measurement::QuantityValue;
+
+pub use vf_attributes_hdk::{
+    ActionId,
+    RecipeFlowAddress,
+    RecipeResourceAddress,
+    RecipeProcessAddress,
+    RecipeExchangeAddress,
+    ProcessSpecificationAddress,
+};
+
+use vf_actions::{ ProcessType, get_builtin_action };
+
+use hc_zome_rea_recipe_flow_rpc::{ CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_recipe_flow_storage_consts::RECIPE_FLOW_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_flow: RecipeFlowZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeFlowZomeConfig {
+    pub index_zome: String,
+    pub recipe_resource_index_zome: Option<String>,
+    pub recipe_process_index_zome: Option<String>,
+    pub recipe_exchange_index_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub action: ActionId,
+    pub recipe_flow_resource: RecipeResourceAddress,
+    pub resource_quantity: Option<QuantityValue>,
+    pub effort_quantity: Option<QuantityValue>,
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+    pub stage: Option<ProcessSpecificationAddress>,
+    pub state: Option<String>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.resource_quantity.is_some() || self.effort_quantity.is_some()) {
+            return Err("RecipeFlow must include either a resource quantity or an effort quantity".into());
+        }
+        if self.recipe_input_of.is_some() && self.recipe_output_of.is_some() {
+            return Err("RecipeFlow cannot be both an input and an output of a RecipeProcess".into());
+        }
+        match get_builtin_action(self.action.as_ref()) {
+            None => Err("Unknown action".into()),
+            Some(action) => match action.input_output {
+                ProcessType::Input if self.recipe_output_of.is_some() =>
+                    Err(format!("RecipeFlow of '{:}' action cannot be a RecipeProcess output", action.id)),
+                ProcessType::Output if self.recipe_input_of.is_some() =>
+                    Err(format!("RecipeFlow of '{:}' action cannot be a RecipeProcess input", action.id)),
+                ProcessType::NotApplicable if self.recipe_input_of.is_some() || self.recipe_output_of.is_some() =>
+                    Err(format!("RecipeFlow of '{:}' action cannot link to RecipeProcesses", action.id)),
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+generate_record_entry!(EntryData, RecipeFlowAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    RecipeFlow(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::RecipeFlow(e)
+    }
+}
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            action: e.action.into(),
+            recipe_flow_resource: e.recipe_flow_resource.into(),
+            resource_quantity: e.resource_quantity.into(),
+            effort_quantity: e.effort_quantity.into(),
+            recipe_input_of: e.recipe_input_of.into(),
+            recipe_output_of: e.recipe_output_of.into(),
+            recipe_clause_of: e.recipe_clause_of.into(),
+            stage: e.stage.into(),
+            state: e.state.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            action: if !e.action.is_some() { self.action.to_owned() } else { e.action.to_owned().unwrap() },
+            recipe_flow_resource: if !e.recipe_flow_resource.is_some() { self.recipe_flow_resource.to_owned() } else { e.recipe_flow_resource.to_owned().unwrap() },
+            resource_quantity: if e.resource_quantity.is_undefined() { self.resource_quantity.to_owned() } else { e.resource_quantity.to_owned().into() },
+            effort_quantity: if e.effort_quantity.is_undefined() { self.effort_quantity.to_owned() } else { e.effort_quantity.to_owned().into() },
+            recipe_input_of: if e.recipe_input_of.is_undefined() { self.recipe_input_of.to_owned() } else { e.recipe_input_of.to_owned().into() },
+            recipe_output_of: if e.recipe_output_of.is_undefined() { self.recipe_output_of.to_owned() } else { e.recipe_output_of.to_owned().into() },
+            recipe_clause_of: if e.recipe_clause_of.is_undefined() { self.recipe_clause_of.to_owned() } else { e.recipe_clause_of.to_owned().into() },
+            stage: if e.stage.is_undefined() { self.stage.to_owned() } else { e.stage.to_owned().into() },
+            state: if e.state.is_undefined() { self.state.to_owned() } else { e.state.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
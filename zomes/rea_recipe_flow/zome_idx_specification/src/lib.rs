@@ -0,0 +1,16 @@
+/**
+ * Recipe flow query indexes for specification DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_flow_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct RecipeFlow {
+    recipe_flow_resource: Local<recipe_resource, recipe_flows>,
+    recipe_input_of: Local<recipe_process, recipe_inputs>,
+    recipe_output_of: Local<recipe_process, recipe_outputs>,
+    recipe_clause_of: Local<recipe_exchange, recipe_clauses>,
+}
@@ -0,0 +1,128 @@
+/**
+ * hREA recipe process zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+use serde_maybe_undefined::MaybeUndefined;
+use vf_measurement::QuantityValue;
+pub use vf_attributes_hdk::{
+    ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+    RecipeProcessAddress,
+    RecipeFlowAddress,
+    ProcessSpecificationAddress,
+    ExternalURL,
+};
+
+// toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub recipe_process: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    pub recipe_process: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete output record, including all managed link fields
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: RecipeProcessAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_conforms_to: Option<ProcessSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_classified_as: Option<Vec<ExternalURL>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_duration: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    // query edges
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipe_inputs: Vec<RecipeFlowAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipe_outputs: Vec<RecipeFlowAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub recipe_process: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub name: String,
+    #[serde(default)]
+    pub process_conforms_to: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub process_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub has_duration: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    pub process_conforms_to: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub process_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub has_duration: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &ActionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub process_conforms_to: Option<ProcessSpecificationAddress>,
+    pub recipe_inputs: Option<RecipeFlowAddress>,
+    pub recipe_outputs: Option<RecipeFlowAddress>,
+}
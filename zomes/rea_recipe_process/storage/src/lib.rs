@@ -0,0 +1,122 @@
+/**
+ * hREA recipe process zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+use vf_measurement::QuantityValue;
+
+pub use vf_attributes_hdk::{
+    RecipeProcessAddress,
+    ProcessSpecificationAddress,
+    ExternalURL,
+};
+
+use hc_zome_rea_recipe_process_rpc::{ CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_recipe_process_storage_consts::RECIPE_PROCESS_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_process: RecipeProcessZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeProcessZomeConfig {
+    pub index_zome: String,
+    pub process_specification_index_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub process_conforms_to: Option<ProcessSpecificationAddress>,
+    pub process_classified_as: Option<Vec<ExternalURL>>,
+    pub has_duration: Option<QuantityValue>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+generate_record_entry!(EntryData, RecipeProcessAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    RecipeProcess(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::RecipeProcess(e)
+    }
+}
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: e.name.into(),
+            process_conforms_to: e.process_conforms_to.into(),
+            process_classified_as: e.process_classified_as.into(),
+            has_duration: e.has_duration.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            process_conforms_to: if e.process_conforms_to.is_undefined() { self.process_conforms_to.to_owned() } else { e.process_conforms_to.to_owned().into() },
+            process_classified_as: if e.process_classified_as.is_undefined() { self.process_classified_as.to_owned() } else { e.process_classified_as.to_owned().into() },
+            has_duration: if e.has_duration.is_undefined() { self.has_duration.to_owned() } else { e.has_duration.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
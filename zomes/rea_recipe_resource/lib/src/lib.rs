@@ -0,0 +1,166 @@
+/**
+ * hREA recipe resource zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeResource` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_resource_storage::*;
+use hc_zome_rea_recipe_resource_rpc::*;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_resource.index_zome)
+}
+
+pub fn handle_create_recipe_resource<S>(entry_def_id: S, recipe_resource: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, recipe_resource)?;
+
+    // handle link fields
+    if let Some(resource_conforms_to) = &entry_resp.resource_conforms_to {
+        let e = create_index!(recipe_resource.resource_conforms_to(resource_conforms_to), resource_specification.recipe_resources(&base_address));
+        hdk::prelude::debug!("handle_create_recipe_resource::resource_conforms_to index {:?}", e);
+    }
+
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_recipe_resource(address: RecipeResourceAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.recipe_resource.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.recipe_resource.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<RecipeResourceAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, RecipeResourceAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.recipe_resource)
+    })
+}
+
+pub fn handle_update_recipe_resource(recipe_resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_recipe_resource(None, recipe_resource)
+}
+
+pub fn handle_resolve_recipe_resource_conflict(conflicting_revisions: Vec<ActionHash>, recipe_resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_recipe_resource(Some(&conflicting_revisions), recipe_resource)
+}
+
+fn update_or_resolve_recipe_resource(conflicting_revisions: Option<&[ActionHash]>, recipe_resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let old_revision = recipe_resource.get_revision_id();
+    let (meta, base_address, new_entry, prev_entry): (_, RecipeResourceAddress, EntryData, EntryData) = update_or_resolve_record(old_revision, conflicting_revisions, recipe_resource.to_owned())?;
+
+    if new_entry.resource_conforms_to != prev_entry.resource_conforms_to {
+        let new_value = match &new_entry.resource_conforms_to { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.resource_conforms_to { Some(val) => vec![val.to_owned()], None => vec![] };
+        let e = update_index!(
+            recipe_resource
+                .resource_conforms_to(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            resource_specification.recipe_resources(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_recipe_resource::resource_conforms_to index {:?}", e);
+    }
+
+    construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_recipe_resource<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // load the record to ensure it is of the correct type
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    if let Some(resource_specification_address) = entry.resource_conforms_to {
+        let e = update_index!(recipe_resource.resource_conforms_to.not(&vec![resource_specification_address]), resource_specification.recipe_resources(&base_address));
+        hdk::prelude::debug!("handle_delete_recipe_resource::resource_conforms_to index {:?}", e);
+    }
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeResourceAddress, meta: &SignedActionHashed, e: &EntryData, (
+        recipe_flows,
+    ): (
+        Vec<RecipeFlowAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_resource: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            name: e.name.to_owned(),
+            resource_conforms_to: e.resource_conforms_to.to_owned(),
+            resource_classified_as: e.resource_classified_as.to_owned(),
+            unit_of_resource: e.unit_of_resource.to_owned(),
+            unit_of_effort: e.unit_of_effort.to_owned(),
+            substitutable: e.substitutable.to_owned(),
+            image: e.image.to_owned(),
+            note: e.note.to_owned(),
+            recipe_flows: recipe_flows.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_resource.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_resource_specification_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.recipe_resource.resource_specification_index_zome
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &RecipeResourceAddress) -> RecordAPIResult<(
+    Vec<RecipeFlowAddress>,
+)> {
+    Ok((
+        read_index!(recipe_resource(base_address).recipe_flows)?,
+    ))
+}
@@ -0,0 +1,142 @@
+/**
+ * hREA recipe resource zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    ActionHash, ByAddress, ByAction, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+    RecipeResourceAddress,
+    RecipeFlowAddress,
+    ResourceSpecificationAddress,
+    ExternalURL,
+    UnitId,
+};
+
+// toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub recipe_resource: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    pub recipe_resource: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete output record, including all managed link fields
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: RecipeResourceAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_classified_as: Option<Vec<ExternalURL>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_of_resource: Option<UnitId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_of_effort: Option<UnitId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub substitutable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ExternalURL>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    // query edges
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipe_flows: Vec<RecipeFlowAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub recipe_resource: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub name: String,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub resource_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub unit_of_resource: MaybeUndefined<UnitId>,
+    #[serde(default)]
+    pub unit_of_effort: MaybeUndefined<UnitId>,
+    #[serde(default)]
+    pub substitutable: MaybeUndefined<bool>,
+    #[serde(default)]
+    pub image: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub resource_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub unit_of_resource: MaybeUndefined<UnitId>,
+    #[serde(default)]
+    pub unit_of_effort: MaybeUndefined<UnitId>,
+    #[serde(default)]
+    pub substitutable: MaybeUndefined<bool>,
+    #[serde(default)]
+    pub image: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &ActionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    pub recipe_flows: Option<RecipeFlowAddress>,
+}
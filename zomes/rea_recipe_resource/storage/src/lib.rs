@@ -0,0 +1,131 @@
+/**
+ * hREA recipe resource zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+pub use vf_attributes_hdk::{
+    RecipeResourceAddress,
+    ResourceSpecificationAddress,
+    ExternalURL,
+    UnitId,
+};
+
+use hc_zome_rea_recipe_resource_rpc::{ CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_recipe_resource_storage_consts::RECIPE_RESOURCE_ENTRY_TYPE;
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_resource: RecipeResourceZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeResourceZomeConfig {
+    pub index_zome: String,
+    pub resource_specification_index_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Default, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    pub resource_classified_as: Option<Vec<ExternalURL>>,
+    pub unit_of_resource: Option<UnitId>,
+    pub unit_of_effort: Option<UnitId>,
+    pub substitutable: Option<bool>,
+    pub image: Option<ExternalURL>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+generate_record_entry!(EntryData, RecipeResourceAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    RecipeResource(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::RecipeResource(e)
+    }
+}
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: e.name.into(),
+            resource_conforms_to: e.resource_conforms_to.into(),
+            resource_classified_as: e.resource_classified_as.into(),
+            unit_of_resource: e.unit_of_resource.into(),
+            unit_of_effort: e.unit_of_effort.into(),
+            substitutable: e.substitutable.into(),
+            image: e.image.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            resource_conforms_to: if e.resource_conforms_to.is_undefined() { self.resource_conforms_to.to_owned() } else { e.resource_conforms_to.to_owned().into() },
+            resource_classified_as: if e.resource_classified_as.is_undefined() { self.resource_classified_as.to_owned() } else { e.resource_classified_as.to_owned().into() },
+            unit_of_resource: if e.unit_of_resource.is_undefined() { self.unit_of_resource.to_owned() } else { e.unit_of_resource.to_owned().into() },
+            unit_of_effort: if e.unit_of_effort.is_undefined() { self.unit_of_effort.to_owned() } else { e.unit_of_effort.to_owned().into() },
+            substitutable: if e.substitutable.is_undefined() { self.substitutable.to_owned() } else { e.substitutable.to_owned().into() },
+            image: if e.image.is_undefined() { self.image.to_owned() } else { e.image.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
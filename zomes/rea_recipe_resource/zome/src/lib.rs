@@ -0,0 +1,61 @@
+/**
+ * hREA recipe resource zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_recipe_resource_rpc::*;
+use hc_zome_rea_recipe_resource_lib::*;
+use hc_zome_rea_recipe_resource_storage_consts::*;
+
+#[hdk_extern]
+fn create_recipe_resource(CreateParams { recipe_resource }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_recipe_resource(RECIPE_RESOURCE_ENTRY_TYPE, recipe_resource)?)
+}
+
+#[hdk_extern]
+fn get_recipe_resource(ByAddress { address }: ByAddress<RecipeResourceAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_recipe_resource(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<RecipeResourceAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn update_recipe_resource(UpdateParams { recipe_resource }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_recipe_resource(recipe_resource)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub recipe_resource: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_recipe_resource_conflict(ResolveConflictParams { conflicting_revisions, recipe_resource }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_recipe_resource_conflict(conflicting_revisions, recipe_resource)?)
+}
+
+#[hdk_extern]
+fn delete_recipe_resource(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_recipe_resource(RECIPE_RESOURCE_ENTRY_TYPE, revision_id)?)
+}
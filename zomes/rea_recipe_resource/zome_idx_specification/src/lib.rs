@@ -0,0 +1,14 @@
+/**
+ * Recipe resource query indexes for specification DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_resource_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct RecipeResource {
+    resource_conforms_to: Local<resource_specification, recipe_resources>,
+    recipe_flows: Local<recipe_flow, recipe_flow_resource>,
+}
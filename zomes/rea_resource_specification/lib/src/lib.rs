@@ -12,10 +12,12 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 
 use vf_attributes_hdk::{
@@ -41,25 +43,52 @@ pub fn handle_create_resource_specification<S>(entry_def_id: S, resource_specifi
 pub fn handle_get_resource_specification(address: ResourceSpecificationAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.resource_specification.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.resource_specification.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ResourceSpecificationAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ResourceSpecificationAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.resource_specification)
+    })
 }
 
 pub fn handle_update_resource_specification(resource_specification: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_resource_specification(None, resource_specification)
+}
+
+pub fn handle_resolve_resource_specification_conflict(conflicting_revisions: Vec<ActionHash>, resource_specification: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_resource_specification(Some(&conflicting_revisions), resource_specification)
+}
+
+fn update_or_resolve_resource_specification(conflicting_revisions: Option<&[ActionHash]>, resource_specification: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let old_revision = resource_specification.get_revision_id();
-    let (meta, base_address, new_entry, _prev_entry): (_, ResourceSpecificationAddress, EntryData, EntryData) = update_record(old_revision, resource_specification.to_owned())?;
+    let (meta, base_address, new_entry, _prev_entry): (_, ResourceSpecificationAddress, EntryData, EntryData) = update_or_resolve_record(old_revision, conflicting_revisions, resource_specification.to_owned())?;
     construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
 }
 
-pub fn handle_delete_resource_specification(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_resource_specification<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 /// Create response from input DHT primitives
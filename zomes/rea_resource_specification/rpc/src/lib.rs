@@ -10,8 +10,11 @@ use holochain_serialized_bytes::prelude::*;
 use serde_maybe_undefined::MaybeUndefined;
 pub use vf_attributes_hdk::{
     ActionHash, ByAddress, ByAction, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     ResourceSpecificationAddress,
     EconomicResourceAddress,
+    RecipeResourceAddress,
     ExternalURL,
     UnitId,
     ByRevision,
@@ -118,4 +121,5 @@ impl<'a> UpdateRequest {
 #[serde(rename_all = "camelCase")]
 pub struct QueryParams {
     pub conforming_resources: Option<EconomicResourceAddress>,
+    pub recipe_resources: Option<RecipeResourceAddress>,
 }
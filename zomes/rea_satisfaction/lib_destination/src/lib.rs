@@ -16,9 +16,12 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
         update_record,
         delete_record,
     },
+    metadata::read_revision_metadata_full,
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -49,13 +52,29 @@ pub fn handle_create_satisfaction<S>(entry_def_id: S, satisfaction: CreateReques
 pub fn handle_get_satisfaction(address: SatisfactionAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.satisfaction.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.satisfaction.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<SatisfactionAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, SatisfactionAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry)?.satisfaction)
+    })
 }
 
 pub fn handle_update_satisfaction(satisfaction: UpdateRequest) -> RecordAPIResult<ResponseData>
@@ -75,7 +94,8 @@ pub fn handle_update_satisfaction(satisfaction: UpdateRequest) -> RecordAPIResul
     construct_response(&base_address, &meta, &new_entry)
 }
 
-pub fn handle_delete_satisfaction(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_satisfaction<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     // read any referencing indexes
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
@@ -84,7 +104,7 @@ pub fn handle_delete_satisfaction(revision_id: ActionHash) -> RecordAPIResult<bo
     let e = update_index!(satisfaction.satisfied_by.not(&vec![entry.satisfied_by]), economic_event.satisfies(&base_address));
     hdk::prelude::debug!("handle_delete_satisfaction::satisfied_by index (destination) {:?}", e);
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 /// Properties accessor for zome config.
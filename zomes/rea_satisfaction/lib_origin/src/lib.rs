@@ -19,10 +19,13 @@ use hdk_records::{
         create_record,
         read_record_entry,
         read_record_entry_by_action,
-        update_record,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
         delete_record,
     },
     rpc::call_zome_method,
+    metadata::read_revision_metadata_full,
 };
 use hdk_semantic_indexes_client_lib::*;
 
@@ -79,18 +82,44 @@ pub fn handle_create_satisfaction<S>(entry_def_id: S, satisfaction: CreateReques
 pub fn handle_get_satisfaction(address: SatisfactionAddress) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.satisfaction.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&base_address, &meta, &entry)
+    let mut response = construct_response(&base_address, &meta, &entry)?;
+    response.satisfaction.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<SatisfactionAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, SatisfactionAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry)?.satisfaction)
+    })
 }
 
 pub fn handle_update_satisfaction(satisfaction: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
-    let (meta, base_address, new_entry, prev_entry): (_, SatisfactionAddress, EntryData, EntryData) = update_record(&satisfaction.get_revision_id(), satisfaction.to_owned())?;
+    update_or_resolve_satisfaction(None, satisfaction)
+}
+
+pub fn handle_resolve_satisfaction_conflict(conflicting_revisions: Vec<ActionHash>, satisfaction: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_satisfaction(Some(&conflicting_revisions), satisfaction)
+}
+
+fn update_or_resolve_satisfaction(conflicting_revisions: Option<&[ActionHash]>, satisfaction: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, new_entry, prev_entry): (_, SatisfactionAddress, EntryData, EntryData) = update_or_resolve_record(&satisfaction.get_revision_id(), conflicting_revisions, satisfaction.to_owned())?;
 
     // update intent indexes in local DNA
     if new_entry.satisfies != prev_entry.satisfies {
@@ -174,7 +203,8 @@ pub fn handle_update_satisfaction(satisfaction: UpdateRequest) -> RecordAPIResul
     construct_response(&base_address, &meta, &new_entry)
 }
 
-pub fn handle_delete_satisfaction(revision_id: ActionHash) -> RecordAPIResult<bool>
+pub fn handle_delete_satisfaction<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
 {
     let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
 
@@ -199,7 +229,7 @@ pub fn handle_delete_satisfaction(revision_id: ActionHash) -> RecordAPIResult<bo
         hdk::prelude::debug!("handle_delete_satisfaction::call_zome_method::{:?} {:?}", REPLICATE_DELETE_API_METHOD, result);
     }
 
-    delete_record::<EntryStorage>(&revision_id)
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 fn is_satisfiedby_local_commitment(event_or_commitment: &EventOrCommitmentAddress) -> RecordAPIResult<bool> {
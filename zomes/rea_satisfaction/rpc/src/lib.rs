@@ -13,6 +13,8 @@ use serde_maybe_undefined::{MaybeUndefined};
 use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     ActionHash, ByAction, ByAddress, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
     SatisfactionAddress,
     EventOrCommitmentAddress,
     EconomicEventAddress,
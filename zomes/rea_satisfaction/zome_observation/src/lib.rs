@@ -35,6 +35,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<SatisfactionAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn satisfaction_updated(UpdateParams { satisfaction }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_satisfaction(satisfaction)?)
@@ -42,5 +52,5 @@ fn satisfaction_updated(UpdateParams { satisfaction }: UpdateParams) -> ExternRe
 
 #[hdk_extern]
 fn satisfaction_deleted(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_satisfaction(revision_id)?)
+    Ok(handle_delete_satisfaction(SATISFACTION_ENTRY_TYPE, revision_id)?)
 }
@@ -33,12 +33,34 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<SatisfactionAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[hdk_extern]
 fn update_satisfaction(UpdateParams { satisfaction }: UpdateParams) -> ExternResult<ResponseData> {
     Ok(handle_update_satisfaction(satisfaction)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub satisfaction: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_satisfaction_conflict(ResolveConflictParams { conflicting_revisions, satisfaction }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_satisfaction_conflict(conflicting_revisions, satisfaction)?)
+}
+
 #[hdk_extern]
 fn delete_satisfaction(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_satisfaction(revision_id)?)
+    Ok(handle_delete_satisfaction(SATISFACTION_ENTRY_TYPE, revision_id)?)
 }
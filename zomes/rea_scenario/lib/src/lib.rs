@@ -0,0 +1,194 @@
+/**
+ * hREA scenario zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Scenario` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, MaybeUndefined, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_scenario_storage::*;
+use hc_zome_rea_scenario_rpc::*;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.scenario.index_zome)
+}
+
+pub fn handle_create_scenario<S>(entry_def_id: S, scenario: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, scenario.to_owned())?;
+
+    // handle link fields
+    if let CreateRequest { defined_as: MaybeUndefined::Some(defined_as), .. } = &scenario {
+        let e = create_index!(scenario.defined_as(defined_as), scenario_definition.defines_scenario(&base_address));
+        hdk::prelude::debug!("handle_create_scenario::defined_as index {:?}", e);
+    };
+    if let CreateRequest { refinement_of: MaybeUndefined::Some(refinement_of), .. } = &scenario {
+        let e = create_index!(scenario.refinement_of(refinement_of), scenario.refinements(&base_address));
+        hdk::prelude::debug!("handle_create_scenario::refinement_of index {:?}", e);
+    };
+
+    // :TODO: pass results from link creation rather than re-reading
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_scenario(address: ScenarioAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&address)?)?;
+    response.scenario.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.scenario.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ScenarioAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ScenarioAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.scenario)
+    })
+}
+
+pub fn handle_update_scenario(scenario: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_scenario(None, scenario)
+}
+
+pub fn handle_resolve_scenario_conflict(conflicting_revisions: Vec<ActionHash>, scenario: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_scenario(Some(&conflicting_revisions), scenario)
+}
+
+fn update_or_resolve_scenario(conflicting_revisions: Option<&[ActionHash]>, scenario: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let address = scenario.get_revision_id().to_owned();
+    let (meta, base_address, new_entry, prev_entry): (_, ScenarioAddress, EntryData, EntryData) = update_or_resolve_record(&address, conflicting_revisions, scenario.to_owned())?;
+
+    if new_entry.defined_as != prev_entry.defined_as {
+        let new_value = match &new_entry.defined_as { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.defined_as { Some(val) => vec![val.to_owned()], None => vec![] };
+        let e = update_index!(
+            scenario
+                .defined_as(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            scenario_definition.defines_scenario(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_scenario::defined_as index {:?}", e);
+    }
+    if new_entry.refinement_of != prev_entry.refinement_of {
+        let new_value = match &new_entry.refinement_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.refinement_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let e = update_index!(
+            scenario
+                .refinement_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            scenario.refinements(&base_address)
+        );
+        hdk::prelude::debug!("handle_update_scenario::refinement_of index {:?}", e);
+    }
+
+    construct_response(&base_address, &meta, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_scenario<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    // load the record to ensure it is of the correct type
+    let (_meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    if let Some(definition_address) = entry.defined_as {
+        let e = update_index!(scenario.defined_as.not(&vec![definition_address]), scenario_definition.defines_scenario(&base_address));
+        hdk::prelude::debug!("handle_delete_scenario::defined_as index {:?}", e);
+    }
+    if let Some(scenario_address) = entry.refinement_of {
+        let e = update_index!(scenario.refinement_of.not(&vec![scenario_address]), scenario.refinements(&base_address));
+        hdk::prelude::debug!("handle_delete_scenario::refinement_of index {:?}", e);
+    }
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &ScenarioAddress, meta: &SignedActionHashed, e: &EntryData, (
+        refinements,
+        plans,
+        processes,
+    ): (
+        Vec<ScenarioAddress>,
+        Vec<PlanAddress>,
+        Vec<ProcessAddress>,
+    )
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        scenario: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            name: e.name.to_owned(),
+            has_beginning: e.has_beginning.to_owned(),
+            has_end: e.has_end.to_owned(),
+            defined_as: e.defined_as.to_owned(),
+            refinement_of: e.refinement_of.to_owned(),
+            note: e.note.to_owned(),
+            refinements: refinements.to_owned(),
+            plans: plans.to_owned(),
+            processes: processes.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_scenario_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.scenario.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_scenario_definition_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.scenario.scenario_definition_index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(scenario: &ScenarioAddress) -> RecordAPIResult<(
+    Vec<ScenarioAddress>,
+    Vec<PlanAddress>,
+    Vec<ProcessAddress>,
+)> {
+    Ok((
+        read_index!(scenario(scenario).refinements)?,
+        read_index!(scenario(scenario).plans)?,
+        read_index!(scenario(scenario).processes)?,
+    ))
+}
@@ -0,0 +1,147 @@
+/**
+ * hREA scenario zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package hREA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_attributes_hdk::{
+    ActionHash, ByAction, ByAddress, ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
+    DateTime, FixedOffset,
+    ScenarioDefinitionAddress,
+    PlanAddress,
+    ProcessAddress,
+};
+
+/// Toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParams {
+    pub scenario: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    pub scenario: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+// Export external type interface to allow consuming zomes to easily import & define zome API
+pub use vf_attributes_hdk::{ ScenarioAddress };
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: ScenarioAddress,
+    pub revision_id: ActionHash,
+    pub meta: RecordMeta,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_beginning: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_end: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defined_as: Option<ScenarioDefinitionAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refinement_of: Option<ScenarioAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    // LINK FIELDS
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub refinements: Vec<ScenarioAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub plans: Vec<PlanAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub processes: Vec<ProcessAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub scenario: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub name: String,
+    #[serde(default)]
+    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub defined_as: MaybeUndefined<ScenarioDefinitionAddress>,
+    #[serde(default)]
+    pub refinement_of: MaybeUndefined<ScenarioAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: ActionHash,
+    #[serde(default)]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub defined_as: MaybeUndefined<ScenarioDefinitionAddress>,
+    #[serde(default)]
+    pub refinement_of: MaybeUndefined<ScenarioAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &ActionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub defined_as: Option<ScenarioDefinitionAddress>,
+    pub refinement_of: Option<ScenarioAddress>,
+    pub refinements: Option<ScenarioAddress>,
+    pub plans: Option<PlanAddress>,
+    pub processes: Option<ProcessAddress>,
+}
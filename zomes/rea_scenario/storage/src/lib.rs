@@ -0,0 +1,142 @@
+/**
+ * hREA scenario zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+pub use vf_attributes_hdk::{
+    DateTime, FixedOffset,
+    ScenarioAddress,
+    ScenarioDefinitionAddress,
+    PlanAddress,
+    ProcessAddress,
+};
+
+use hc_zome_rea_scenario_rpc::{ CreateRequest, UpdateRequest };
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub scenario: ScenarioZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct ScenarioZomeConfig {
+    pub index_zome: String,
+    pub scenario_definition_index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub has_beginning: Option<DateTime<FixedOffset>>,
+    pub has_end: Option<DateTime<FixedOffset>>,
+    pub defined_as: Option<ScenarioDefinitionAddress>,
+    pub refinement_of: Option<ScenarioAddress>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+impl EntryData {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Scenario must have a name".into());
+        }
+        if let (Some(begins), Some(ends)) = (&self.has_beginning, &self.has_end) {
+            if ends < begins {
+                return Err("Scenario cannot end before it begins".into());
+            }
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, ScenarioAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    Scenario(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::Scenario(e)
+    }
+}
+
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: e.name.into(),
+            has_beginning: e.has_beginning.into(),
+            has_end: e.has_end.into(),
+            defined_as: e.defined_as.into(),
+            refinement_of: e.refinement_of.into(),
+            note: e.note.into(),
+            _nonce: random_bytes(32)?,
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            has_beginning: if e.has_beginning == MaybeUndefined::Undefined { self.has_beginning.to_owned() } else { e.has_beginning.to_owned().into() },
+            has_end: if e.has_end == MaybeUndefined::Undefined { self.has_end.to_owned() } else { e.has_end.to_owned().into() },
+            defined_as: if e.defined_as == MaybeUndefined::Undefined { self.defined_as.to_owned() } else { e.defined_as.to_owned().into() },
+            refinement_of: if e.refinement_of == MaybeUndefined::Undefined { self.refinement_of.to_owned() } else { e.refinement_of.to_owned().into() },
+            note: if e.note == MaybeUndefined::Undefined { self.note.clone() } else { e.note.clone().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+/**
+ * hREA scenario zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_scenario_rpc::*;
+use hc_zome_rea_scenario_lib::*;
+use hc_zome_rea_scenario_storage_consts::*;
+
+#[hdk_extern]
+fn create_scenario(CreateParams { scenario }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_scenario(SCENARIO_ENTRY_TYPE, scenario)?)
+}
+
+#[hdk_extern]
+fn get_scenario(ByAddress { address }: ByAddress<ScenarioAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_scenario(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<ScenarioAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn update_scenario(UpdateParams { scenario }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_scenario(scenario)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub scenario: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_scenario_conflict(ResolveConflictParams { conflicting_revisions, scenario }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_scenario_conflict(conflicting_revisions, scenario)?)
+}
+
+#[hdk_extern]
+fn delete_scenario(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_scenario(SCENARIO_ENTRY_TYPE, revision_id)?)
+}
@@ -0,0 +1,17 @@
+/**
+ * Scenario query indexes for plan DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_scenario_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct Scenario {
+    defined_as: Local<scenario_definition, defines_scenario>,
+    refinement_of: Local<scenario, refinements>,
+    refinements: Local<scenario, refinement_of>,
+    plans: Local<plan, in_scope_of>,
+    processes: Remote<process, in_scope_of>,
+}
@@ -0,0 +1,126 @@
+/**
+ * hREA scenario definition zome library API
+ *
+ * Contains helper methods that can be used to manipulate `ScenarioDefinition` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package hREA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_action,
+        read_record_revision_history,
+        diff_revisions,
+        update_or_resolve_record,
+        delete_record,
+    },
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_scenario_definition_storage::*;
+use hc_zome_rea_scenario_definition_rpc::*;
+
+/// properties accessor for zome config
+fn read_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.scenario_definition.index_zome)
+}
+
+pub fn handle_create_scenario_definition<S>(entry_def_id: S, scenario_definition: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    let (meta, base_address, entry_resp): (_,_, EntryData) = create_record::<EntryTypes,_,_,_,_,_,_,_,_>(read_index_zome, &entry_def_id, scenario_definition)?;
+    construct_response(&base_address, &meta, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_scenario_definition(address: ScenarioDefinitionAddress) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _>(address.as_ref())?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.scenario_definition.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
+{
+    let (meta, base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
+    let mut response = construct_response(&base_address, &meta, &entry, get_link_fields(&base_address)?)?;
+    response.scenario_definition.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<ScenarioDefinitionAddress>) -> RecordAPIResult<RevisionHistory>
+{
+    read_record_revision_history(&page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, ScenarioDefinitionAddress, _, _>(&from_revision_id, &to_revision_id, |address, meta, entry| {
+        Ok(construct_response(address, meta, entry, Default::default())?.scenario_definition)
+    })
+}
+
+pub fn handle_update_scenario_definition(scenario_definition: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_scenario_definition(None, scenario_definition)
+}
+
+pub fn handle_resolve_scenario_definition_conflict(conflicting_revisions: Vec<ActionHash>, scenario_definition: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_scenario_definition(Some(&conflicting_revisions), scenario_definition)
+}
+
+fn update_or_resolve_scenario_definition(conflicting_revisions: Option<&[ActionHash]>, scenario_definition: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    let revision_hash = scenario_definition.get_revision_id().clone();
+    let (meta, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_or_resolve_record(&revision_hash, conflicting_revisions, scenario_definition)?;
+    construct_response(&identity_address, &meta, &entry, get_link_fields(&identity_address)?)
+}
+
+pub fn handle_delete_scenario_definition<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &ScenarioDefinitionAddress, meta: &SignedActionHashed, e: &EntryData, (
+        scenarios,
+    ): (
+        Vec<ScenarioAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        scenario_definition: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            name: e.name.to_owned(),
+            has_duration: e.has_duration.to_owned(),
+            note: e.note.to_owned(),
+            defines_scenario: scenarios.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_scenario_definition_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.scenario_definition.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &ScenarioDefinitionAddress) -> RecordAPIResult<(
+    Vec<ScenarioAddress>,
+)> {
+    Ok((
+        read_index!(scenario_definition(base_address).defines_scenario)?,
+    ))
+}
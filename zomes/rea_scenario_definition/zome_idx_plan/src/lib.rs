@@ -0,0 +1,13 @@
+/**
+ * Scenario definition query indexes for plan DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_scenario_definition_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct ScenarioDefinition {
+    defines_scenario: Local<scenario, defined_as>,
+}
@@ -0,0 +1,34 @@
+/**
+ * hREA settlement zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Settlement` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * Contains functionality for the "origin" side of an "indirect remote index" pair
+ * (@see `hdk_records` README).
+ *
+ * @package hREA
+ */
+use hdk_records::{
+    RecordAPIResult, SignedActionHashed,
+    metadata::read_revision_metadata_abbreviated,
+};
+use vf_attributes_hdk::{SettlementAddress};
+use hc_zome_rea_settlement_storage::EntryData;
+use hc_zome_rea_settlement_rpc::*;
+
+/// Create response from input DHT primitives
+pub fn construct_response(address: &SettlementAddress, meta: &SignedActionHashed, e: &EntryData) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        settlement: Response {
+            id: address.to_owned(),
+            revision_id: meta.as_hash().to_owned(),
+            meta: read_revision_metadata_abbreviated(meta)?,
+            settled_by: e.settled_by.to_owned(),
+            settles: e.settles.to_owned(),
+            resource_quantity: e.resource_quantity.to_owned(),
+            effort_quantity: e.effort_quantity.to_owned(),
+            note: e.note.to_owned(),
+        }
+    })
+}
@@ -0,0 +1,141 @@
+/**
+ * hREA settlement zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_dna_auth_resolver_core::AvailableCapability;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+use vf_measurement::QuantityValue;
+
+pub use vf_attributes_hdk::{
+    SettlementAddress,
+    EconomicEventAddress,
+    ClaimAddress,
+};
+
+use hc_zome_rea_settlement_rpc::{ CreateRequest, UpdateRequest };
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlicePlanning {
+    pub settlement: SettlementZomeConfigPlanning,
+}
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSliceObservation {
+    pub settlement: SettlementZomeConfigObservation,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct SettlementZomeConfigPlanning {
+    pub claim_index_zome: String,
+    pub index_zome: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct SettlementZomeConfigObservation {
+    pub economic_event_index_zome: String,
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub settled_by: EconomicEventAddress,
+    pub settles: ClaimAddress,
+    pub resource_quantity: Option<QuantityValue>,
+    pub effort_quantity: Option<QuantityValue>,
+    pub note: Option<String>,
+    pub _nonce: Bytes,
+}
+
+generate_record_entry!(EntryData, SettlementAddress, EntryStorage);
+
+//---------------- Holochain App Entry And Link Types Setup ----------------
+
+
+#[hdk_entry_defs(skip_hdk_extern = true)]
+#[unit_enum(EntryTypesUnit)]
+pub enum EntryTypes {
+    Settlement(EntryStorage),
+    #[entry_def(visibility = "private")]
+    AvailableCapability(AvailableCapability)
+}
+
+impl From<EntryStorage> for EntryTypes
+{
+    fn from(e: EntryStorage) -> EntryTypes
+    {
+        EntryTypes::Settlement(e)
+    }
+}
+impl TryFrom<AvailableCapability> for EntryTypes {
+    type Error = WasmError;
+
+    fn try_from(e: AvailableCapability) -> Result<EntryTypes, Self::Error>
+    {
+        Ok(EntryTypes::AvailableCapability(e))
+    }
+}
+
+#[hdk_link_types(skip_no_mangle = true)]
+pub enum LinkTypes {
+    // relates to dna-auth-resolver mixin
+    // and remote authorizations
+    AvailableCapability
+}
+
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl TryFrom<CreateRequest> for EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(e: CreateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            settled_by: e.settled_by.into(),
+            settles: e.settles.into(),
+            resource_quantity: e.resource_quantity.into(),
+            effort_quantity: e.effort_quantity.into(),
+            note: e.note.into(),
+            _nonce: if e.nonce.is_none_or_undefined() { random_bytes(32)? } else { e.nonce.unwrap() },
+        })
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> RecordAPIResult<EntryData> {
+        Ok(EntryData {
+            settled_by: match &e.settled_by {
+                MaybeUndefined::Some(settled_by) => settled_by.clone(),
+                _ => self.settled_by.clone(),
+            },
+            settles: match &e.settles {
+                MaybeUndefined::Some(settles) => settles.clone(),
+                _ => self.settles.clone(),
+            },
+            resource_quantity: if e.resource_quantity== MaybeUndefined::Undefined { self.resource_quantity.clone() } else { e.resource_quantity.clone().into() },
+            effort_quantity: if e.effort_quantity== MaybeUndefined::Undefined { self.effort_quantity.clone() } else { e.effort_quantity.clone().into() },
+            note: if e.note== MaybeUndefined::Undefined { self.note.clone() } else { e.note.clone().into() },
+            _nonce: self._nonce.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,13 @@
+/**
+ * Settlement query indexes for planning DNA
+ *
+ * @package hREA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_settlement_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from RPC query struct
+
+#[index_zome]
+struct Settlement {
+    settles: Local<claim, settled_by>,
+}
@@ -0,0 +1,51 @@
+/**
+ * hREA settlement remote index zome API definition
+ *
+ * Manages indexes for querying `EconomicEvents` against remote `Settlements`.
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_settlement_lib_destination::*;
+use hc_zome_rea_settlement_rpc::*;
+use hc_zome_rea_settlement_storage_consts::*;
+
+#[hdk_extern]
+fn settlement_created(CreateParams { settlement }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_settlement(SETTLEMENT_ENTRY_TYPE, settlement)?)
+}
+
+#[hdk_extern]
+fn get_settlement(ByAddress { address }: ByAddress<SettlementAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_settlement(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<SettlementAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn settlement_updated(UpdateParams { settlement }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_settlement(settlement)?)
+}
+
+#[hdk_extern]
+fn settlement_deleted(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_settlement(SETTLEMENT_ENTRY_TYPE, revision_id)?)
+}
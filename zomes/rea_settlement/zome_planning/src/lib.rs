@@ -0,0 +1,61 @@
+/**
+ * hREA settlement zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package hREA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_settlement_lib_origin::*;
+use hc_zome_rea_settlement_rpc::*;
+use hc_zome_rea_settlement_storage_consts::*;
+
+#[hdk_extern]
+fn create_settlement(CreateParams { settlement }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_settlement(SETTLEMENT_ENTRY_TYPE, settlement)?)
+}
+
+#[hdk_extern]
+fn get_settlement(ByAddress { address }: ByAddress<SettlementAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_settlement(address)?)
+}
+
+#[hdk_extern]
+fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<ResponseData> {
+    Ok(handle_get_revision(revision_id)?)
+}
+
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<SettlementAddress>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
+#[hdk_extern]
+fn update_settlement(UpdateParams { settlement }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_settlement(settlement)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub settlement: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_settlement_conflict(ResolveConflictParams { conflicting_revisions, settlement }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_settlement_conflict(conflicting_revisions, settlement)?)
+}
+
+#[hdk_extern]
+fn delete_settlement(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
+    Ok(handle_delete_settlement(SETTLEMENT_ENTRY_TYPE, revision_id)?)
+}
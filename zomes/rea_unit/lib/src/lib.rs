@@ -12,14 +12,16 @@ use hdk_records::{
     records_anchored::{
         create_anchored_record,
         read_anchored_record_entry,
+        read_anchored_record_revision_history,
         update_anchored_record,
         delete_anchored_record,
     },
     records::{
         read_record_entry,
         read_record_entry_by_action,
+        diff_revisions,
     },
-    metadata::read_revision_metadata_abbreviated,
+    metadata::{read_revision_metadata_abbreviated, read_revision_metadata_full},
 };
 
 pub use vf_attributes_hdk::{
@@ -58,7 +60,9 @@ pub fn handle_get_unit(id: UnitId) -> RecordAPIResult<ResponseData>
         LinkTypes::UnitIdentifier,
         id_str
       )?;
-    construct_response(&entry_id, &meta, &entry)
+    let mut response = construct_response(&entry_id, &meta, &entry)?;
+    response.unit.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
 }
 
 // internal method used by index zomes to locate indexed unit record data
@@ -74,21 +78,50 @@ pub fn handle_get_unit_by_address(address: UnitInternalAddress) -> RecordAPIResu
 pub fn handle_get_revision(revision_id: ActionHash) -> RecordAPIResult<ResponseData>
 {
     let (meta, _base_address, entry) = read_record_entry_by_action::<EntryData, EntryStorage, _>(&revision_id)?;
-    construct_response(&UnitId::new(
+    let mut response = construct_response(&UnitId::new(
         dna_info()?.hash,
         entry.symbol.to_owned(),
-    ), &meta, &entry)
+    ), &meta, &entry)?;
+    response.unit.meta = read_revision_metadata_full(&meta)?;
+    Ok(response)
+}
+
+pub fn handle_get_revision_history(page: RevisionHistoryParams<UnitId>) -> RecordAPIResult<RevisionHistory>
+{
+    read_anchored_record_revision_history(LinkTypes::UnitIdentifier, &page)
+}
+
+pub fn handle_diff_revisions(from_revision_id: ActionHash, to_revision_id: ActionHash) -> RecordAPIResult<RevisionDiff>
+{
+    diff_revisions::<EntryData, EntryStorage, UnitInternalAddress, _, _>(&from_revision_id, &to_revision_id, |_address, meta, entry| {
+        Ok(construct_response(&UnitId::new(
+            dna_info()?.hash,
+            entry.symbol.to_owned(),
+        ), meta, entry)?.unit)
+    })
 }
 
 pub fn handle_update_unit(unit: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_unit(None, unit)
+}
+
+pub fn handle_resolve_unit_conflict(conflicting_revisions: Vec<ActionHash>, unit: UpdateRequest) -> RecordAPIResult<ResponseData>
+{
+    update_or_resolve_unit(Some(&conflicting_revisions), unit)
+}
+
+fn update_or_resolve_unit(conflicting_revisions: Option<&[ActionHash]>, unit: UpdateRequest) -> RecordAPIResult<ResponseData>
 {
     let revision_id = unit.get_revision_id().clone();
-    let (meta, new_id, new_entry, _prev_entry): (_,UnitId,_,_) = update_anchored_record::<LinkTypes, EntryData, EntryStorage, UnitInternalAddress, _,_,_>(LinkTypes::UnitIdentifier, &revision_id, unit)?;
+    let (meta, new_id, new_entry, _prev_entry): (_,UnitId,_,_) = update_anchored_record::<LinkTypes, EntryData, EntryStorage, UnitInternalAddress, _,_,_>(LinkTypes::UnitIdentifier, &revision_id, conflicting_revisions, unit)?;
     construct_response(&new_id, &meta, &new_entry)
 }
 
-pub fn handle_delete_unit(revision_id: ActionHash) -> RecordAPIResult<bool> {
-    delete_anchored_record::<EntryStorage>(&revision_id)
+pub fn handle_delete_unit<S>(entry_def_id: S, revision_id: ActionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str> + std::fmt::Display,
+{
+    delete_anchored_record::<EntryStorage,_,_,_,_,_>(read_index_zome, &entry_def_id, &revision_id)
 }
 
 fn construct_response<'a>(
@@ -21,6 +21,8 @@ pub use vf_attributes_hdk::{
     UnitId,
     UnitInternalAddress as UnitAddress,
     ByRevision, RecordMeta, RevisionMeta,
+    RevisionHistory, RevisionHistoryParams,
+    RevisionDiff, ByRevisionPair,
 };
 
 /// I/O struct to describe the complete record, including all managed link fields
@@ -45,6 +45,16 @@ fn get_revision(ByRevision { revision_id }: ByRevision) -> ExternResult<Response
     Ok(handle_get_revision(revision_id)?)
 }
 
+#[hdk_extern]
+fn get_revision_history(page: RevisionHistoryParams<UnitId>) -> ExternResult<RevisionHistory> {
+    Ok(handle_get_revision_history(page)?)
+}
+
+#[hdk_extern]
+fn diff_revisions(ByRevisionPair { from_revision_id, to_revision_id }: ByRevisionPair) -> ExternResult<RevisionDiff> {
+    Ok(handle_diff_revisions(from_revision_id, to_revision_id)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateParams {
@@ -56,7 +66,19 @@ fn update_unit(UpdateParams { unit }: UpdateParams) -> ExternResult<ResponseData
     Ok(handle_update_unit(unit)?)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolveConflictParams {
+    pub conflicting_revisions: Vec<ActionHash>,
+    pub unit: UpdateRequest,
+}
+
+#[hdk_extern]
+fn resolve_unit_conflict(ResolveConflictParams { conflicting_revisions, unit }: ResolveConflictParams) -> ExternResult<ResponseData> {
+    Ok(handle_resolve_unit_conflict(conflicting_revisions, unit)?)
+}
+
 #[hdk_extern]
 fn delete_unit(ByRevision { revision_id }: ByRevision) -> ExternResult<bool> {
-    Ok(handle_delete_unit(revision_id)?)
+    Ok(handle_delete_unit(UNIT_ENTRY_TYPE, revision_id)?)
 }